@@ -4,7 +4,9 @@ use {super::*, CompileErrorKind::*};
 pub(crate) struct Analyzer<'run, 'src> {
   aliases: Table<'src, Alias<'src, Name<'src>>>,
   assignments: Vec<&'run Binding<'src, Expression<'src>>>,
-  modules: Table<'src, Justfile<'src>>,
+  functions: Vec<&'run UserFunction<'src>>,
+  modules: Table<'src, UnresolvedModule<'src>>,
+  parameters: Vec<Assignment<'src>>,
   recipes: Vec<&'run Recipe<'src, UnresolvedDependency<'src>>>,
   sets: Table<'src, Set<'src>>,
   unexports: HashSet<String>,
@@ -13,7 +15,7 @@ pub(crate) struct Analyzer<'run, 'src> {
 
 impl<'run, 'src> Analyzer<'run, 'src> {
   pub(crate) fn analyze(
-    asts: &'run HashMap<PathBuf, Ast<'src>>,
+    asts: &'run HashMap<(Vec<&'src str>, PathBuf), Ast<'src>>,
     doc: Option<String>,
     groups: &[String],
     loaded: &[PathBuf],
@@ -21,25 +23,47 @@ impl<'run, 'src> Analyzer<'run, 'src> {
     paths: &HashMap<PathBuf, PathBuf>,
     root: &Path,
   ) -> CompileResult<'src, Justfile<'src>> {
-    Self::default().justfile(asts, doc, groups, loaded, name, paths, root)
+    let mut module =
+      Self::default().module(asts, &[], doc, groups, None, loaded, &[], name, paths, root)?;
+
+    let mut recipes = RecipeResolver::resolve_recipes(&mut module)?;
+
+    module.resolve(&mut recipes, &mut Vec::new())
   }
 
-  fn justfile(
+  fn module(
     mut self,
-    asts: &'run HashMap<PathBuf, Ast<'src>>,
+    asts: &'run HashMap<(Vec<&'src str>, PathBuf), Ast<'src>>,
+    arguments: &[(Name<'src>, StringLiteral<'src>)],
     doc: Option<String>,
     groups: &[String],
+    inherited: Option<&Settings<'src>>,
     loaded: &[PathBuf],
+    module_path: &[&'src str],
     name: Option<Name<'src>>,
     paths: &HashMap<PathBuf, PathBuf>,
     root: &Path,
-  ) -> CompileResult<'src, Justfile<'src>> {
+  ) -> CompileResult<'src, UnresolvedModule<'src>> {
     let mut definitions = HashMap::new();
     let mut imports = HashSet::new();
     let mut unstable_features = BTreeSet::new();
 
+    for (i, (argument, _)) in arguments.iter().enumerate() {
+      if arguments[..i]
+        .iter()
+        .any(|(original, _)| original.lexeme() == argument.lexeme())
+      {
+        return Err(argument.error(DuplicateModuleArgument {
+          argument: argument.lexeme(),
+          module: name.unwrap().lexeme(),
+        }));
+      }
+    }
+
+    let mut submodules = Vec::new();
+
     let mut stack = Vec::new();
-    let ast = asts.get(root).unwrap();
+    let ast = asts.get(&(module_path.to_vec(), root.into())).unwrap();
     stack.push(ast);
 
     while let Some(ast) = stack.pop() {
@@ -55,15 +79,19 @@ impl<'run, 'src> Analyzer<'run, 'src> {
             self.assignments.push(assignment);
           }
           Item::Comment(_) => (),
+          Item::Function(function) => {
+            self.functions.push(function);
+          }
           Item::Import { absolute, .. } => {
             if let Some(absolute) = absolute {
               if imports.insert(absolute) {
-                stack.push(asts.get(absolute).unwrap());
+                stack.push(asts.get(&(module_path.to_vec(), absolute.clone())).unwrap());
               }
             }
           }
           Item::Module {
             absolute,
+            arguments,
             doc,
             groups,
             name,
@@ -71,17 +99,39 @@ impl<'run, 'src> Analyzer<'run, 'src> {
           } => {
             if let Some(absolute) = absolute {
               Self::define(&mut definitions, *name, "module", false)?;
-              self.modules.insert(Self::analyze(
-                asts,
-                doc.clone(),
-                groups.as_slice(),
-                loaded,
-                Some(*name),
-                paths,
-                absolute,
-              )?);
+              submodules.push((absolute, arguments, doc, groups, *name));
             }
           }
+          Item::Parameter(parameter) => {
+            let value = if let Some((_, value)) = arguments
+              .iter()
+              .find(|(argument, _)| argument.lexeme() == parameter.name.lexeme())
+            {
+              Expression::StringLiteral {
+                string_literal: value.clone(),
+              }
+            } else if let Some(default) = &parameter.value {
+              default.clone()
+            } else if let Some(name) = name {
+              return Err(name.error(MissingModuleArgument {
+                module: name.lexeme(),
+                parameter: parameter.name.lexeme(),
+              }));
+            } else {
+              return Err(parameter.name.error(UnboundModuleParameter {
+                parameter: parameter.name.lexeme(),
+              }));
+            };
+
+            self.parameters.push(Assignment {
+              constant: parameter.constant,
+              export: parameter.export,
+              file_depth: parameter.file_depth,
+              name: parameter.name,
+              private: parameter.private,
+              value,
+            });
+          }
           Item::Recipe(recipe) => {
             if recipe.enabled() {
               Self::analyze_recipe(recipe)?;
@@ -105,10 +155,65 @@ impl<'run, 'src> Analyzer<'run, 'src> {
       self.warnings.extend(ast.warnings.iter().cloned());
     }
 
-    let settings = Settings::from_table(self.sets);
+    for (argument, _) in arguments {
+      if !self
+        .parameters
+        .iter()
+        .any(|parameter| parameter.name.lexeme() == argument.lexeme())
+      {
+        return Err(argument.error(UnknownModuleArgument {
+          argument: argument.lexeme(),
+          module: name.unwrap().lexeme(),
+        }));
+      }
+    }
+
+    let settings = Settings::from_table(inherited, self.sets);
+
+    for (absolute, arguments, doc, groups, name) in submodules {
+      let submodule_path = module_path
+        .iter()
+        .copied()
+        .chain(iter::once(name.lexeme()))
+        .collect::<Vec<&str>>();
+
+      self.modules.insert(Self::default().module(
+        asts,
+        arguments,
+        doc.clone(),
+        groups.as_slice(),
+        settings.inherit_settings.then_some(&settings),
+        loaded,
+        &submodule_path,
+        Some(name),
+        paths,
+        absolute,
+      )?);
+    }
+
+    let mut functions: Table<'src, UserFunction<'src>> = Table::default();
+    for function in self.functions {
+      let name = function.name.lexeme();
+
+      if function::get(name).is_some() || Keyword::Int == name {
+        return Err(
+          function
+            .name
+            .error(BuiltinFunctionRedefinition { function: name }),
+        );
+      }
+
+      if functions.contains_key(name) {
+        return Err(function.name.error(DuplicateFunction { function: name }));
+      }
+
+      functions.insert(function.clone());
+    }
+
+    FunctionResolver::resolve_functions(&functions)?;
 
     let mut assignments: Table<'src, Assignment<'src>> = Table::default();
-    for assignment in self.assignments {
+    for assignment in self.parameters.iter().chain(self.assignments) {
       let variable = assignment.name.lexeme();
 
       if !settings.allow_duplicate_variables && assignments.contains_key(variable) {
@@ -126,7 +231,7 @@ impl<'run, 'src> Analyzer<'run, 'src> {
       }
     }
 
-    AssignmentResolver::resolve_assignments(&assignments)?;
+    AssignmentResolver::resolve_assignments(&assignments, &functions, &self.modules)?;
 
     let mut deduplicated_recipes = Table::<'src, UnresolvedRecipe<'src>>::default();
     for recipe in self.recipes {
@@ -145,49 +250,19 @@ impl<'run, 'src> Analyzer<'run, 'src> {
       }
     }
 
-    let recipes = RecipeResolver::resolve_recipes(&assignments, &settings, deduplicated_recipes)?;
-
-    let mut aliases = Table::new();
-    while let Some(alias) = self.aliases.pop() {
-      aliases.insert(Self::resolve_alias(&recipes, alias)?);
-    }
-
-    for recipe in recipes.values() {
-      if recipe.attributes.contains(AttributeDiscriminant::Script) {
-        unstable_features.insert(UnstableFeature::ScriptAttribute);
-        break;
-      }
-    }
-
-    if settings.script_interpreter.is_some() {
-      unstable_features.insert(UnstableFeature::ScriptInterpreterSetting);
-    }
-
-    let source = root.to_owned();
-    let root = paths.get(root).unwrap();
-
-    Ok(Justfile {
-      aliases,
+    Ok(UnresolvedModule {
+      aliases: self.aliases,
       assignments,
-      default: recipes
-        .values()
-        .filter(|recipe| recipe.name.path == root)
-        .fold(None, |accumulator, next| match accumulator {
-          None => Some(Rc::clone(next)),
-          Some(previous) => Some(if previous.line_number() < next.line_number() {
-            previous
-          } else {
-            Rc::clone(next)
-          }),
-        }),
       doc: doc.filter(|doc| !doc.is_empty()),
+      functions,
       groups: groups.into(),
       loaded: loaded.into(),
       modules: self.modules,
       name,
-      recipes,
+      path: paths.get(root).unwrap().clone(),
+      recipes: deduplicated_recipes,
       settings,
-      source,
+      source: root.to_owned(),
       unexports: self.unexports,
       unstable_features,
       warnings: self.warnings,
@@ -236,7 +311,7 @@ impl<'run, 'src> Analyzer<'run, 'src> {
       }
       parameters.insert(parameter.name.lexeme());
 
-      if parameter.default.is_some() {
+      if parameter.default.is_some() || parameter.is_flag() {
         passed_default = true;
       } else if passed_default {
         return Err(
@@ -270,7 +345,7 @@ impl<'run, 'src> Analyzer<'run, 'src> {
         return Err(recipe.name.error(InvalidAttribute {
           item_kind: "Recipe",
           item_name: recipe.name.lexeme(),
-          attribute: attribute.clone(),
+          attribute: Box::new(attribute.clone()),
         }));
       }
     }
@@ -288,20 +363,6 @@ impl<'run, 'src> Analyzer<'run, 'src> {
 
     Ok(())
   }
-
-  fn resolve_alias(
-    recipes: &Table<'src, Rc<Recipe<'src>>>,
-    alias: Alias<'src, Name<'src>>,
-  ) -> CompileResult<'src, Alias<'src>> {
-    // Make sure the target recipe exists
-    match recipes.get(alias.target.lexeme()) {
-      Some(target) => Ok(alias.resolve(Rc::clone(target))),
-      None => Err(alias.name.token.error(UnknownAliasTarget {
-        alias: alias.name.lexeme(),
-        target: alias.target.lexeme(),
-      })),
-    }
-  }
 }
 
 #[cfg(test)]
@@ -398,6 +459,66 @@ mod tests {
     kind:   DuplicateVariable{variable: "a"},
   }
 
+  analysis_error! {
+    name:   duplicate_function,
+    input:  "fn a() := 'x'\nfn a() := 'y'",
+    offset: 17,
+    line:   1,
+    column: 3,
+    width:  1,
+    kind:   DuplicateFunction{function: "a"},
+  }
+
+  analysis_error! {
+    name:   builtin_function_redefinition,
+    input:  "fn trim(s) := s",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  4,
+    kind:   BuiltinFunctionRedefinition{function: "trim"},
+  }
+
+  analysis_error! {
+    name:   int_redefinition,
+    input:  "fn int(s) := s",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  3,
+    kind:   BuiltinFunctionRedefinition{function: "int"},
+  }
+
+  analysis_error! {
+    name:   unknown_function,
+    input:  "a := foo()",
+    offset: 5,
+    line:   0,
+    column: 5,
+    width:  3,
+    kind:   UnknownFunction{function: "foo"},
+  }
+
+  analysis_error! {
+    name:   unknown_function_in_interpolation,
+    input:  "a:\n echo {{bar()}}",
+    offset: 11,
+    line:   1,
+    column: 8,
+    width:  3,
+    kind:   UnknownFunction{function: "bar"},
+  }
+
+  analysis_error! {
+    name:   unknown_function_in_default,
+    input:  "a f=baz():",
+    offset: 4,
+    line:   0,
+    column: 4,
+    width:  3,
+    kind:   UnknownFunction{function: "baz"},
+  }
+
   analysis_error! {
     name:   extra_whitespace,
     input:  "a:\n blah\n  blarg",
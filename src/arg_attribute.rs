@@ -0,0 +1,35 @@
+use super::*;
+
+/// The arguments of an `[arg]` attribute, which configures how a recipe
+/// parameter is passed on the command line
+#[derive(PartialEq, Debug, Clone, Serialize, Ord, PartialOrd, Eq)]
+pub(crate) struct ArgAttribute<'src> {
+  pub(crate) choices: Option<StringLiteral<'src>>,
+  pub(crate) help: Option<StringLiteral<'src>>,
+  pub(crate) long: Option<StringLiteral<'src>>,
+  pub(crate) name: StringLiteral<'src>,
+  pub(crate) pattern: Option<StringLiteral<'src>>,
+  pub(crate) short: Option<StringLiteral<'src>>,
+  pub(crate) value: Option<StringLiteral<'src>>,
+  pub(crate) value_type: Option<StringLiteral<'src>>,
+}
+
+impl Display for ArgAttribute<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "({}", self.name)?;
+    for (keyword, argument) in [
+      ("choices", &self.choices),
+      ("help", &self.help),
+      ("long", &self.long),
+      ("pattern", &self.pattern),
+      ("short", &self.short),
+      ("type", &self.value_type),
+      ("value", &self.value),
+    ] {
+      if let Some(argument) = argument {
+        write!(f, ", {keyword}={argument}")?;
+      }
+    }
+    write!(f, ")")
+  }
+}
@@ -24,8 +24,8 @@ pub(crate) struct ArgumentParser<'src: 'run, 'run> {
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) struct ArgumentGroup<'run> {
-  pub(crate) arguments: Vec<&'run str>,
+pub(crate) struct ArgumentGroup {
+  pub(crate) arguments: Vec<Vec<String>>,
   pub(crate) path: Vec<String>,
 }
 
@@ -33,7 +33,7 @@ impl<'src: 'run, 'run> ArgumentParser<'src, 'run> {
   pub(crate) fn parse_arguments(
     root: &'run Justfile<'src>,
     arguments: &'run [&'run str],
-  ) -> RunResult<'src, Vec<ArgumentGroup<'run>>> {
+  ) -> RunResult<'src, Vec<ArgumentGroup>> {
     let mut groups = Vec::new();
 
     let mut invocation_parser = Self {
@@ -53,7 +53,7 @@ impl<'src: 'run, 'run> ArgumentParser<'src, 'run> {
     Ok(groups)
   }
 
-  fn parse_group(&mut self) -> RunResult<'src, ArgumentGroup<'run>> {
+  fn parse_group(&mut self) -> RunResult<'src, ArgumentGroup> {
     let (recipe, path) = if let Some(next) = self.next() {
       if next.contains(':') {
         let module_path =
@@ -75,25 +75,164 @@ impl<'src: 'run, 'run> ArgumentParser<'src, 'run> {
       (recipe, path)
     };
 
-    let rest = self.rest();
+    let (arguments, consumed) = Self::parse_recipe_arguments(recipe, self.rest())?;
 
-    let argument_range = recipe.argument_range();
-    let argument_count = cmp::min(rest.len(), recipe.max_arguments());
-    if !argument_range.range_contains(&argument_count) {
+    self.next += consumed;
+
+    Ok(ArgumentGroup { arguments, path })
+  }
+
+  /// Group the arguments to `recipe` by parameter, returning the groups and
+  /// the number of arguments consumed. Positional arguments fill parameters
+  /// without options in order, stopping at the first argument which doesn't
+  /// fit, which starts the next group.
+  fn parse_recipe_arguments(
+    recipe: &Recipe<'src>,
+    rest: &[&str],
+  ) -> RunResult<'src, (Vec<Vec<String>>, usize)> {
+    let mut arguments = vec![Vec::new(); recipe.parameters.len()];
+
+    let positional = recipe
+      .parameters
+      .iter()
+      .enumerate()
+      .filter(|(_, parameter)| !parameter.is_option())
+      .map(|(i, _)| i)
+      .collect::<Vec<usize>>();
+
+    let options = positional.len() < recipe.parameters.len();
+
+    let mut consumed = 0;
+    let mut found = 0;
+    let mut next_positional = positional.iter().peekable();
+
+    while let Some(&argument) = rest.get(consumed) {
+      if options && Self::is_option(recipe, argument) {
+        consumed += 1;
+
+        let (option, inline) = match argument.split_once('=') {
+          Some((option, value)) if argument.starts_with("--") => (option, Some(value)),
+          _ => (argument, None),
+        };
+
+        let Some(index) = recipe
+          .parameters
+          .iter()
+          .position(|parameter| parameter.options().iter().any(|name| name == option))
+        else {
+          return Err(Error::UnknownOption {
+            recipe: recipe.name(),
+            option: option.into(),
+            suggestion: Self::suggest_option(recipe, option),
+          });
+        };
+
+        let parameter = &recipe.parameters[index];
+
+        if !arguments[index].is_empty() {
+          return Err(Error::DuplicateOption {
+            recipe: recipe.name(),
+            option: option.into(),
+          });
+        }
+
+        let value = if let Some(value) = &parameter.value {
+          if inline.is_some() {
+            return Err(Error::FlagValue {
+              recipe: recipe.name(),
+              option: option.into(),
+            });
+          }
+          value.clone()
+        } else if let Some(value) = inline {
+          value.into()
+        } else if let Some(&value) = rest.get(consumed) {
+          consumed += 1;
+          value.into()
+        } else {
+          return Err(Error::OptionMissingValue {
+            recipe: recipe.name(),
+            option: option.into(),
+          });
+        };
+
+        arguments[index].push(value);
+      } else if let Some(&&index) = next_positional.peek() {
+        consumed += 1;
+        found += 1;
+        arguments[index].push(argument.into());
+        if !recipe.parameters[index].kind.is_variadic() {
+          next_positional.next();
+        }
+      } else {
+        break;
+      }
+    }
+
+    let min = positional
+      .iter()
+      .filter(|&&i| recipe.parameters[i].is_required())
+      .count();
+
+    let max = if positional
+      .iter()
+      .any(|&i| recipe.parameters[i].kind.is_variadic())
+    {
+      usize::MAX - 1
+    } else {
+      positional.len()
+    };
+
+    if !(min..=max).range_contains(&found) {
       return Err(Error::ArgumentCountMismatch {
         recipe: recipe.name(),
         parameters: recipe.parameters.clone(),
-        found: rest.len(),
-        min: recipe.min_arguments(),
-        max: recipe.max_arguments(),
+        found,
+        min,
+        max,
       });
     }
 
-    let arguments = rest[..argument_count].to_vec();
+    for (parameter, arguments) in recipe.parameters.iter().zip(&arguments) {
+      if parameter.is_option() && parameter.is_required() && arguments.is_empty() {
+        return Err(Error::MissingOption {
+          recipe: recipe.name(),
+          option: parameter.options().remove(0),
+        });
+      }
+    }
+
+    Ok((arguments, consumed))
+  }
 
-    self.next += argument_count;
+  /// Arguments starting with `--`, or consisting of `-` and one of the
+  /// recipe's short options, are options, so that arguments like `-1` can
+  /// still be passed positionally
+  fn is_option(recipe: &Recipe, argument: &str) -> bool {
+    if argument.starts_with("--") {
+      return true;
+    }
 
-    Ok(ArgumentGroup { arguments, path })
+    let mut chars = argument.chars();
+
+    match (chars.next(), chars.next(), chars.next()) {
+      (Some('-'), Some(short), None) => recipe
+        .parameters
+        .iter()
+        .any(|parameter| parameter.short == Some(short)),
+      _ => false,
+    }
+  }
+
+  fn suggest_option(recipe: &Recipe, option: &str) -> Option<String> {
+    recipe
+      .parameters
+      .iter()
+      .flat_map(Parameter::options)
+      .map(|name| (edit_distance(option, &name), name))
+      .filter(|(distance, _name)| *distance < 3)
+      .min_by_key(|(distance, _name)| *distance)
+      .map(|(_distance, name)| name)
   }
 
   fn resolve_recipe(
@@ -184,7 +323,7 @@ mod tests {
       ArgumentParser::parse_arguments(&justfile, &["foo"]).unwrap(),
       vec![ArgumentGroup {
         path: vec!["foo".into()],
-        arguments: Vec::new()
+        arguments: Vec::new(),
       }],
     );
   }
@@ -197,7 +336,7 @@ mod tests {
       ArgumentParser::parse_arguments(&justfile, &["foo", "baz"]).unwrap(),
       vec![ArgumentGroup {
         path: vec!["foo".into()],
-        arguments: vec!["baz"],
+        arguments: vec![vec!["baz".into()]],
       }],
     );
   }
@@ -258,7 +397,7 @@ mod tests {
       ArgumentParser::parse_arguments(&compilation.justfile, &["foo", "bar"]).unwrap(),
       vec![ArgumentGroup {
         path: vec!["foo".into(), "bar".into()],
-        arguments: Vec::new()
+        arguments: Vec::new(),
       }],
     );
   }
@@ -387,15 +526,15 @@ BAZ +Z:
       vec![
         ArgumentGroup {
           path: vec!["BAR".into()],
-          arguments: vec!["0"],
+          arguments: vec![vec!["0".into()]],
         },
         ArgumentGroup {
           path: vec!["FOO".into()],
-          arguments: vec!["1", "2"],
+          arguments: vec![vec!["1".into()], vec!["2".into()]],
         },
         ArgumentGroup {
           path: vec!["BAZ".into()],
-          arguments: vec!["3", "4", "5"],
+          arguments: vec![vec!["3".into(), "4".into(), "5".into()]],
         },
       ],
     );
@@ -0,0 +1,37 @@
+use super::*;
+
+/// An integer arithmetic operator, only valid inside of `int(…)`.
+#[derive(PartialEq, Debug, Copy, Clone)]
+pub(crate) enum ArithmeticOperator {
+  /// `+`
+  Addition,
+  /// `*`
+  Multiplication,
+  /// `%`
+  Remainder,
+  /// `-`
+  Subtraction,
+}
+
+impl ArithmeticOperator {
+  /// Apply the operator, returning `None` on overflow or remainder by zero
+  pub(crate) fn apply(self, lhs: i64, rhs: i64) -> Option<i64> {
+    match self {
+      Self::Addition => lhs.checked_add(rhs),
+      Self::Multiplication => lhs.checked_mul(rhs),
+      Self::Remainder => lhs.checked_rem(rhs),
+      Self::Subtraction => lhs.checked_sub(rhs),
+    }
+  }
+}
+
+impl Display for ArithmeticOperator {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Addition => write!(f, "+"),
+      Self::Multiplication => write!(f, "*"),
+      Self::Remainder => write!(f, "%"),
+      Self::Subtraction => write!(f, "-"),
+    }
+  }
+}
@@ -2,6 +2,8 @@ use {super::*, CompileErrorKind::*};
 
 pub(crate) struct AssignmentResolver<'src: 'run, 'run> {
   assignments: &'run Table<'src, Assignment<'src>>,
+  functions: &'run Table<'src, UserFunction<'src>>,
+  modules: &'run Table<'src, UnresolvedModule<'src>>,
   stack: Vec<&'src str>,
   evaluated: BTreeSet<&'src str>,
 }
@@ -9,11 +11,15 @@ pub(crate) struct AssignmentResolver<'src: 'run, 'run> {
 impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
   pub(crate) fn resolve_assignments(
     assignments: &'run Table<'src, Assignment<'src>>,
+    functions: &'run Table<'src, UserFunction<'src>>,
+    modules: &'run Table<'src, UnresolvedModule<'src>>,
   ) -> CompileResult<'src> {
     let mut resolver = Self {
       stack: Vec::new(),
       evaluated: BTreeSet::new(),
       assignments,
+      functions,
+      modules,
     };
 
     for name in assignments.keys() {
@@ -31,6 +37,12 @@ impl<'src: 'run, 'run> AssignmentResolver<'src, 'run> {
     self.stack.push(name);
 
     if let Some(assignment) = self.assignments.get(name) {
+      FunctionResolver::resolve_calls(self.functions, &assignment.value)?;
+
+      for path in assignment.value.qualified_variables() {
+        UnresolvedModule::resolve_variable(self.modules, path)?;
+      }
+
       for variable in assignment.value.variables() {
         let name = variable.lexeme();
 
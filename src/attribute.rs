@@ -9,6 +9,8 @@ use super::*;
 #[strum_discriminants(derive(EnumString, Ord, PartialOrd))]
 #[strum_discriminants(strum(serialize_all = "kebab-case"))]
 pub(crate) enum Attribute<'src> {
+  Arg(Box<ArgAttribute<'src>>),
+  Cache,
   Confirm(Option<StringLiteral<'src>>),
   Doc(Option<StringLiteral<'src>>),
   Extension(StringLiteral<'src>),
@@ -19,9 +21,17 @@ pub(crate) enum Attribute<'src> {
   NoExitMessage,
   NoQuiet,
   Openbsd,
+  Outputs(Vec<StringLiteral<'src>>),
+  Parallel,
   PositionalArguments,
   Private,
+  Retry {
+    backoff: Option<DurationLiteral<'src>>,
+    retries: u32,
+  },
   Script(Option<Interpreter<'src>>),
+  Sources(Vec<StringLiteral<'src>>),
+  Timeout(DurationLiteral<'src>),
   Unix,
   Windows,
   WorkingDirectory(StringLiteral<'src>),
@@ -31,17 +41,21 @@ impl AttributeDiscriminant {
   fn argument_range(self) -> RangeInclusive<usize> {
     match self {
       Self::Confirm | Self::Doc => 0..=1,
-      Self::Group | Self::Extension | Self::WorkingDirectory => 1..=1,
-      Self::Linux
+      Self::Arg | Self::Group | Self::Extension | Self::Timeout | Self::WorkingDirectory => 1..=1,
+      Self::Cache
+      | Self::Linux
       | Self::Macos
       | Self::NoCd
       | Self::NoExitMessage
       | Self::NoQuiet
       | Self::Openbsd
+      | Self::Parallel
       | Self::PositionalArguments
       | Self::Private
       | Self::Unix
       | Self::Windows => 0..=0,
+      Self::Retry => 1..=2,
+      Self::Outputs | Self::Sources => 1..=usize::MAX,
       Self::Script => 0..=usize::MAX,
     }
   }
@@ -51,6 +65,7 @@ impl<'src> Attribute<'src> {
   pub(crate) fn new(
     name: Name<'src>,
     arguments: Vec<StringLiteral<'src>>,
+    keywords: Vec<(Name<'src>, Option<StringLiteral<'src>>)>,
   ) -> CompileResult<'src, Self> {
     let discriminant = name
       .lexeme()
@@ -75,7 +90,20 @@ impl<'src> Attribute<'src> {
       );
     }
 
+    if discriminant != AttributeDiscriminant::Arg {
+      if let Some((keyword, _)) = keywords.first() {
+        return Err(keyword.error(CompileErrorKind::UnknownAttributeKeyword {
+          attribute: name.lexeme(),
+          keyword: keyword.lexeme(),
+        }));
+      }
+    }
+
     Ok(match discriminant {
+      AttributeDiscriminant::Arg => {
+        Self::arg(name, arguments.into_iter().next().unwrap(), keywords)?
+      }
+      AttributeDiscriminant::Cache => Self::Cache,
       AttributeDiscriminant::Confirm => Self::Confirm(arguments.into_iter().next()),
       AttributeDiscriminant::Doc => Self::Doc(arguments.into_iter().next()),
       AttributeDiscriminant::Extension => Self::Extension(arguments.into_iter().next().unwrap()),
@@ -86,8 +114,30 @@ impl<'src> Attribute<'src> {
       AttributeDiscriminant::NoExitMessage => Self::NoExitMessage,
       AttributeDiscriminant::NoQuiet => Self::NoQuiet,
       AttributeDiscriminant::Openbsd => Self::Openbsd,
+      AttributeDiscriminant::Outputs => Self::Outputs(arguments),
+      AttributeDiscriminant::Parallel => Self::Parallel,
       AttributeDiscriminant::PositionalArguments => Self::PositionalArguments,
       AttributeDiscriminant::Private => Self::Private,
+      AttributeDiscriminant::Retry => {
+        let mut arguments = arguments.into_iter();
+        let retries = arguments.next().unwrap();
+        Self::Retry {
+          retries: retries
+            .cooked
+            .parse::<u32>()
+            .ok()
+            .filter(|retries| *retries > 0)
+            .ok_or_else(|| {
+              name.error(CompileErrorKind::InvalidRetryCount {
+                count: retries.cooked.clone(),
+              })
+            })?,
+          backoff: arguments
+            .next()
+            .map(|backoff| Self::duration(name, backoff))
+            .transpose()?,
+        }
+      }
       AttributeDiscriminant::Script => Self::Script({
         let mut arguments = arguments.into_iter();
         arguments.next().map(|command| Interpreter {
@@ -95,6 +145,20 @@ impl<'src> Attribute<'src> {
           arguments: arguments.collect(),
         })
       }),
+      AttributeDiscriminant::Sources => {
+        for source in &arguments {
+          if let Err(error) = glob::Pattern::new(&source.cooked) {
+            return Err(name.error(CompileErrorKind::InvalidSourcePattern {
+              pattern: source.cooked.clone(),
+              message: error.to_string(),
+            }));
+          }
+        }
+        Self::Sources(arguments)
+      }
+      AttributeDiscriminant::Timeout => {
+        Self::Timeout(Self::duration(name, arguments.into_iter().next().unwrap())?)
+      }
       AttributeDiscriminant::Unix => Self::Unix,
       AttributeDiscriminant::Windows => Self::Windows,
       AttributeDiscriminant::WorkingDirectory => {
@@ -103,6 +167,139 @@ impl<'src> Attribute<'src> {
     })
   }
 
+  fn duration(
+    attribute: Name<'src>,
+    literal: StringLiteral<'src>,
+  ) -> CompileResult<'src, DurationLiteral<'src>> {
+    match humantime::parse_duration(&literal.cooked) {
+      Ok(duration) => Ok(DurationLiteral { duration, literal }),
+      Err(error) => Err(attribute.error(CompileErrorKind::InvalidDuration {
+        attribute: attribute.lexeme(),
+        duration: literal.cooked,
+        message: error.to_string(),
+      })),
+    }
+  }
+
+  fn arg(
+    attribute: Name<'src>,
+    name: StringLiteral<'src>,
+    keywords: Vec<(Name<'src>, Option<StringLiteral<'src>>)>,
+  ) -> CompileResult<'src, Self> {
+    let mut choices = None;
+    let mut help = None;
+    let mut long = None;
+    let mut pattern = None;
+    let mut short = None;
+    let mut value = None;
+    let mut value_type = None;
+
+    for (keyword, argument) in keywords {
+      let slot = match keyword.lexeme() {
+        "choices" => &mut choices,
+        "help" => &mut help,
+        "long" => &mut long,
+        "pattern" => &mut pattern,
+        "short" => &mut short,
+        "type" => &mut value_type,
+        "value" => &mut value,
+        _ => {
+          return Err(keyword.error(CompileErrorKind::UnknownAttributeKeyword {
+            attribute: attribute.lexeme(),
+            keyword: keyword.lexeme(),
+          }))
+        }
+      };
+
+      if slot.is_some() {
+        return Err(keyword.error(CompileErrorKind::DuplicateAttributeKeyword {
+          attribute: attribute.lexeme(),
+          keyword: keyword.lexeme(),
+        }));
+      }
+
+      // a bare `long` uses the parameter name as the option name
+      let argument = match argument {
+        Some(argument) => argument,
+        None if keyword.lexeme() == "long" => name.clone(),
+        None => {
+          return Err(
+            keyword.error(CompileErrorKind::AttributeKeywordMissingValue {
+              attribute: attribute.lexeme(),
+              keyword: keyword.lexeme(),
+            }),
+          )
+        }
+      };
+
+      *slot = Some(argument);
+    }
+
+    if let Some(long) = &long {
+      let long = &long.cooked;
+      if long.is_empty()
+        || long.starts_with('-')
+        || long.contains('=')
+        || long.contains(char::is_whitespace)
+      {
+        return Err(attribute.error(CompileErrorKind::InvalidLongOption { long: long.clone() }));
+      }
+    }
+
+    if let Some(short) = &short {
+      let short = &short.cooked;
+      if short.chars().count() != 1 || short == "-" || short.contains(char::is_whitespace) {
+        return Err(attribute.error(CompileErrorKind::InvalidShortOption {
+          short: short.clone(),
+        }));
+      }
+    }
+
+    if value.is_some() && long.is_none() && short.is_none() {
+      return Err(attribute.error(CompileErrorKind::ArgValueWithoutOption {
+        parameter: name.cooked,
+      }));
+    }
+
+    if let Some(choices) = &choices {
+      if choices.cooked.split_whitespace().next().is_none() {
+        return Err(attribute.error(CompileErrorKind::EmptyArgChoices {
+          parameter: name.cooked,
+        }));
+      }
+    }
+
+    if let Some(pattern) = &pattern {
+      if let Err(error) = Pattern::new(&pattern.cooked) {
+        return Err(attribute.error(CompileErrorKind::InvalidArgPattern {
+          parameter: name.cooked,
+          pattern: pattern.cooked.clone(),
+          message: error.to_string(),
+        }));
+      }
+    }
+
+    if let Some(value_type) = &value_type {
+      if value_type.cooked.parse::<ParameterType>().is_err() {
+        return Err(attribute.error(CompileErrorKind::UnknownArgType {
+          parameter: name.cooked,
+          value_type: value_type.cooked.clone(),
+        }));
+      }
+    }
+
+    Ok(Self::Arg(Box::new(ArgAttribute {
+      choices,
+      help,
+      long,
+      name,
+      pattern,
+      short,
+      value,
+      value_type,
+    })))
+  }
+
   pub(crate) fn discriminant(&self) -> AttributeDiscriminant {
     self.into()
   }
@@ -112,7 +309,10 @@ impl<'src> Attribute<'src> {
   }
 
   pub(crate) fn repeatable(&self) -> bool {
-    matches!(self, Attribute::Group(_))
+    matches!(
+      self,
+      Attribute::Arg(_) | Attribute::Group(_) | Attribute::Outputs(_) | Attribute::Sources(_)
+    )
   }
 }
 
@@ -121,13 +321,33 @@ impl Display for Attribute<'_> {
     write!(f, "{}", self.name())?;
 
     match self {
+      Self::Arg(arg) => write!(f, "{arg}")?,
       Self::Confirm(Some(argument))
       | Self::Doc(Some(argument))
       | Self::Extension(argument)
       | Self::Group(argument)
       | Self::WorkingDirectory(argument) => write!(f, "({argument})")?,
+      Self::Timeout(timeout) => write!(f, "({timeout})")?,
+      Self::Outputs(arguments) | Self::Sources(arguments) => {
+        write!(f, "(")?;
+        for (i, argument) in arguments.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{argument}")?;
+        }
+        write!(f, ")")?;
+      }
+      Self::Retry { backoff, retries } => {
+        write!(f, "({retries}")?;
+        if let Some(backoff) = backoff {
+          write!(f, ", {backoff}")?;
+        }
+        write!(f, ")")?;
+      }
       Self::Script(Some(shell)) => write!(f, "({shell})")?,
-      Self::Confirm(None)
+      Self::Cache
+      | Self::Confirm(None)
       | Self::Doc(None)
       | Self::Linux
       | Self::Macos
@@ -135,6 +355,7 @@ impl Display for Attribute<'_> {
       | Self::NoExitMessage
       | Self::NoQuiet
       | Self::Openbsd
+      | Self::Parallel
       | Self::PositionalArguments
       | Self::Private
       | Self::Script(None)
@@ -0,0 +1,129 @@
+use super::*;
+
+/// Hashes of the last successful run of each `[cache]` recipe and set of
+/// arguments in a justfile, stored in `cache_directory()/just/<path-hash>`,
+/// where `<path-hash>` is a hash of the justfile's path.
+///
+/// Since the justfile's contents are part of each recipe's hash, editing the
+/// justfile invalidates its entries without moving its cache directory.
+pub(crate) struct Cache {
+  directory: PathBuf,
+}
+
+#[derive(Deserialize, Serialize)]
+pub(crate) struct CacheEntry {
+  pub(crate) arguments: Vec<String>,
+  pub(crate) hash: String,
+  pub(crate) recipe: String,
+}
+
+impl Cache {
+  pub(crate) fn new(justfile: &Path) -> RunResult<'static, Self> {
+    let directory = dirs::cache_dir().ok_or(Error::CacheDirectory)?;
+
+    Ok(Self {
+      directory: directory
+        .join("just")
+        .join(blake3::hash(justfile.to_string_lossy().as_bytes()).to_string()),
+    })
+  }
+
+  pub(crate) fn directory(&self) -> &Path {
+    &self.directory
+  }
+
+  fn path(&self, recipe: &Namepath, arguments: &[String]) -> PathBuf {
+    let mut hasher = blake3::Hasher::new();
+
+    for item in iter::once(recipe.to_string()).chain(arguments.iter().cloned()) {
+      hasher.update(&item.len().to_le_bytes());
+      hasher.update(item.as_bytes());
+    }
+
+    self.directory.join(format!("{}.json", hasher.finalize()))
+  }
+
+  fn read(path: &Path) -> RunResult<'static, Option<CacheEntry>> {
+    match fs::read_to_string(path) {
+      // unreadable entries are treated as missing, and overwritten on the next run
+      Ok(json) => Ok(serde_json::from_str(&json).ok()),
+      Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => Ok(None),
+      Err(io_error) => Err(Error::CacheIo {
+        path: path.into(),
+        io_error,
+      }),
+    }
+  }
+
+  pub(crate) fn get(
+    &self,
+    recipe: &Namepath,
+    arguments: &[String],
+  ) -> RunResult<'static, Option<String>> {
+    Ok(Self::read(&self.path(recipe, arguments))?.map(|entry| entry.hash))
+  }
+
+  pub(crate) fn insert(
+    &self,
+    recipe: &Namepath,
+    arguments: &[String],
+    hash: String,
+  ) -> RunResult<'static> {
+    fs::create_dir_all(&self.directory).map_err(|io_error| Error::CacheIo {
+      path: self.directory.clone(),
+      io_error,
+    })?;
+
+    let path = self.path(recipe, arguments);
+
+    let entry = CacheEntry {
+      arguments: arguments.into(),
+      hash,
+      recipe: recipe.to_string(),
+    };
+
+    fs::write(&path, serde_json::to_string(&entry).unwrap())
+      .map_err(|io_error| Error::CacheIo { path, io_error })
+  }
+
+  pub(crate) fn entries(&self) -> RunResult<'static, Vec<CacheEntry>> {
+    let entries = match fs::read_dir(&self.directory) {
+      Ok(entries) => entries,
+      Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+      Err(io_error) => {
+        return Err(Error::CacheIo {
+          path: self.directory.clone(),
+          io_error,
+        })
+      }
+    };
+
+    let mut cached = Vec::new();
+
+    for entry in entries {
+      let entry = entry.map_err(|io_error| Error::CacheIo {
+        path: self.directory.clone(),
+        io_error,
+      })?;
+
+      if let Some(entry) = Self::read(&entry.path())? {
+        cached.push(entry);
+      }
+    }
+
+    cached.sort_by(|a, b| (&a.recipe, &a.arguments).cmp(&(&b.recipe, &b.arguments)));
+
+    Ok(cached)
+  }
+
+  pub(crate) fn clear(&self) -> RunResult<'static> {
+    match fs::remove_dir_all(&self.directory) {
+      Ok(()) => Ok(()),
+      Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => Ok(()),
+      Err(io_error) => Err(Error::CacheIo {
+        path: self.directory.clone(),
+        io_error,
+      }),
+    }
+  }
+}
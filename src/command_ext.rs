@@ -7,9 +7,17 @@ pub(crate) trait CommandExt {
     dotenv: &BTreeMap<String, String>,
     scope: &Scope,
     unexports: &HashSet<String>,
+    working_directory: &Path,
   );
 
   fn export_scope(&mut self, settings: &Settings, scope: &Scope, unexports: &HashSet<String>);
+
+  /// The `PATH` that will be searched for this command's program
+  fn search_path(&self) -> Option<OsString>;
+
+  fn status_guard(&mut self) -> io::Result<ExitStatus>;
+
+  fn status_guard_timeout(&mut self, timeout: Option<Duration>) -> io::Result<ExitStatus>;
 }
 
 impl CommandExt for Command {
@@ -19,6 +27,7 @@ impl CommandExt for Command {
     dotenv: &BTreeMap<String, String>,
     scope: &Scope,
     unexports: &HashSet<String>,
+    working_directory: &Path,
   ) {
     for (name, value) in dotenv {
       self.env(name, value);
@@ -27,6 +36,20 @@ impl CommandExt for Command {
     if let Some(parent) = scope.parent() {
       self.export_scope(settings, parent, unexports);
     }
+
+    if !settings.path.is_empty() {
+      let directories = settings
+        .path
+        .iter()
+        .map(|directory| working_directory.join(directory))
+        .chain(self.search_path().iter().flat_map(env::split_paths))
+        .collect::<Vec<PathBuf>>();
+
+      // directories containing the path separator cannot be added to `PATH`
+      if let Ok(path) = env::join_paths(directories) {
+        self.env("PATH", path);
+      }
+    }
   }
 
   fn export_scope(&mut self, settings: &Settings, scope: &Scope, unexports: &HashSet<String>) {
@@ -40,8 +63,26 @@ impl CommandExt for Command {
 
     for binding in scope.bindings() {
       if binding.export || (settings.export && !binding.constant) {
-        self.env(binding.name.lexeme(), &binding.value);
+        self.env(binding.name.lexeme(), binding.value.to_string());
+      }
+    }
+  }
+
+  fn search_path(&self) -> Option<OsString> {
+    for (key, value) in self.get_envs() {
+      if key == "PATH" {
+        return value.map(OsStr::to_owned);
       }
     }
+
+    env::var_os("PATH")
+  }
+
+  fn status_guard(&mut self) -> io::Result<ExitStatus> {
+    self.status_guard_timeout(None)
+  }
+
+  fn status_guard_timeout(&mut self, timeout: Option<Duration>) -> io::Result<ExitStatus> {
+    InterruptHandler::guard(|| InterruptHandler::spawn(self, timeout))
   }
 }
@@ -2,7 +2,7 @@ use super::*;
 
 #[derive(Debug)]
 pub(crate) struct Compilation<'src> {
-  pub(crate) asts: HashMap<PathBuf, Ast<'src>>,
+  pub(crate) asts: HashMap<(Vec<&'src str>, PathBuf), Ast<'src>>,
   pub(crate) justfile: Justfile<'src>,
   pub(crate) root: PathBuf,
   pub(crate) srcs: HashMap<PathBuf, &'src str>,
@@ -10,7 +10,7 @@ pub(crate) struct Compilation<'src> {
 
 impl<'src> Compilation<'src> {
   pub(crate) fn root_ast(&self) -> &Ast<'src> {
-    self.asts.get(&self.root).unwrap()
+    self.asts.get(&(Vec::new(), self.root.clone())).unwrap()
   }
 
   pub(crate) fn root_src(&self) -> &'src str {
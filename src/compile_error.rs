@@ -32,6 +32,10 @@ impl Display for CompileError<'_> {
     use CompileErrorKind::*;
 
     match &*self.kind {
+      ArgValueWithoutOption { parameter } => write!(
+        f,
+        "Attribute `arg` for parameter `{parameter}` has `value` but no `long` or `short` option"
+      ),
       AttributeArgumentCountMismatch {
         attribute,
         found,
@@ -53,7 +57,30 @@ impl Display for CompileError<'_> {
           write!(f, "at most {max} {}", Count("argument", *max))
         }
       }
+      AttributeKeywordMissingValue { attribute, keyword } => {
+        write!(
+          f,
+          "Attribute `{attribute}` keyword `{keyword}` requires a value"
+        )
+      }
       BacktickShebang => write!(f, "Backticks may not start with `#!`"),
+      BuiltinFunctionRedefinition { function } => {
+        write!(f, "Function `{function}` redefines a built-in function")
+      }
+      CircularFunctionCall {
+        function,
+        ref circle,
+      } => {
+        if circle.len() == 2 {
+          write!(f, "Function `{function}` calls itself")
+        } else {
+          write!(
+            f,
+            "Function `{function}` has circular call chain `{}`",
+            circle.join(" -> "),
+          )
+        }
+      }
       CircularRecipeDependency { recipe, ref circle } => {
         if circle.len() == 2 {
           write!(f, "Recipe `{recipe}` depends on itself")
@@ -100,12 +127,43 @@ impl Display for CompileError<'_> {
           write!(f, "at most {max} {}", Count("argument", *max))
         }
       }
+      DuplicateArgAttribute { recipe, parameter } => write!(
+        f,
+        "Recipe `{recipe}` has multiple `arg` attributes for parameter `{parameter}`"
+      ),
       DuplicateAttribute { attribute, first } => write!(
         f,
         "Recipe attribute `{attribute}` first used on line {} is duplicated on line {}",
         first.ordinal(),
         self.token.line.ordinal(),
       ),
+      DuplicateAttributeKeyword { attribute, keyword } => {
+        write!(
+          f,
+          "Attribute `{attribute}` has duplicate keyword `{keyword}`"
+        )
+      }
+      DuplicateFunction { function } => {
+        write!(f, "Function `{function}` has multiple definitions")
+      }
+      DuplicateFunctionParameter {
+        function,
+        parameter,
+      } => {
+        write!(
+          f,
+          "Function `{function}` has duplicate parameter `{parameter}`"
+        )
+      }
+      DuplicateMatchArm { pattern } => {
+        write!(f, "`match` expression has duplicate arm `{pattern}`")
+      }
+      DuplicateModuleArgument { argument, module } => {
+        write!(f, "Module `{module}` has duplicate argument `{argument}`")
+      }
+      DuplicateOption { recipe, option } => {
+        write!(f, "Recipe `{recipe}` has duplicate option `{option}`")
+      }
       DuplicateParameter { recipe, parameter } => {
         write!(f, "Recipe `{recipe}` has duplicate parameter `{parameter}`")
       }
@@ -121,6 +179,10 @@ impl Display for CompileError<'_> {
       DuplicateUnexport { variable } => {
         write!(f, "Variable `{variable}` is unexported multiple times")
       }
+      EmptyArgChoices { parameter } => write!(
+        f,
+        "Attribute `arg` for parameter `{parameter}` has empty `choices`"
+      ),
       ExpectedKeyword { expected, found } => {
         let expected = List::or_ticked(expected);
         if found.kind == TokenKind::Identifier {
@@ -154,6 +216,7 @@ impl Display for CompileError<'_> {
         f,
         "The `!include` directive has been stabilized as `import`"
       ),
+      IndentedFormatString => write!(f, "Indented format strings are not supported"),
       InconsistentLeadingWhitespace { expected, found } => write!(
         f,
         "Recipe line has inconsistent leading whitespace. Recipe started with `{}` but found \
@@ -166,6 +229,14 @@ impl Display for CompileError<'_> {
         "Internal error, this may indicate a bug in just: {message}\n\
            consider filing an issue: https://github.com/casey/just/issues/new"
       ),
+      InvalidArgPattern {
+        parameter,
+        pattern,
+        message,
+      } => write!(
+        f,
+        "Attribute `arg` for parameter `{parameter}` has invalid pattern `{pattern}`: {message}"
+      ),
       InvalidAttribute {
         item_name,
         item_kind,
@@ -175,6 +246,14 @@ impl Display for CompileError<'_> {
         "{item_kind} `{item_name}` has invalid attribute `{}`",
         attribute.name(),
       ),
+      InvalidDuration {
+        attribute,
+        duration,
+        message,
+      } => write!(
+        f,
+        "Attribute `{attribute}` has invalid duration `{duration}`: {message}"
+      ),
       InvalidEscapeSequence { character } => write!(
         f,
         "`\\{}` is not a valid escape sequence",
@@ -186,6 +265,37 @@ impl Display for CompileError<'_> {
           _ => character.escape_default().collect(),
         }
       ),
+      InvalidFlagValue {
+        parameter,
+        value,
+        expected,
+      } => write!(
+        f,
+        "Flag value `{value}` for parameter `{parameter}` is not {expected}"
+      ),
+      InvalidLongOption { long } => write!(
+        f,
+        "Long option `{long}` may not be empty, start with `-`, or contain `=` or whitespace"
+      ),
+      InvalidParameterDefault {
+        parameter,
+        default,
+        expected,
+      } => write!(
+        f,
+        "Default `{default}` for parameter `{parameter}` is not {expected}"
+      ),
+      InvalidRetryCount { count } => {
+        write!(f, "Retry count `{count}` is not a positive integer")
+      }
+      InvalidShortOption { short } => write!(
+        f,
+        "Short option `{short}` must be a single character other than `-` or whitespace"
+      ),
+      InvalidSourcePattern { pattern, message } => write!(
+        f,
+        "Attribute `sources` has invalid pattern `{pattern}`: {message}"
+      ),
       MismatchedClosingDelimiter {
         open,
         open_line,
@@ -197,6 +307,13 @@ impl Display for CompileError<'_> {
         open.open(),
         open_line.ordinal(),
       ),
+      MissingMatchDefault => write!(f, "`match` expression has no default arm `_`"),
+      MissingModuleArgument { module, parameter } => {
+        write!(
+          f,
+          "Module `{module}` is missing argument for parameter `{parameter}`"
+        )
+      }
       MixedLeadingWhitespace { whitespace } => write!(
         f,
         "Found a mix of tabs and spaces in leading whitespace: `{}`\nLeading whitespace may \
@@ -245,6 +362,15 @@ impl Display for CompileError<'_> {
         f,
         "Non-default parameter `{parameter}` follows default parameter"
       ),
+      UnboundModuleParameter { parameter } => write!(
+        f,
+        "Module parameter `{parameter}` of root justfile must have a default value"
+      ),
+      UndefinedArgAttribute { recipe, parameter } => write!(
+        f,
+        "Recipe `{recipe}` has `arg` attribute for undefined parameter `{parameter}`"
+      ),
+      UndefinedQualifiedVariable { variable } => write!(f, "Variable `{variable}` not defined"),
       UndefinedVariable { variable } => write!(f, "Variable `{variable}` not defined"),
       UnexpectedCharacter { expected } => {
         write!(f, "Expected character {}", List::or_ticked(expected))
@@ -285,17 +411,40 @@ impl Display for CompileError<'_> {
       UnknownAliasTarget { alias, target } => {
         write!(f, "Alias `{alias}` has an unknown target `{target}`")
       }
+      UnknownArgType {
+        parameter,
+        value_type,
+      } => write!(
+        f,
+        "Attribute `arg` for parameter `{parameter}` has unknown type `{value_type}`, expected \
+        `boolean`, `integer`, or `path`"
+      ),
       UnknownAttribute { attribute } => write!(f, "Unknown attribute `{attribute}`"),
+      UnknownAttributeKeyword { attribute, keyword } => {
+        write!(f, "Attribute `{attribute}` has unknown keyword `{keyword}`")
+      }
       UnknownDependency { recipe, unknown } => {
         write!(f, "Recipe `{recipe}` has unknown dependency `{unknown}`")
       }
       UnknownFunction { function } => write!(f, "Call to unknown function `{function}`"),
+      UnknownModuleArgument { argument, module } => {
+        write!(f, "Module `{module}` has no parameter `{argument}`")
+      }
+      UnknownVariableModule { module, variable } => {
+        write!(
+          f,
+          "Variable `{variable}` refers to unknown module `{module}`"
+        )
+      }
       UnknownSetting { setting } => write!(f, "Unknown setting `{setting}`"),
       UnknownStartOfToken => write!(f, "Unknown start of token:"),
       UnpairedCarriageReturn => write!(f, "Unpaired carriage return"),
       UnterminatedBacktick => write!(f, "Unterminated backtick"),
       UnterminatedInterpolation => write!(f, "Unterminated interpolation"),
       UnterminatedString => write!(f, "Unterminated string"),
+      VariadicOption { parameter } => {
+        write!(f, "Variadic parameter `{parameter}` cannot be an option")
+      }
     }
   }
 }
@@ -2,16 +2,30 @@ use super::*;
 
 #[derive(Debug, PartialEq)]
 pub(crate) enum CompileErrorKind<'src> {
+  ArgValueWithoutOption {
+    parameter: String,
+  },
   AttributeArgumentCountMismatch {
     attribute: &'src str,
     found: usize,
     min: usize,
     max: usize,
   },
+  AttributeKeywordMissingValue {
+    attribute: &'src str,
+    keyword: &'src str,
+  },
   BacktickShebang,
+  BuiltinFunctionRedefinition {
+    function: &'src str,
+  },
+  CircularFunctionCall {
+    function: &'src str,
+    circle: Vec<&'src str>,
+  },
   CircularRecipeDependency {
     recipe: &'src str,
-    circle: Vec<&'src str>,
+    circle: Vec<String>,
   },
   CircularVariableDependency {
     variable: &'src str,
@@ -29,10 +43,36 @@ pub(crate) enum CompileErrorKind<'src> {
     name: &'src str,
     second_type: &'static str,
   },
+  DuplicateArgAttribute {
+    recipe: &'src str,
+    parameter: String,
+  },
   DuplicateAttribute {
     attribute: &'src str,
     first: usize,
   },
+  DuplicateAttributeKeyword {
+    attribute: &'src str,
+    keyword: &'src str,
+  },
+  DuplicateFunction {
+    function: &'src str,
+  },
+  DuplicateFunctionParameter {
+    function: &'src str,
+    parameter: &'src str,
+  },
+  DuplicateMatchArm {
+    pattern: String,
+  },
+  DuplicateModuleArgument {
+    argument: &'src str,
+    module: &'src str,
+  },
+  DuplicateOption {
+    recipe: &'src str,
+    option: String,
+  },
   DuplicateParameter {
     recipe: &'src str,
     parameter: &'src str,
@@ -47,6 +87,9 @@ pub(crate) enum CompileErrorKind<'src> {
   DuplicateUnexport {
     variable: &'src str,
   },
+  EmptyArgChoices {
+    parameter: String,
+  },
   ExpectedKeyword {
     expected: Vec<Keyword>,
     found: Token<'src>,
@@ -64,6 +107,7 @@ pub(crate) enum CompileErrorKind<'src> {
     expected: RangeInclusive<usize>,
   },
   Include,
+  IndentedFormatString,
   InconsistentLeadingWhitespace {
     expected: &'src str,
     found: &'src str,
@@ -71,19 +115,57 @@ pub(crate) enum CompileErrorKind<'src> {
   Internal {
     message: String,
   },
+  InvalidArgPattern {
+    parameter: String,
+    pattern: String,
+    message: String,
+  },
   InvalidAttribute {
     item_kind: &'static str,
     item_name: &'src str,
-    attribute: Attribute<'src>,
+    attribute: Box<Attribute<'src>>,
+  },
+  InvalidDuration {
+    attribute: &'src str,
+    duration: String,
+    message: String,
   },
   InvalidEscapeSequence {
     character: char,
   },
+  InvalidFlagValue {
+    parameter: &'src str,
+    value: String,
+    expected: String,
+  },
+  InvalidLongOption {
+    long: String,
+  },
+  InvalidParameterDefault {
+    parameter: &'src str,
+    default: String,
+    expected: String,
+  },
+  InvalidRetryCount {
+    count: String,
+  },
+  InvalidShortOption {
+    short: String,
+  },
+  InvalidSourcePattern {
+    pattern: String,
+    message: String,
+  },
   MismatchedClosingDelimiter {
     close: Delimiter,
     open: Delimiter,
     open_line: usize,
   },
+  MissingMatchDefault,
+  MissingModuleArgument {
+    module: &'src str,
+    parameter: &'src str,
+  },
   MixedLeadingWhitespace {
     whitespace: &'src str,
   },
@@ -103,6 +185,16 @@ pub(crate) enum CompileErrorKind<'src> {
   ShellExpansion {
     err: shellexpand::LookupError<env::VarError>,
   },
+  UnboundModuleParameter {
+    parameter: &'src str,
+  },
+  UndefinedArgAttribute {
+    recipe: &'src str,
+    parameter: String,
+  },
+  UndefinedQualifiedVariable {
+    variable: String,
+  },
   UndefinedVariable {
     variable: &'src str,
   },
@@ -137,12 +229,28 @@ pub(crate) enum CompileErrorKind<'src> {
     alias: &'src str,
     target: &'src str,
   },
+  UnknownArgType {
+    parameter: String,
+    value_type: String,
+  },
   UnknownAttribute {
     attribute: &'src str,
   },
+  UnknownAttributeKeyword {
+    attribute: &'src str,
+    keyword: &'src str,
+  },
   UnknownDependency {
     recipe: &'src str,
-    unknown: &'src str,
+    unknown: String,
+  },
+  UnknownModuleArgument {
+    argument: &'src str,
+    module: &'src str,
+  },
+  UnknownVariableModule {
+    module: String,
+    variable: String,
   },
   UnknownFunction {
     function: &'src str,
@@ -155,4 +263,7 @@ pub(crate) enum CompileErrorKind<'src> {
   UnterminatedBacktick,
   UnterminatedInterpolation,
   UnterminatedString,
+  VariadicOption {
+    parameter: &'src str,
+  },
 }
@@ -7,7 +7,7 @@ impl Compiler {
     loader: &'src Loader,
     root: &Path,
   ) -> RunResult<'src, Compilation<'src>> {
-    let mut asts = HashMap::<PathBuf, Ast>::new();
+    let mut asts = HashMap::<(Vec<&str>, PathBuf), Ast>::new();
     let mut loaded = Vec::new();
     let mut paths = HashMap::<PathBuf, PathBuf>::new();
     let mut srcs = HashMap::<PathBuf, &str>::new();
@@ -91,7 +91,7 @@ impl Compiler {
         }
       }
 
-      asts.insert(current.path, ast.clone());
+      asts.insert((current.namepath.lexemes(), current.path), ast.clone());
     }
 
     let justfile = Analyzer::analyze(&asts, None, &[], &loaded, None, &paths, root)?;
@@ -211,12 +211,12 @@ impl Compiler {
   }
 
   #[cfg(test)]
-  pub(crate) fn test_compile(src: &str) -> CompileResult<Justfile> {
+  pub(crate) fn test_compile(src: &str) -> CompileResult<'_, Justfile<'_>> {
     let tokens = Lexer::test_lex(src)?;
     let ast = Parser::parse(0, &[], &Namepath::default(), &tokens, &PathBuf::new())?;
     let root = PathBuf::from("justfile");
-    let mut asts: HashMap<PathBuf, Ast> = HashMap::new();
-    asts.insert(root.clone(), ast);
+    let mut asts: HashMap<(Vec<&str>, PathBuf), Ast> = HashMap::new();
+    asts.insert((Vec::new(), root.clone()), ast);
     let mut paths: HashMap<PathBuf, PathBuf> = HashMap::new();
     paths.insert(root.clone(), root.clone());
     Analyzer::analyze(&asts, None, &[], &[], None, &paths, &root)
@@ -124,6 +124,10 @@ const ZSH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
     r"'-s+[Show recipe at <PATH>]: :(_just_commands)' \
 '--show=[Show recipe at <PATH>]: :(_just_commands)' \",
   ),
+  (
+    r"'()--usage=[Print usage information for recipe at <PATH>]:PATH:_default' \",
+    r"'--usage=[Print usage information for recipe at <PATH>]: :(_just_commands)' \",
+  ),
   (
     "'*::ARGUMENTS -- Overrides and recipe(s) to run, defaulting to the first recipe in the \
      justfile:_default' \\
@@ -272,6 +276,13 @@ const BASH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
                         COMPREPLY=( $(compgen -W "${recipes}" -- "${cur}") )
                         return 0
                     fi
+                elif [[ ${cword} -gt 1 ]]; then
+                    local choices=$(just --argument-choices "${words[1]}" 2> /dev/null | sed -n "$((cword - 1))p")
+
+                    if [[ -n ${choices} ]]; then
+                        COMPREPLY=( $(compgen -W "${choices}" -- "${cur}") )
+                        return 0
+                    fi
                 fi"#,
   ),
   (
@@ -290,7 +301,7 @@ const BASH_COMPLETION_REPLACEMENTS: &[(&str, &str)] = &[
     else
         cur="${COMP_WORDS[COMP_CWORD]}"
         prev="${COMP_WORDS[COMP_CWORD-1]}"
-        words=$COMP_WORDS
+        words=("${COMP_WORDS[@]}")
         cword=$COMP_CWORD
     fi
 "#,
@@ -5,8 +5,16 @@ use super::*;
 pub(crate) enum ConditionalOperator {
   /// `==`
   Equality,
+  /// `>`
+  Greater,
+  /// `>=`
+  GreaterEqual,
   /// `!=`
   Inequality,
+  /// `<`
+  Less,
+  /// `<=`
+  LessEqual,
   /// `=~`
   RegexMatch,
   /// `!~`
@@ -17,7 +25,11 @@ impl Display for ConditionalOperator {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     match self {
       Self::Equality => write!(f, "=="),
+      Self::Greater => write!(f, ">"),
+      Self::GreaterEqual => write!(f, ">="),
       Self::Inequality => write!(f, "!="),
+      Self::Less => write!(f, "<"),
+      Self::LessEqual => write!(f, "<="),
       Self::RegexMatch => write!(f, "=~"),
       Self::RegexMismatch => write!(f, "!~"),
     }
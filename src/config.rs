@@ -5,7 +5,6 @@ use {
       styling::{AnsiColor, Effects},
       FalseyValueParser, Styles,
     },
-    parser::ValuesRef,
     value_parser, Arg, ArgAction, ArgGroup, ArgMatches, Command,
   },
 };
@@ -22,8 +21,11 @@ pub(crate) struct Config {
   pub(crate) dry_run: bool,
   pub(crate) dump_format: DumpFormat,
   pub(crate) explain: bool,
+  pub(crate) force: bool,
+  pub(crate) graph_format: GraphFormat,
   pub(crate) highlight: bool,
   pub(crate) invocation_directory: PathBuf,
+  pub(crate) jobs: Option<NonZeroUsize>,
   pub(crate) list_heading: String,
   pub(crate) list_prefix: String,
   pub(crate) list_submodules: bool,
@@ -31,20 +33,27 @@ pub(crate) struct Config {
   pub(crate) no_aliases: bool,
   pub(crate) no_dependencies: bool,
   pub(crate) one: bool,
+  pub(crate) plan: bool,
   pub(crate) search_config: SearchConfig,
   pub(crate) shell: Option<String>,
   pub(crate) shell_args: Option<Vec<String>>,
   pub(crate) shell_command: bool,
   pub(crate) subcommand: Subcommand,
+  pub(crate) timeout: Option<Duration>,
   pub(crate) timestamp: bool,
   pub(crate) timestamp_format: String,
   pub(crate) unsorted: bool,
   pub(crate) unstable: bool,
   pub(crate) verbosity: Verbosity,
+  pub(crate) watch: bool,
+  pub(crate) watch_globs: Vec<String>,
   pub(crate) yes: bool,
 }
 
 mod cmd {
+  pub(crate) const ARGUMENT_CHOICES: &str = "ARGUMENT-CHOICES";
+  pub(crate) const CACHE_CLEAR: &str = "CACHE-CLEAR";
+  pub(crate) const CACHE_STATUS: &str = "CACHE-STATUS";
   pub(crate) const CHANGELOG: &str = "CHANGELOG";
   pub(crate) const CHOOSE: &str = "CHOOSE";
   pub(crate) const COMMAND: &str = "COMMAND";
@@ -53,6 +62,7 @@ mod cmd {
   pub(crate) const EDIT: &str = "EDIT";
   pub(crate) const EVALUATE: &str = "EVALUATE";
   pub(crate) const FORMAT: &str = "FORMAT";
+  pub(crate) const GRAPH: &str = "GRAPH";
   pub(crate) const GROUPS: &str = "GROUPS";
   pub(crate) const INIT: &str = "INIT";
   pub(crate) const LIST: &str = "LIST";
@@ -60,9 +70,13 @@ mod cmd {
   pub(crate) const REQUEST: &str = "REQUEST";
   pub(crate) const SHOW: &str = "SHOW";
   pub(crate) const SUMMARY: &str = "SUMMARY";
+  pub(crate) const USAGE: &str = "USAGE";
   pub(crate) const VARIABLES: &str = "VARIABLES";
 
   pub(crate) const ALL: &[&str] = &[
+    ARGUMENT_CHOICES,
+    CACHE_CLEAR,
+    CACHE_STATUS,
     CHANGELOG,
     CHOOSE,
     COMMAND,
@@ -71,17 +85,29 @@ mod cmd {
     EDIT,
     EVALUATE,
     FORMAT,
+    GRAPH,
     INIT,
     LIST,
     MAN,
     REQUEST,
     SHOW,
     SUMMARY,
+    USAGE,
     VARIABLES,
   ];
 
-  pub(crate) const ARGLESS: &[&str] =
-    &[CHANGELOG, DUMP, EDIT, FORMAT, INIT, MAN, SUMMARY, VARIABLES];
+  pub(crate) const ARGLESS: &[&str] = &[
+    CACHE_CLEAR,
+    CACHE_STATUS,
+    CHANGELOG,
+    DUMP,
+    EDIT,
+    FORMAT,
+    INIT,
+    MAN,
+    SUMMARY,
+    VARIABLES,
+  ];
 
   pub(crate) const HEADING: &str = "Commands";
 }
@@ -100,8 +126,11 @@ mod arg {
   pub(crate) const DRY_RUN: &str = "DRY-RUN";
   pub(crate) const DUMP_FORMAT: &str = "DUMP-FORMAT";
   pub(crate) const EXPLAIN: &str = "EXPLAIN";
+  pub(crate) const FORCE: &str = "FORCE";
   pub(crate) const GLOBAL_JUSTFILE: &str = "GLOBAL-JUSTFILE";
+  pub(crate) const GRAPH_FORMAT: &str = "GRAPH-FORMAT";
   pub(crate) const HIGHLIGHT: &str = "HIGHLIGHT";
+  pub(crate) const JOBS: &str = "JOBS";
   pub(crate) const JUSTFILE: &str = "JUSTFILE";
   pub(crate) const LIST_HEADING: &str = "LIST-HEADING";
   pub(crate) const LIST_PREFIX: &str = "LIST-PREFIX";
@@ -111,16 +140,20 @@ mod arg {
   pub(crate) const NO_DOTENV: &str = "NO-DOTENV";
   pub(crate) const NO_HIGHLIGHT: &str = "NO-HIGHLIGHT";
   pub(crate) const ONE: &str = "ONE";
+  pub(crate) const PLAN: &str = "PLAN";
   pub(crate) const QUIET: &str = "QUIET";
   pub(crate) const SET: &str = "SET";
   pub(crate) const SHELL: &str = "SHELL";
   pub(crate) const SHELL_ARG: &str = "SHELL-ARG";
   pub(crate) const SHELL_COMMAND: &str = "SHELL-COMMAND";
+  pub(crate) const TIMEOUT: &str = "TIMEOUT";
   pub(crate) const TIMESTAMP: &str = "TIMESTAMP";
   pub(crate) const TIMESTAMP_FORMAT: &str = "TIMESTAMP-FORMAT";
   pub(crate) const UNSORTED: &str = "UNSORTED";
   pub(crate) const UNSTABLE: &str = "UNSTABLE";
   pub(crate) const VERBOSE: &str = "VERBOSE";
+  pub(crate) const WATCH: &str = "WATCH";
+  pub(crate) const WATCH_GLOB: &str = "WATCH-GLOB";
   pub(crate) const WORKING_DIRECTORY: &str = "WORKING-DIRECTORY";
   pub(crate) const YES: &str = "YES";
 }
@@ -239,6 +272,13 @@ impl Config {
           .env("JUST_EXPLAIN")
           .help("Print recipe doc comment before running it"),
       )
+      .arg(
+        Arg::new(arg::FORCE)
+          .action(ArgAction::SetTrue)
+          .long("force")
+          .env("JUST_FORCE")
+          .help("Run recipes even if their outputs are up to date"),
+      )
       .arg(
         Arg::new(arg::GLOBAL_JUSTFILE)
           .action(ArgAction::SetTrue)
@@ -248,6 +288,16 @@ impl Config {
           .conflicts_with(arg::WORKING_DIRECTORY)
           .help("Use global justfile"),
       )
+      .arg(
+        Arg::new(arg::GRAPH_FORMAT)
+          .long("graph-format")
+          .env("JUST_GRAPH_FORMAT")
+          .action(ArgAction::Set)
+          .value_parser(clap::value_parser!(GraphFormat))
+          .default_value("dot")
+          .value_name("FORMAT")
+          .help("Print dependency graph as <FORMAT>"),
+      )
       .arg(
         Arg::new(arg::HIGHLIGHT)
           .long("highlight")
@@ -256,6 +306,15 @@ impl Config {
           .help("Highlight echoed recipe lines in bold")
           .overrides_with(arg::NO_HIGHLIGHT),
       )
+      .arg(
+        Arg::new(arg::JOBS)
+          .short('j')
+          .long("jobs")
+          .env("JUST_JOBS")
+          .action(ArgAction::Set)
+          .value_parser(value_parser!(NonZeroUsize))
+          .help("Run at most <JOBS> dependencies of `[parallel]` recipes at the same time"),
+      )
       .arg(
         Arg::new(arg::JUSTFILE)
           .short('f')
@@ -328,6 +387,14 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("Forbid multiple recipes from being invoked on the command line"),
       )
+      .arg(
+        Arg::new(arg::PLAN)
+          .long("plan")
+          .env("JUST_PLAN")
+          .action(ArgAction::SetTrue)
+          .conflicts_with_all([arg::DRY_RUN, arg::WATCH, "SUBCOMMAND"])
+          .help("Print the recipes that would run, in order, without running them or backticks"),
+      )
       .arg(
         Arg::new(arg::QUIET)
           .short('q')
@@ -373,6 +440,18 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("Invoke <COMMAND> with the shell used to run recipe lines and backticks"),
       )
+      .arg(
+        Arg::new(arg::TIMEOUT)
+          .long("timeout")
+          .env("JUST_TIMEOUT")
+          .action(ArgAction::Set)
+          .value_name("DURATION")
+          .value_parser(humantime::parse_duration)
+          .help(
+            "Terminate recipes that run for longer than <DURATION>, e.g. `30s` or `10m`, \
+            unless overridden with `[timeout]`",
+          ),
+      )
       .arg(
         Arg::new(arg::TIMESTAMP)
           .action(ArgAction::SetTrue)
@@ -412,6 +491,22 @@ impl Config {
           .action(ArgAction::Count)
           .help("Use verbose output"),
       )
+      .arg(
+        Arg::new(arg::WATCH)
+          .long("watch")
+          .env("JUST_WATCH")
+          .action(ArgAction::SetTrue)
+          .conflicts_with("SUBCOMMAND")
+          .help("Rerun recipes when the justfile or files matching `--watch-glob` change"),
+      )
+      .arg(
+        Arg::new(arg::WATCH_GLOB)
+          .long("watch-glob")
+          .action(ArgAction::Append)
+          .value_name("PATTERN")
+          .requires(arg::WATCH)
+          .help("Also watch files matching <PATTERN>, relative to the working directory"),
+      )
       .arg(
         Arg::new(arg::WORKING_DIRECTORY)
           .short('d')
@@ -429,6 +524,34 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help("Automatically confirm all recipes."),
       )
+      .arg(
+        Arg::new(cmd::ARGUMENT_CHOICES)
+          .long("argument-choices")
+          .num_args(1..)
+          .action(ArgAction::Set)
+          .value_name("PATH")
+          .conflicts_with(arg::ARGUMENTS)
+          .hide(true)
+          .help(
+            "Print the choices of each positional parameter of recipe at <PATH>, one line per \
+            parameter. Used by shell completion scripts.",
+          )
+          .help_heading(cmd::HEADING),
+      )
+      .arg(
+        Arg::new(cmd::CACHE_CLEAR)
+          .long("cache-clear")
+          .action(ArgAction::SetTrue)
+          .help("Remove cached hashes of `[cache]` recipes")
+          .help_heading(cmd::HEADING),
+      )
+      .arg(
+        Arg::new(cmd::CACHE_STATUS)
+          .long("cache-status")
+          .action(ArgAction::SetTrue)
+          .help("Print cached hashes of `[cache]` recipes")
+          .help_heading(cmd::HEADING),
+      )
       .arg(
         Arg::new(cmd::CHANGELOG)
           .long("changelog")
@@ -492,7 +615,8 @@ impl Config {
           .action(ArgAction::SetTrue)
           .help(
             "Evaluate and print all variables. If a variable name is given as an argument, only \
-             print that variable's value.",
+             print that variable's value. Variables and modules in submodules may be given as \
+             `::`-separated paths.",
           )
           .help_heading(cmd::HEADING),
       )
@@ -511,6 +635,16 @@ impl Config {
           .help("List recipe groups")
           .help_heading(cmd::HEADING),
       )
+      .arg(
+        Arg::new(cmd::GRAPH)
+          .long("graph")
+          .action(ArgAction::SetTrue)
+          .help(
+            "Print dependency graph. If a recipe or module path is given as an argument, only \
+             print the graph of that recipe or module.",
+          )
+          .help_heading(cmd::HEADING),
+      )
       .arg(
         Arg::new(cmd::INIT)
           .long("init")
@@ -566,6 +700,16 @@ impl Config {
           .help("List names of available recipes")
           .help_heading(cmd::HEADING),
       )
+      .arg(
+        Arg::new(cmd::USAGE)
+          .long("usage")
+          .num_args(1..)
+          .action(ArgAction::Set)
+          .value_name("PATH")
+          .conflicts_with(arg::ARGUMENTS)
+          .help("Print usage information for recipe at <PATH>")
+          .help_heading(cmd::HEADING),
+      )
       .arg(
         Arg::new(cmd::VARIABLES)
           .long("variables")
@@ -582,8 +726,12 @@ impl Config {
       )
   }
 
-  fn parse_module_path(values: ValuesRef<String>) -> ConfigResult<ModulePath> {
-    let path = values.clone().map(|s| (*s).as_str()).collect::<Vec<&str>>();
+  fn parse_module_path<'a>(
+    values: impl IntoIterator<Item = &'a String>,
+  ) -> ConfigResult<ModulePath> {
+    let values = values.into_iter().collect::<Vec<&String>>();
+
+    let path = values.iter().map(|s| s.as_str()).collect::<Vec<&str>>();
 
     let path = if path.len() == 1 && path[0].contains(' ') {
       path[0].split_whitespace().collect::<Vec<&str>>()
@@ -595,7 +743,7 @@ impl Config {
       .as_slice()
       .try_into()
       .map_err(|()| ConfigError::ModulePath {
-        path: values.cloned().collect(),
+        path: values.into_iter().cloned().collect(),
       })
   }
 
@@ -679,7 +827,15 @@ impl Config {
       }
     }
 
-    let subcommand = if matches.get_flag(cmd::CHANGELOG) {
+    let subcommand = if let Some(path) = matches.get_many::<String>(cmd::ARGUMENT_CHOICES) {
+      Subcommand::ArgumentChoices {
+        path: Self::parse_module_path(path)?,
+      }
+    } else if matches.get_flag(cmd::CACHE_CLEAR) {
+      Subcommand::CacheClear
+    } else if matches.get_flag(cmd::CACHE_STATUS) {
+      Subcommand::CacheStatus
+    } else if matches.get_flag(cmd::CHANGELOG) {
       Subcommand::Changelog
     } else if matches.get_flag(cmd::CHOOSE) {
       Subcommand::Choose {
@@ -719,6 +875,10 @@ impl Config {
       Subcommand::Format
     } else if matches.get_flag(cmd::GROUPS) {
       Subcommand::Groups
+    } else if matches.get_flag(cmd::GRAPH) {
+      Subcommand::Graph {
+        path: Self::parse_module_path(&positional.arguments)?,
+      }
     } else if matches.get_flag(cmd::INIT) {
       Subcommand::Init
     } else if let Some(path) = matches.get_many::<String>(cmd::LIST) {
@@ -738,6 +898,10 @@ impl Config {
       }
     } else if matches.get_flag(cmd::SUMMARY) {
       Subcommand::Summary
+    } else if let Some(path) = matches.get_many::<String>(cmd::USAGE) {
+      Subcommand::Usage {
+        path: Self::parse_module_path(path)?,
+      }
     } else if matches.get_flag(cmd::VARIABLES) {
       Subcommand::Variables
     } else {
@@ -772,8 +936,11 @@ impl Config {
         .unwrap()
         .clone(),
       explain,
+      force: matches.get_flag(arg::FORCE),
+      graph_format: *matches.get_one::<GraphFormat>(arg::GRAPH_FORMAT).unwrap(),
       highlight: !matches.get_flag(arg::NO_HIGHLIGHT),
       invocation_directory: env::current_dir().context(config_error::CurrentDirContext)?,
+      jobs: matches.get_one::<NonZeroUsize>(arg::JOBS).copied(),
       list_heading: matches.get_one::<String>(arg::LIST_HEADING).unwrap().into(),
       list_prefix: matches.get_one::<String>(arg::LIST_PREFIX).unwrap().into(),
       list_submodules: matches.get_flag(arg::LIST_SUBMODULES),
@@ -781,6 +948,7 @@ impl Config {
       no_aliases: matches.get_flag(arg::NO_ALIASES),
       no_dependencies: matches.get_flag(arg::NO_DEPS),
       one: matches.get_flag(arg::ONE),
+      plan: matches.get_flag(arg::PLAN),
       search_config,
       shell: matches.get_one::<String>(arg::SHELL).map(Into::into),
       shell_args: if matches.get_flag(arg::CLEAR_SHELL_ARGS) {
@@ -792,6 +960,7 @@ impl Config {
       },
       shell_command: matches.get_flag(arg::SHELL_COMMAND),
       subcommand,
+      timeout: matches.get_one::<Duration>(arg::TIMEOUT).copied(),
       timestamp: matches.get_flag(arg::TIMESTAMP),
       timestamp_format: matches
         .get_one::<String>(arg::TIMESTAMP_FORMAT)
@@ -804,6 +973,11 @@ impl Config {
       } else {
         Verbosity::from_flag_occurrences(matches.get_count(arg::VERBOSE))
       },
+      watch: matches.get_flag(arg::WATCH),
+      watch_globs: matches
+        .get_many::<String>(arg::WATCH_GLOB)
+        .map(|globs| globs.map(Into::into).collect())
+        .unwrap_or_default(),
       yes: matches.get_flag(arg::YES),
     })
   }
@@ -1334,6 +1508,18 @@ mod tests {
     },
   }
 
+  test! {
+    name: subcommand_graph,
+    args: ["--graph"],
+    subcommand: Subcommand::Graph { path: ModulePath { path: Vec::new(), spaced: false } },
+  }
+
+  test! {
+    name: subcommand_graph_arguments,
+    args: ["--graph", "foo::bar"],
+    subcommand: Subcommand::Graph { path: ModulePath { path: vec!["foo".into(), "bar".into()], spaced: false } },
+  }
+
   test! {
     name: subcommand_list_long,
     args: ["--list"],
@@ -1375,6 +1561,18 @@ mod tests {
     },
   }
 
+  test! {
+    name: subcommand_argument_choices,
+    args: ["--argument-choices", "deploy"],
+    subcommand: Subcommand::ArgumentChoices { path: ModulePath { path: vec!["deploy".into()], spaced: false } },
+  }
+
+  test! {
+    name: subcommand_usage,
+    args: ["--usage", "build"],
+    subcommand: Subcommand::Usage { path: ModulePath { path: vec!["build".into()], spaced: false } },
+  }
+
   test! {
     name: subcommand_summary,
     args: ["--summary"],
@@ -0,0 +1,72 @@
+use super::*;
+
+/// Structured data format read by `json_get`, `toml_get`, `yaml_get`, and
+/// their `_file` variants
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum DataFormat {
+  Json,
+  Toml,
+  Yaml,
+}
+
+impl DataFormat {
+  /// Parse `text`, converting the result to a JSON value so that all formats
+  /// can be queried in the same way
+  pub(crate) fn parse(self, text: &str) -> Result<serde_json::Value, String> {
+    match self {
+      Self::Json => serde_json::from_str(text).map_err(|err| err.to_string()),
+      Self::Toml => text
+        .parse::<toml::Table>()
+        .map(|table| Self::toml_to_json(toml::Value::Table(table)))
+        .map_err(|err| Self::toml_error(text, &err)),
+      Self::Yaml => serde_yaml_ng::from_str(text).map_err(|err| err.to_string()),
+    }
+  }
+
+  /// Format TOML errors like JSON and YAML errors, since the `Display`
+  /// implementation of `toml::de::Error` includes a multi-line source snippet
+  fn toml_error(text: &str, err: &toml::de::Error) -> String {
+    let message = err.message().trim_end();
+
+    let Some(span) = err.span() else {
+      return message.into();
+    };
+
+    let before = &text[..span.start];
+    let line = before.matches('\n').count() + 1;
+    let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+
+    format!("{message} at line {line} column {column}")
+  }
+
+  fn toml_to_json(value: toml::Value) -> serde_json::Value {
+    use serde_json::Value;
+
+    match value {
+      toml::Value::Array(array) => array.into_iter().map(Self::toml_to_json).collect(),
+      toml::Value::Boolean(boolean) => boolean.into(),
+      toml::Value::Datetime(datetime) => datetime.to_string().into(),
+      toml::Value::Float(float) => {
+        serde_json::Number::from_f64(float).map_or_else(|| float.to_string().into(), Value::Number)
+      }
+      toml::Value::Integer(integer) => integer.into(),
+      toml::Value::String(string) => string.into(),
+      toml::Value::Table(table) => Value::Object(
+        table
+          .into_iter()
+          .map(|(key, value)| (key, Self::toml_to_json(value)))
+          .collect(),
+      ),
+    }
+  }
+}
+
+impl Display for DataFormat {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Json => write!(f, "JSON"),
+      Self::Toml => write!(f, "TOML"),
+      Self::Yaml => write!(f, "YAML"),
+    }
+  }
+}
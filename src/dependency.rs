@@ -3,21 +3,28 @@ use super::*;
 #[derive(PartialEq, Debug, Serialize)]
 pub(crate) struct Dependency<'src> {
   pub(crate) arguments: Vec<Expression<'src>>,
-  #[serde(serialize_with = "keyed::serialize")]
-  pub(crate) recipe: Rc<Recipe<'src>>,
+  pub(crate) iteration: Option<Iteration<'src>>,
+  #[serde(rename = "recipe")]
+  pub(crate) path: Namepath<'src>,
+  #[serde(skip)]
+  pub(crate) recipe: Arc<Recipe<'src>>,
 }
 
 impl Display for Dependency<'_> {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     if self.arguments.is_empty() {
-      write!(f, "{}", self.recipe.name())
+      write!(f, "{}", self.path)
     } else {
-      write!(f, "({}", self.recipe.name())?;
+      write!(f, "({}", self.path)?;
 
       for argument in &self.arguments {
         write!(f, " {argument}")?;
       }
 
+      if let Some(iteration) = &self.iteration {
+        write!(f, " {iteration}")?;
+      }
+
       write!(f, ")")
     }
   }
@@ -0,0 +1,24 @@
+use super::*;
+
+/// A string literal containing a duration, like `'10m'`, parsed when the
+/// attribute containing it is parsed
+#[derive(PartialEq, Debug, Clone, Ord, Eq, PartialOrd)]
+pub(crate) struct DurationLiteral<'src> {
+  pub(crate) duration: Duration,
+  pub(crate) literal: StringLiteral<'src>,
+}
+
+impl Display for DurationLiteral<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.literal)
+  }
+}
+
+impl Serialize for DurationLiteral<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    self.literal.serialize(serializer)
+  }
+}
@@ -20,6 +20,11 @@ pub(crate) enum Error<'src> {
     token: Token<'src>,
     output_error: OutputError,
   },
+  CacheDirectory,
+  CacheIo {
+    path: PathBuf,
+    io_error: io::Error,
+  },
   RuntimeDirIo {
     io_error: io::Error,
     path: PathBuf,
@@ -80,6 +85,10 @@ pub(crate) enum Error<'src> {
     dotenv_error: dotenvy::Error,
   },
   DotenvRequired,
+  DuplicateOption {
+    recipe: &'src str,
+    option: String,
+  },
   DumpJson {
     source: serde_json::Error,
   },
@@ -98,9 +107,19 @@ pub(crate) enum Error<'src> {
   ExcessInvocations {
     invocations: usize,
   },
+  ExpectedInteger {
+    value: String,
+  },
+  ExpectedList {
+    value: String,
+  },
   ExpectedSubmoduleButFoundRecipe {
     path: String,
   },
+  FlagValue {
+    recipe: &'src str,
+    option: String,
+  },
   FormatCheckFoundDiff,
   FunctionCall {
     function: Name<'src>,
@@ -113,32 +132,72 @@ pub(crate) enum Error<'src> {
   InitExists {
     justfile: PathBuf,
   },
+  IntegerOverflow {
+    lhs: i64,
+    operator: ArithmeticOperator,
+    rhs: i64,
+  },
   Internal {
     message: String,
   },
+  InvalidArgument {
+    recipe: &'src str,
+    parameter: &'src str,
+    argument: String,
+    expected: String,
+  },
+  InvalidListIndex {
+    index: String,
+  },
+  InvalidParameterDefault {
+    recipe: &'src str,
+    parameter: &'src str,
+    default: String,
+    expected: String,
+  },
   Io {
     recipe: &'src str,
     io_error: io::Error,
   },
+  ListIndexOutOfRange {
+    index: usize,
+    length: usize,
+  },
   Load {
     path: PathBuf,
     io_error: io::Error,
   },
+  MissingOption {
+    recipe: &'src str,
+    option: String,
+  },
   MissingImportFile {
     path: Token<'src>,
   },
   MissingModuleFile {
     module: Name<'src>,
   },
+  ModificationTime {
+    recipe: &'src str,
+    path: PathBuf,
+    io_error: io::Error,
+  },
   NoChoosableRecipes,
   NoDefaultRecipe,
   NoRecipes,
   NotConfirmed {
     recipe: &'src str,
   },
+  OptionMissingValue {
+    recipe: &'src str,
+    option: String,
+  },
   RegexCompile {
     source: regex::Error,
   },
+  RemainderByZero {
+    lhs: i64,
+  },
   Script {
     command: String,
     io_error: io::Error,
@@ -158,6 +217,11 @@ pub(crate) enum Error<'src> {
     line_number: Option<usize>,
     signal: i32,
   },
+  SourceRead {
+    recipe: &'src str,
+    path: PathBuf,
+    io_error: io::Error,
+  },
   StdoutIo {
     io_error: io::Error,
   },
@@ -168,6 +232,12 @@ pub(crate) enum Error<'src> {
   TempfileIo {
     io_error: io::Error,
   },
+  Timeout {
+    recipe: &'src str,
+    line_number: Option<usize>,
+    elapsed: Duration,
+    timeout: Duration,
+  },
   Unknown {
     recipe: &'src str,
     line_number: Option<usize>,
@@ -175,6 +245,11 @@ pub(crate) enum Error<'src> {
   UnknownSubmodule {
     path: String,
   },
+  UnknownOption {
+    recipe: &'src str,
+    option: String,
+    suggestion: Option<String>,
+  },
   UnknownOverrides {
     overrides: Vec<String>,
   },
@@ -185,6 +260,13 @@ pub(crate) enum Error<'src> {
   UnstableFeature {
     unstable_feature: UnstableFeature,
   },
+  Watch {
+    notify_error: notify::Error,
+  },
+  WatchPattern {
+    pattern: String,
+    pattern_error: glob::PatternError,
+  },
   WriteJustfile {
     justfile: PathBuf,
     io_error: io::Error,
@@ -298,6 +380,12 @@ impl ColorDisplay for Error<'_> {
           }?,
         OutputError::Utf8(utf8_error) => write!(f, "Backtick succeeded but stdout was not utf8: {utf8_error}")?,
       }
+      CacheDirectory => {
+        write!(f, "Failed to get cache directory")?;
+      }
+      CacheIo { path, io_error } => {
+        write!(f, "I/O error in cache `{}`: {io_error}", path.display())?;
+      }
       ChooserInvoke { shell_binary, shell_arguments, chooser, io_error} => {
         let chooser = chooser.to_string_lossy();
         write!(f, "Chooser `{shell_binary} {shell_arguments} {chooser}` invocation failed: {io_error}")?;
@@ -359,6 +447,9 @@ impl ColorDisplay for Error<'_> {
       DotenvRequired => {
         write!(f, "Dotenv file not found")?;
       }
+      DuplicateOption { recipe, option } => {
+        write!(f, "Option `{option}` passed to recipe `{recipe}` more than once")?;
+      }
       DumpJson { source } => {
         write!(f, "Failed to dump JSON to stdout: {source}")?;
       }
@@ -379,9 +470,18 @@ impl ColorDisplay for Error<'_> {
       ExcessInvocations { invocations } => {
         write!(f, "Expected 1 command-line recipe invocation but found {invocations}.")?;
       },
+      ExpectedInteger { value } => {
+        write!(f, "Expected integer but found `{value}`")?;
+      }
+      ExpectedList { value } => {
+        write!(f, "Expected list but found string `{value}`")?;
+      }
       ExpectedSubmoduleButFoundRecipe { path } => {
         write!(f, "Expected submodule at `{path}` but found recipe.")?;
       },
+      FlagValue { recipe, option } => {
+        write!(f, "Flag `{option}` of recipe `{recipe}` does not take a value")?;
+      }
       FormatCheckFoundDiff => {
         write!(f, "Formatted justfile differs from original.")?;
       }
@@ -398,10 +498,38 @@ impl ColorDisplay for Error<'_> {
       InitExists { justfile } => {
         write!(f, "Justfile `{}` already exists", justfile.display())?;
       }
+      IntegerOverflow { lhs, operator, rhs } => {
+        write!(f, "Integer overflow evaluating `{lhs} {operator} {rhs}`")?;
+      }
       Internal { message } => {
         write!(f, "Internal runtime error, this may indicate a bug in just: {message} \
                    consider filing an issue: https://github.com/casey/just/issues/new")?;
       }
+      InvalidArgument {
+        recipe,
+        parameter,
+        argument,
+        expected,
+      } => {
+        write!(
+          f,
+          "Argument `{argument}` for parameter `{parameter}` of recipe `{recipe}` is not {expected}"
+        )?;
+      }
+      InvalidListIndex { index } => {
+        write!(f, "List index `{index}` is not a non-negative integer")?;
+      }
+      InvalidParameterDefault {
+        recipe,
+        parameter,
+        default,
+        expected,
+      } => {
+        write!(
+          f,
+          "Default `{default}` for parameter `{parameter}` of recipe `{recipe}` is not {expected}"
+        )?;
+      }
       Io { recipe, io_error } => {
         match io_error.kind() {
           io::ErrorKind::NotFound => write!(f, "Recipe `{recipe}` could not be run because just could not find the shell: {io_error}"),
@@ -409,18 +537,31 @@ impl ColorDisplay for Error<'_> {
           _ => write!(f, "Recipe `{recipe}` could not be run because of an IO error while launching the shell: {io_error}"),
         }?;
       }
+      ListIndexOutOfRange { index, length } => {
+        write!(f, "List index {index} is out of range for list of length {length}")?;
+      }
       Load { io_error, path } => {
         write!(f, "Failed to read justfile at `{}`: {io_error}", path.display())?;
       }
+      MissingOption { recipe, option } => {
+        write!(f, "Recipe `{recipe}` requires option `{option}`")?;
+      }
       MissingImportFile { .. } => write!(f, "Could not find source file for import.")?,
       MissingModuleFile { module } => write!(f, "Could not find source file for module `{module}`.")?,
+      ModificationTime { recipe, path, io_error } => {
+        write!(f, "Recipe `{recipe}` could not read modification time of `{}`: {io_error}", path.display())?;
+      }
       NoChoosableRecipes => write!(f, "Justfile contains no choosable recipes.")?,
       NoDefaultRecipe => write!(f, "Justfile contains no default recipe.")?,
       NoRecipes => write!(f, "Justfile contains no recipes.")?,
       NotConfirmed { recipe } => {
         write!(f, "Recipe `{recipe}` was not confirmed")?;
       }
+      OptionMissingValue { recipe, option } => {
+        write!(f, "Option `{option}` of recipe `{recipe}` requires a value")?;
+      }
       RegexCompile { source } => write!(f, "{source}")?,
+      RemainderByZero { lhs } => write!(f, "Remainder by zero evaluating `{lhs} % 0`")?,
       RuntimeDirIo { io_error, path } => {
         write!(f, "I/O error in runtime dir `{}`: {io_error}", path.display())?;
       }
@@ -442,6 +583,9 @@ impl ColorDisplay for Error<'_> {
           write!(f, "Recipe `{recipe}` was terminated by signal {signal}")?;
         }
       }
+      SourceRead { recipe, path, io_error } => {
+        write!(f, "Recipe `{recipe}` could not read source `{}`: {io_error}", path.display())?;
+      }
       StdoutIo { io_error } => {
         write!(f, "I/O error writing to stdout: {io_error}?")?;
       }
@@ -452,6 +596,17 @@ impl ColorDisplay for Error<'_> {
       TempfileIo { io_error } => {
         write!(f, "Tempfile I/O error: {io_error}")?;
       }
+      Timeout { recipe, line_number, elapsed, timeout } => {
+        let elapsed = humantime::format_duration(Duration::from_millis(
+          elapsed.as_millis().try_into().unwrap_or(u64::MAX),
+        ));
+        let timeout = humantime::format_duration(*timeout);
+        if let Some(n) = line_number {
+          write!(f, "Recipe `{recipe}` timed out on line {n} after {elapsed} (limit {timeout})")?;
+        } else {
+          write!(f, "Recipe `{recipe}` timed out after {elapsed} (limit {timeout})")?;
+        }
+      }
       Unknown { recipe, line_number} => {
         if let Some(n) = line_number {
           write!(f, "Recipe `{recipe}` failed on line {n} for an unknown reason")?;
@@ -462,6 +617,12 @@ impl ColorDisplay for Error<'_> {
       UnknownSubmodule { path } => {
         write!(f, "Justfile does not contain submodule `{path}`")?;
       }
+      UnknownOption { recipe, option, suggestion } => {
+        write!(f, "Recipe `{recipe}` does not have option `{option}`")?;
+        if let Some(suggestion) = suggestion {
+          write!(f, "\nDid you mean `{suggestion}`?")?;
+        }
+      }
       UnknownOverrides { overrides } => {
         let count = Count("Variable", overrides.len());
         let overrides = List::and_ticked(overrides);
@@ -476,6 +637,12 @@ impl ColorDisplay for Error<'_> {
       UnstableFeature { unstable_feature } => {
         write!(f, "{unstable_feature} Invoke `just` with `--unstable`, set the `JUST_UNSTABLE` environment variable, or add `set unstable` to your `justfile` to enable unstable features.")?;
       }
+      Watch { notify_error } => {
+        write!(f, "Failed to watch files: {notify_error}")?;
+      }
+      WatchPattern { pattern, pattern_error } => {
+        write!(f, "Invalid watch pattern `{pattern}`: {pattern_error}")?;
+      }
       WriteJustfile { justfile, io_error } => {
         let justfile = justfile.display();
         write!(f, "Failed to write justfile to `{justfile}`: {io_error}")?;
@@ -491,7 +658,7 @@ impl ColorDisplay for Error<'_> {
       writeln!(f)?;
       write!(f, "{}:\n    just {recipe}", color.message().paint("usage"))?;
       for param in parameters {
-        write!(f, " {}", param.color_display(color))?;
+        write!(f, " {}", param.signature().color_display(color))?;
       }
     }
 
@@ -30,6 +30,12 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     let mut scope = context.scope.child();
     let mut unknown_overrides = Vec::new();
 
+    // bind variables already evaluated on behalf of the parent module
+    if module.is_submodule() {
+      let (bindings, submodules) = parent.submodule(&[module.name()]);
+      scope.extend(bindings, submodules);
+    }
+
     for (name, value) in overrides {
       if let Some(assignment) = module.assignments.get(name) {
         scope.bind(Binding {
@@ -38,7 +44,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
           file_depth: 0,
           name: assignment.name,
           private: assignment.private,
-          value: value.clone(),
+          value: value.clone().into(),
         });
       } else {
         unknown_overrides.push(name.clone());
@@ -62,14 +68,18 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       evaluator.evaluate_assignment(assignment)?;
     }
 
+    for path in module.qualified_variables() {
+      evaluator.evaluate_qualified_variable(path)?;
+    }
+
     Ok(evaluator.scope)
   }
 
-  fn evaluate_assignment(&mut self, assignment: &Assignment<'src>) -> RunResult<'src, &str> {
+  fn evaluate_assignment(&mut self, assignment: &Assignment<'src>) -> RunResult<'src, &Value> {
     let name = assignment.name.lexeme();
 
     if !self.scope.bound(name) {
-      let value = self.evaluate_expression(&assignment.value)?;
+      let value = self.evaluate_value(&assignment.value)?;
       self.scope.bind(Binding {
         constant: false,
         export: assignment.export,
@@ -87,17 +97,24 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     &mut self,
     expression: &Expression<'src>,
   ) -> RunResult<'src, String> {
+    Ok(self.evaluate_value(expression)?.into_string())
+  }
+
+  pub(crate) fn evaluate_value(&mut self, expression: &Expression<'src>) -> RunResult<'src, Value> {
     match expression {
       Expression::And { lhs, rhs } => {
-        let lhs = self.evaluate_expression(lhs)?;
+        let lhs = self.evaluate_value(lhs)?;
         if lhs.is_empty() {
-          return Ok(String::new());
+          return Ok(Value::default());
         }
-        self.evaluate_expression(rhs)
+        self.evaluate_value(rhs)
+      }
+      Expression::Arithmetic { .. } | Expression::Int { .. } => {
+        Ok(self.evaluate_integer(expression)?.to_string().into())
       }
       Expression::Assert { condition, error } => {
         if self.evaluate_condition(condition)? {
-          Ok(String::new())
+          Ok(Value::default())
         } else {
           Err(Error::Assert {
             message: self.evaluate_expression(error)?,
@@ -105,10 +122,10 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         }
       }
       Expression::Backtick { contents, token } => {
-        if self.context.config.dry_run {
-          Ok(format!("`{contents}`"))
+        if self.context.config.dry_run || self.context.config.plan {
+          Ok(format!("`{contents}`").into())
         } else {
-          Ok(self.run_backtick(contents, token)?)
+          Ok(self.run_backtick(contents, token)?.into())
         }
       }
       Expression::Call { thunk } => {
@@ -119,6 +136,26 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             let arg = self.evaluate_expression(arg)?;
             function(function::Context::new(self, thunk.name()), &arg)
           }
+          UnaryList { function, arg, .. } => {
+            let arg =
+              self
+                .evaluate_value(arg)?
+                .into_list()
+                .map_err(|value| Error::FunctionCall {
+                  function: thunk.name(),
+                  message: format!("Expected list but found string `{value}`"),
+                })?;
+            function(function::Context::new(self, thunk.name()), &arg)
+          }
+          UnaryToList { function, arg, .. } => {
+            let arg = self.evaluate_expression(arg)?;
+            return function(function::Context::new(self, thunk.name()), &arg)
+              .map(Value::List)
+              .map_err(|message| Error::FunctionCall {
+                function: thunk.name(),
+                message,
+              });
+          }
           UnaryOpt {
             function,
             args: (a, b),
@@ -184,14 +221,36 @@ impl<'src, 'run> Evaluator<'src, 'run> {
             let c = self.evaluate_expression(c)?;
             function(function::Context::new(self, thunk.name()), &a, &b, &c)
           }
+          User { name, args } => return self.evaluate_user_function(*name, args),
         };
-        result.map_err(|message| Error::FunctionCall {
-          function: thunk.name(),
-          message,
-        })
+        result
+          .map(Value::String)
+          .map_err(|message| Error::FunctionCall {
+            function: thunk.name(),
+            message,
+          })
+      }
+      Expression::Comprehension {
+        condition,
+        element,
+        iterable,
+        name,
+      } => {
+        let evaluated =
+          self.evaluate_iteration(condition.as_ref(), iterable, *name, |evaluator| {
+            evaluator.evaluate_expression(element)
+          })?;
+
+        Ok(Value::List(evaluated))
       }
       Expression::Concatenation { lhs, rhs } => {
-        Ok(self.evaluate_expression(lhs)? + &self.evaluate_expression(rhs)?)
+        match (self.evaluate_value(lhs)?, self.evaluate_value(rhs)?) {
+          (Value::List(mut lhs), Value::List(rhs)) => {
+            lhs.extend(rhs);
+            Ok(Value::List(lhs))
+          }
+          (lhs, rhs) => Ok(Value::String(lhs.into_string() + &rhs.to_string())),
+        }
       }
       Expression::Conditional {
         condition,
@@ -199,41 +258,322 @@ impl<'src, 'run> Evaluator<'src, 'run> {
         otherwise,
       } => {
         if self.evaluate_condition(condition)? {
-          self.evaluate_expression(then)
+          self.evaluate_value(then)
         } else {
-          self.evaluate_expression(otherwise)
+          self.evaluate_value(otherwise)
         }
       }
-      Expression::Group { contents } => self.evaluate_expression(contents),
-      Expression::Join { lhs: None, rhs } => Ok("/".to_string() + &self.evaluate_expression(rhs)?),
+      Expression::FormatString { contents, .. } => Ok(self.evaluate_expression(contents)?.into()),
+      Expression::Group { contents } | Expression::Splat { contents } => {
+        self.evaluate_value(contents)
+      }
+      Expression::Index { lhs, index } => {
+        let list = self.evaluate_list(lhs)?;
+
+        let index = self.evaluate_expression(index)?;
+
+        let Ok(index) = index.parse::<usize>() else {
+          return Err(Error::InvalidListIndex { index });
+        };
+
+        let length = list.len();
+
+        list
+          .into_iter()
+          .nth(index)
+          .map(Value::String)
+          .ok_or(Error::ListIndexOutOfRange { index, length })
+      }
+      Expression::Integer { token } => Ok(token.lexeme().to_owned().into()),
+      Expression::Join { lhs: None, rhs } => {
+        Ok(("/".to_string() + &self.evaluate_expression(rhs)?).into())
+      }
       Expression::Join {
         lhs: Some(lhs),
         rhs,
-      } => Ok(self.evaluate_expression(lhs)? + "/" + &self.evaluate_expression(rhs)?),
+      } => Ok((self.evaluate_expression(lhs)? + "/" + &self.evaluate_expression(rhs)?).into()),
+      Expression::List { elements } => Ok(Value::List(self.evaluate_elements(elements)?)),
+      Expression::Match {
+        arms,
+        default,
+        subject,
+      } => {
+        let subject = self.evaluate_expression(subject)?;
+        let value = arms
+          .iter()
+          .find(|arm| arm.pattern.cooked == subject)
+          .map_or(&**default, |arm| &arm.value);
+        self.evaluate_value(value)
+      }
       Expression::Or { lhs, rhs } => {
-        let lhs = self.evaluate_expression(lhs)?;
+        let lhs = self.evaluate_value(lhs)?;
         if !lhs.is_empty() {
           return Ok(lhs);
         }
-        self.evaluate_expression(rhs)
+        self.evaluate_value(rhs)
       }
-      Expression::StringLiteral { string_literal } => Ok(string_literal.cooked.clone()),
+      Expression::QualifiedVariable { path } => self.evaluate_qualified_variable(path),
+      Expression::StringLiteral { string_literal } => Ok(string_literal.cooked.clone().into()),
       Expression::Variable { name, .. } => {
         let variable = name.lexeme();
-        if let Some(value) = self.scope.value(variable) {
-          Ok(value.to_owned())
-        } else if let Some(assignment) = self
-          .assignments
-          .and_then(|assignments| assignments.get(variable))
-        {
-          Ok(self.evaluate_assignment(assignment)?.to_owned())
-        } else {
-          Err(Error::Internal {
+
+        // a module's own variables take precedence over bindings inherited
+        // from parent scopes, even if they have not yet been evaluated
+        if !self.scope.bound(variable) {
+          if let Some(assignment) = self
+            .assignments
+            .and_then(|assignments| assignments.get(variable))
+          {
+            return Ok(self.evaluate_assignment(assignment)?.clone());
+          }
+        }
+
+        self
+          .scope
+          .value(variable)
+          .cloned()
+          .ok_or_else(|| Error::Internal {
             message: format!("attempted to evaluate undefined variable `{variable}`"),
           })
+      }
+    }
+  }
+
+  /// Evaluate `path`, a reference to a variable in a submodule. While the
+  /// current module's assignments are being evaluated, the variable is
+  /// evaluated on demand in the submodule's own scope, whose ancestors are the
+  /// scopes of the modules between it and the current module, and recorded so
+  /// that it is bound when the submodule's own scope is evaluated. Afterwards,
+  /// the recorded value is used.
+  fn evaluate_qualified_variable(&mut self, path: &Namepath<'src>) -> RunResult<'src, Value> {
+    let (name, modules) = path.split_last();
+    let modules = modules
+      .iter()
+      .map(|name| name.lexeme())
+      .collect::<Vec<&str>>();
+
+    if self.assignments.is_none() {
+      return self
+        .context
+        .scope
+        .submodule_value(&modules, name.lexeme())
+        .cloned()
+        .ok_or_else(|| Error::Internal {
+          message: format!("attempted to evaluate unevaluated variable `{path}`"),
+        });
+    }
+
+    if let Some(value) = self.scope.submodule_value(&modules, name.lexeme()) {
+      return Ok(value.clone());
+    }
+
+    let mut module = self.context.module;
+    let mut dotenv = self.context.dotenv;
+    for name in &modules {
+      module = module.modules.get(name).ok_or_else(|| Error::Internal {
+        message: format!("attempted to evaluate variable `{path}` in undefined module"),
+      })?;
+
+      dotenv = module.submodule_dotenv(self.context.config, dotenv)?;
+    }
+
+    let Some(assignment) = module.assignments.get(name.lexeme()) else {
+      return Err(Error::Internal {
+        message: format!("attempted to evaluate undefined variable `{path}`"),
+      });
+    };
+
+    // each intermediate scope holds the variables already evaluated for its
+    // module
+    let arena = Arena::new();
+    let mut parent = &self.scope;
+    for i in 1..modules.len() {
+      let mut scope = parent.child();
+      let (bindings, submodules) = self.scope.submodule(&modules[..i]);
+      scope.extend(bindings, submodules);
+      parent = arena.alloc(scope);
+    }
+
+    let (bindings, submodules) = self.scope.submodule(&modules);
+
+    let mut scope = parent.child();
+    scope.extend(bindings, submodules);
+
+    let mut evaluator = Evaluator {
+      assignments: Some(&module.assignments),
+      context: ExecutionContext {
+        dotenv,
+        module,
+        scope: parent,
+        ..self.context
+      },
+      is_dependency: false,
+      scope,
+    };
+
+    let value = evaluator.evaluate_assignment(assignment)?.clone();
+
+    let (bindings, submodules) = evaluator.scope.into_bindings();
+
+    self.scope.bind_submodule(&modules, bindings, submodules);
+
+    Ok(value)
+  }
+
+  /// Call `f` once for each element of `iterable` for which `condition` is
+  /// true, with the element bound to `name`
+  fn evaluate_iteration<T>(
+    &mut self,
+    condition: Option<&Condition<'src>>,
+    iterable: &Expression<'src>,
+    name: Name<'src>,
+    mut f: impl FnMut(&mut Self) -> RunResult<'src, T>,
+  ) -> RunResult<'src, Vec<T>> {
+    let iterable = self.evaluate_list(iterable)?;
+
+    let shadowed = self.scope.unbind(name.lexeme());
+
+    let iterate = || {
+      let mut evaluated = Vec::new();
+
+      for item in iterable {
+        self.scope.bind(Binding {
+          constant: false,
+          export: false,
+          file_depth: 0,
+          name,
+          private: false,
+          value: item.into(),
+        });
+
+        if let Some(condition) = condition {
+          if !self.evaluate_condition(condition)? {
+            continue;
+          }
         }
+
+        evaluated.push(f(self)?);
+      }
+
+      Ok(evaluated)
+    };
+
+    let result = iterate();
+
+    self.scope.unbind(name.lexeme());
+
+    if let Some(shadowed) = shadowed {
+      self.scope.bind(shadowed);
+    }
+
+    result
+  }
+
+  /// Evaluate `elements`, splicing in the elements of splatted lists
+  pub(crate) fn evaluate_elements(
+    &mut self,
+    elements: &[Expression<'src>],
+  ) -> RunResult<'src, Vec<String>> {
+    let mut evaluated = Vec::new();
+
+    for element in elements {
+      if let Expression::Splat { contents } = element {
+        evaluated.extend(self.evaluate_list(contents)?);
+      } else {
+        evaluated.push(self.evaluate_expression(element)?);
       }
     }
+
+    Ok(evaluated)
+  }
+
+  /// Evaluate the arguments to `dependency`, returning one set of grouped
+  /// arguments for each invocation of the dependency
+  pub(crate) fn evaluate_dependency(
+    &mut self,
+    dependency: &Dependency<'src>,
+  ) -> RunResult<'src, Vec<Vec<Vec<String>>>> {
+    let Dependency {
+      arguments,
+      iteration,
+      recipe,
+      ..
+    } = dependency;
+
+    match iteration {
+      Some(Iteration {
+        condition,
+        iterable,
+        name,
+      }) => self.evaluate_iteration(condition.as_ref(), iterable, *name, |evaluator| {
+        evaluator.evaluate_dependency_arguments(recipe, arguments)
+      }),
+      None => Ok(vec![self.evaluate_dependency_arguments(recipe, arguments)?]),
+    }
+  }
+
+  /// Evaluate the arguments to a dependency on `recipe`, and group them by
+  /// parameter
+  fn evaluate_dependency_arguments(
+    &mut self,
+    recipe: &Recipe<'src>,
+    arguments: &[Expression<'src>],
+  ) -> RunResult<'src, Vec<Vec<String>>> {
+    let arguments = self.evaluate_elements(arguments)?;
+
+    if !recipe.argument_range().contains(&arguments.len()) {
+      return Err(Error::ArgumentCountMismatch {
+        recipe: recipe.name(),
+        parameters: recipe.parameters.clone(),
+        found: arguments.len(),
+        min: recipe.min_arguments(),
+        max: recipe.max_arguments(),
+      });
+    }
+
+    Ok(recipe.group_arguments(arguments))
+  }
+
+  fn evaluate_list(&mut self, expression: &Expression<'src>) -> RunResult<'src, Vec<String>> {
+    self
+      .evaluate_value(expression)?
+      .into_list()
+      .map_err(|value| Error::ExpectedList { value })
+  }
+
+  fn evaluate_user_function(
+    &mut self,
+    name: Name<'src>,
+    arguments: &[Expression<'src>],
+  ) -> RunResult<'src, Value> {
+    let Some(function) = self.context.module.functions.get(name.lexeme()) else {
+      return Err(Error::Internal {
+        message: format!("attempted to call undefined function `{}`", name.lexeme()),
+      });
+    };
+
+    let mut scope = Scope::root();
+
+    for (parameter, argument) in function.parameters.iter().zip(arguments) {
+      let value = self.evaluate_value(argument)?;
+      scope.bind(Binding {
+        constant: false,
+        export: false,
+        file_depth: 0,
+        name: *parameter,
+        private: false,
+        value,
+      });
+    }
+
+    let mut evaluator = Evaluator {
+      assignments: None,
+      context: self.context,
+      is_dependency: self.is_dependency,
+      scope,
+    };
+
+    evaluator.evaluate_value(&function.body)
   }
 
   fn evaluate_condition(&mut self, condition: &Condition<'src>) -> RunResult<'src, bool> {
@@ -241,7 +581,19 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     let rhs_value = self.evaluate_expression(&condition.rhs)?;
     let condition = match condition.operator {
       ConditionalOperator::Equality => lhs_value == rhs_value,
+      ConditionalOperator::Greater => {
+        Self::parse_integer(lhs_value)? > Self::parse_integer(rhs_value)?
+      }
+      ConditionalOperator::GreaterEqual => {
+        Self::parse_integer(lhs_value)? >= Self::parse_integer(rhs_value)?
+      }
       ConditionalOperator::Inequality => lhs_value != rhs_value,
+      ConditionalOperator::Less => {
+        Self::parse_integer(lhs_value)? < Self::parse_integer(rhs_value)?
+      }
+      ConditionalOperator::LessEqual => {
+        Self::parse_integer(lhs_value)? <= Self::parse_integer(rhs_value)?
+      }
       ConditionalOperator::RegexMatch => Regex::new(&rhs_value)
         .map_err(|source| Error::RegexCompile { source })?
         .is_match(&lhs_value),
@@ -252,6 +604,35 @@ impl<'src, 'run> Evaluator<'src, 'run> {
     Ok(condition)
   }
 
+  /// Evaluate the contents of `int(…)`, performing checked integer arithmetic
+  fn evaluate_integer(&mut self, expression: &Expression<'src>) -> RunResult<'src, i64> {
+    match expression {
+      Expression::Arithmetic { lhs, operator, rhs } => {
+        let lhs = self.evaluate_integer(lhs)?;
+        let rhs = self.evaluate_integer(rhs)?;
+        operator.apply(lhs, rhs).ok_or_else(|| {
+          if *operator == ArithmeticOperator::Remainder && rhs == 0 {
+            Error::RemainderByZero { lhs }
+          } else {
+            Error::IntegerOverflow {
+              lhs,
+              operator: *operator,
+              rhs,
+            }
+          }
+        })
+      }
+      Expression::Group { contents } | Expression::Int { contents } => {
+        self.evaluate_integer(contents)
+      }
+      _ => Self::parse_integer(self.evaluate_expression(expression)?),
+    }
+  }
+
+  fn parse_integer(value: String) -> RunResult<'src, i64> {
+    value.parse().map_err(|_| Error::ExpectedInteger { value })
+  }
+
   fn run_backtick(&self, raw: &str, token: &Token<'src>) -> RunResult<'src, String> {
     self
       .run_command(raw, &[])
@@ -261,6 +642,24 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       })
   }
 
+  /// Search for executable `name` in the `PATH` that commands run by this
+  /// evaluator would see
+  pub(crate) fn which(&self, name: &str) -> Option<PathBuf> {
+    let mut cmd = Command::new(name);
+    cmd.export(
+      &self.context.module.settings,
+      self.context.dotenv,
+      &self.scope,
+      &self.context.module.unexports,
+      &self.context.working_directory(),
+    );
+    which(
+      name,
+      cmd.search_path().as_deref(),
+      &self.context.working_directory(),
+    )
+  }
+
   pub(crate) fn run_command(&self, command: &str, args: &[&str]) -> Result<String, OutputError> {
     let mut cmd = self
       .context
@@ -275,6 +674,7 @@ impl<'src, 'run> Evaluator<'src, 'run> {
       self.context.dotenv,
       &self.scope,
       &self.context.module.unexports,
+      &self.context.working_directory(),
     );
     cmd.stdin(Stdio::inherit());
     cmd.stderr(if self.context.config.verbosity.quiet() {
@@ -313,39 +713,75 @@ impl<'src, 'run> Evaluator<'src, 'run> {
   pub(crate) fn evaluate_parameters(
     context: &ExecutionContext<'src, 'run>,
     is_dependency: bool,
-    arguments: &[String],
-    parameters: &[Parameter<'src>],
+    arguments: &[Vec<String>],
+    recipe: &Recipe<'src>,
   ) -> RunResult<'src, (Scope<'src, 'run>, Vec<String>)> {
     let mut evaluator = Self::new(context, is_dependency, context.scope);
 
     let mut positional = Vec::new();
 
-    let mut rest = arguments;
-    for parameter in parameters {
-      let value = if rest.is_empty() {
+    for (parameter, arguments) in recipe.parameters.iter().zip(arguments) {
+      let variadic = parameter.kind.is_variadic();
+
+      let value = if arguments.is_empty() {
         if let Some(ref default) = parameter.default {
-          let value = evaluator.evaluate_expression(default)?;
-          positional.push(value.clone());
+          let value = match evaluator.evaluate_value(default)? {
+            Value::List(list) => {
+              if variadic {
+                positional.extend(list.iter().cloned());
+                Value::List(list)
+              } else {
+                let string = list.join(" ");
+                positional.push(string.clone());
+                Value::String(string)
+              }
+            }
+            Value::String(string) => {
+              positional.push(string.clone());
+              if variadic {
+                Value::List(vec![string])
+              } else {
+                Value::String(string)
+              }
+            }
+          };
+
+          // defaults are checked like arguments, since only literal defaults
+          // can be checked at compile time
+          let defaults = match &value {
+            Value::List(list) => list.as_slice(),
+            Value::String(string) => std::slice::from_ref(string),
+          };
+
+          for default in defaults {
+            if let Err(expected) = parameter.check(default) {
+              return Err(Error::InvalidParameterDefault {
+                recipe: recipe.name(),
+                parameter: parameter.name.lexeme(),
+                default: default.clone(),
+                expected,
+              });
+            }
+          }
+
           value
+        } else if parameter.is_flag() {
+          positional.push(String::new());
+          Value::default()
         } else if parameter.kind == ParameterKind::Star {
-          String::new()
+          Value::List(Vec::new())
         } else {
           return Err(Error::Internal {
             message: "missing parameter without default".to_owned(),
           });
         }
-      } else if parameter.kind.is_variadic() {
-        for value in rest {
-          positional.push(value.clone());
-        }
-        let value = rest.to_vec().join(" ");
-        rest = &[];
-        value
       } else {
-        let value = rest[0].clone();
-        positional.push(value.clone());
-        rest = &rest[1..];
-        value
+        positional.extend(arguments.iter().cloned());
+        if variadic {
+          Value::List(arguments.clone())
+        } else {
+          Value::String(arguments.join(" "))
+        }
       };
       evaluator.scope.bind(Binding {
         constant: false,
@@ -1,5 +1,16 @@
 use super::*;
 
+/// The evaluated scopes and environment file variables of the modules taking
+/// part in a run, keyed by module path
+pub(crate) type Scopes<'src, 'run> = BTreeMap<
+  Vec<&'src str>,
+  (
+    &'run Justfile<'src>,
+    &'run Scope<'src, 'run>,
+    &'run BTreeMap<String, String>,
+  ),
+>;
+
 #[derive(Copy, Clone)]
 pub(crate) struct ExecutionContext<'src: 'run, 'run> {
   pub(crate) config: &'run Config,
@@ -10,6 +21,19 @@ pub(crate) struct ExecutionContext<'src: 'run, 'run> {
 }
 
 impl<'src: 'run, 'run> ExecutionContext<'src, 'run> {
+  /// The context in which to run `recipe`, using the scope, settings, and
+  /// environment file variables of the module in which it was defined
+  pub(crate) fn recipe_context(&self, scopes: &Scopes<'src, 'run>, recipe: &Recipe<'src>) -> Self {
+    let (module, scope, dotenv) = scopes[&recipe.namepath.module()];
+
+    Self {
+      dotenv,
+      module,
+      scope,
+      ..*self
+    }
+  }
+
   pub(crate) fn working_directory(&self) -> PathBuf {
     let base = if self.module.is_submodule() {
       &self.module.working_directory
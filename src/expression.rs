@@ -8,6 +8,13 @@ use super::*;
 /// The parser parses both values and expressions into `Expression`s.
 #[derive(PartialEq, Debug, Clone)]
 pub(crate) enum Expression<'src> {
+  /// `lhs + rhs`, `lhs - rhs`, `lhs * rhs`, or `lhs % rhs`, only valid inside
+  /// of `int(…)`
+  Arithmetic {
+    lhs: Box<Expression<'src>>,
+    operator: ArithmeticOperator,
+    rhs: Box<Expression<'src>>,
+  },
   /// `lhs && rhs`
   And {
     lhs: Box<Expression<'src>>,
@@ -25,6 +32,13 @@ pub(crate) enum Expression<'src> {
   },
   /// `name(arguments)`
   Call { thunk: Thunk<'src> },
+  /// `[element for name in iterable if condition]`
+  Comprehension {
+    condition: Option<Condition<'src>>,
+    element: Box<Expression<'src>>,
+    iterable: Box<Expression<'src>>,
+    name: Name<'src>,
+  },
   /// `lhs + rhs`
   Concatenation {
     lhs: Box<Expression<'src>>,
@@ -36,18 +50,45 @@ pub(crate) enum Expression<'src> {
     then: Box<Expression<'src>>,
     otherwise: Box<Expression<'src>>,
   },
+  /// `f"text{{expression}}"`, where `contents` is a concatenation of the
+  /// format string's text and interpolations
+  FormatString {
+    contents: Box<Expression<'src>>,
+    kind: StringKind,
+  },
   /// `(contents)`
   Group { contents: Box<Expression<'src>> },
+  /// `lhs[index]`
+  Index {
+    lhs: Box<Expression<'src>>,
+    index: Box<Expression<'src>>,
+  },
+  /// `int(contents)`
+  Int { contents: Box<Expression<'src>> },
+  /// `123`, which evaluates to the string of its digits
+  Integer { token: Token<'src> },
   /// `lhs / rhs`
   Join {
     lhs: Option<Box<Expression<'src>>>,
     rhs: Box<Expression<'src>>,
   },
+  /// `[elements]`
+  List { elements: Vec<Expression<'src>> },
+  /// `match subject { arms, _ => default }`
+  Match {
+    arms: Vec<MatchArm<'src>>,
+    default: Box<Expression<'src>>,
+    subject: Box<Expression<'src>>,
+  },
   /// `lhs || rhs`
   Or {
     lhs: Box<Expression<'src>>,
     rhs: Box<Expression<'src>>,
   },
+  /// `*contents`, only valid in lists and dependency arguments
+  Splat { contents: Box<Expression<'src>> },
+  /// `module::variable`, a reference to a variable in a submodule
+  QualifiedVariable { path: Namepath<'src> },
   /// `"string_literal"` or `'string_literal'`
   StringLiteral { string_literal: StringLiteral<'src> },
   /// `variable`
@@ -55,18 +96,60 @@ pub(crate) enum Expression<'src> {
 }
 
 impl<'src> Expression<'src> {
+  /// References to variables in submodules made by this expression
+  pub(crate) fn qualified_variables<'expression>(
+    &'expression self,
+  ) -> impl Iterator<Item = &'expression Namepath<'src>> {
+    self.walk().filter_map(|expression| match expression {
+      Self::QualifiedVariable { path } => Some(path),
+      _ => None,
+    })
+  }
+
   pub(crate) fn variables<'expression>(&'expression self) -> Variables<'expression, 'src> {
     Variables::new(self)
   }
+
+  pub(crate) fn walk<'expression>(&'expression self) -> Walk<'expression, 'src> {
+    Walk::new(self)
+  }
+}
+
+impl Expression<'_> {
+  /// Write the text and interpolations of a format string's contents
+  fn fmt_format_string(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Concatenation { lhs, rhs } => {
+        lhs.fmt_format_string(f)?;
+        rhs.fmt_format_string(f)
+      }
+      Self::Group { contents } => write!(f, "{{{{ {contents} }}}}"),
+      Self::StringLiteral { string_literal } => write!(f, "{}", string_literal.raw),
+      _ => write!(f, "{{{{ {self} }}}}"),
+    }
+  }
 }
 
 impl Display for Expression<'_> {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     match self {
       Self::And { lhs, rhs } => write!(f, "{lhs} && {rhs}"),
+      Self::Arithmetic { lhs, operator, rhs } => write!(f, "{lhs} {operator} {rhs}"),
       Self::Assert { condition, error } => write!(f, "assert({condition}, {error})"),
-      Self::Backtick { token, .. } => write!(f, "{}", token.lexeme()),
+      Self::Backtick { token, .. } | Self::Integer { token } => write!(f, "{}", token.lexeme()),
       Self::Call { thunk } => write!(f, "{thunk}"),
+      Self::Comprehension {
+        condition,
+        element,
+        iterable,
+        name,
+      } => {
+        write!(f, "[{element} for {name} in {iterable}")?;
+        if let Some(condition) = condition {
+          write!(f, " if {condition}")?;
+        }
+        write!(f, "]")
+      }
       Self::Concatenation { lhs, rhs } => write!(f, "{lhs} + {rhs}"),
       Self::Conditional {
         condition,
@@ -79,13 +162,43 @@ impl Display for Expression<'_> {
           write!(f, "if {condition} {{ {then} }} else {{ {otherwise} }}")
         }
       }
+      Self::FormatString { contents, kind } => {
+        write!(f, "f{}", kind.delimiter())?;
+        contents.fmt_format_string(f)?;
+        write!(f, "{}", kind.delimiter())
+      }
       Self::Group { contents } => write!(f, "({contents})"),
+      Self::Index { lhs, index } => write!(f, "{lhs}[{index}]"),
+      Self::Int { contents } => write!(f, "int({contents})"),
       Self::Join { lhs: None, rhs } => write!(f, "/ {rhs}"),
       Self::Join {
         lhs: Some(lhs),
         rhs,
       } => write!(f, "{lhs} / {rhs}"),
+      Self::List { elements } => {
+        write!(f, "[")?;
+        for (i, element) in elements.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{element}")?;
+        }
+        write!(f, "]")
+      }
+      Self::Match {
+        arms,
+        default,
+        subject,
+      } => {
+        write!(f, "match {subject} {{ ")?;
+        for arm in arms {
+          write!(f, "{arm}, ")?;
+        }
+        write!(f, "_ => {default} }}")
+      }
       Self::Or { lhs, rhs } => write!(f, "{lhs} || {rhs}"),
+      Self::QualifiedVariable { path } => write!(f, "{path}"),
+      Self::Splat { contents } => write!(f, "*{contents}"),
       Self::StringLiteral { string_literal } => write!(f, "{string_literal}"),
       Self::Variable { name } => write!(f, "{}", name.lexeme()),
     }
@@ -105,6 +218,13 @@ impl Serialize for Expression<'_> {
         seq.serialize_element(rhs)?;
         seq.end()
       }
+      Self::Arithmetic { lhs, operator, rhs } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element(&operator.to_string())?;
+        seq.serialize_element(lhs)?;
+        seq.serialize_element(rhs)?;
+        seq.end()
+      }
       Self::Assert { condition, error } => {
         let mut seq: <S as Serializer>::SerializeSeq = serializer.serialize_seq(None)?;
         seq.serialize_element("assert")?;
@@ -119,6 +239,20 @@ impl Serialize for Expression<'_> {
         seq.end()
       }
       Self::Call { thunk } => thunk.serialize(serializer),
+      Self::Comprehension {
+        condition,
+        element,
+        iterable,
+        name,
+      } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("comprehension")?;
+        seq.serialize_element(element)?;
+        seq.serialize_element(name)?;
+        seq.serialize_element(iterable)?;
+        seq.serialize_element(condition)?;
+        seq.end()
+      }
       Self::Concatenation { lhs, rhs } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("concatenate")?;
@@ -138,7 +272,23 @@ impl Serialize for Expression<'_> {
         seq.serialize_element(otherwise)?;
         seq.end()
       }
-      Self::Group { contents } => contents.serialize(serializer),
+      Self::FormatString { contents, .. } | Self::Group { contents } => {
+        contents.serialize(serializer)
+      }
+      Self::Index { lhs, index } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("index")?;
+        seq.serialize_element(lhs)?;
+        seq.serialize_element(index)?;
+        seq.end()
+      }
+      Self::Int { contents } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("int")?;
+        seq.serialize_element(contents)?;
+        seq.end()
+      }
+      Self::Integer { token } => serializer.serialize_str(token.lexeme()),
       Self::Join { lhs, rhs } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("join")?;
@@ -146,6 +296,26 @@ impl Serialize for Expression<'_> {
         seq.serialize_element(rhs)?;
         seq.end()
       }
+      Self::List { elements } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("list")?;
+        for element in elements {
+          seq.serialize_element(element)?;
+        }
+        seq.end()
+      }
+      Self::Match {
+        arms,
+        default,
+        subject,
+      } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("match")?;
+        seq.serialize_element(subject)?;
+        seq.serialize_element(arms)?;
+        seq.serialize_element(default)?;
+        seq.end()
+      }
       Self::Or { lhs, rhs } => {
         let mut seq = serializer.serialize_seq(None)?;
         seq.serialize_element("or")?;
@@ -153,6 +323,18 @@ impl Serialize for Expression<'_> {
         seq.serialize_element(rhs)?;
         seq.end()
       }
+      Self::QualifiedVariable { path } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("variable")?;
+        seq.serialize_element(path)?;
+        seq.end()
+      }
+      Self::Splat { contents } => {
+        let mut seq = serializer.serialize_seq(None)?;
+        seq.serialize_element("splat")?;
+        seq.serialize_element(contents)?;
+        seq.end()
+      }
       Self::StringLiteral { string_literal } => string_literal.serialize(serializer),
       Self::Variable { name } => {
         let mut seq = serializer.serialize_seq(None)?;
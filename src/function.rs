@@ -13,6 +13,8 @@ use {
 pub(crate) enum Function {
   Nullary(fn(Context) -> FunctionResult),
   Unary(fn(Context, &str) -> FunctionResult),
+  UnaryList(fn(Context, &[String]) -> FunctionResult),
+  UnaryToList(fn(Context, &str) -> ListResult),
   UnaryOpt(fn(Context, &str, Option<&str>) -> FunctionResult),
   UnaryPlus(fn(Context, &str, &[String]) -> FunctionResult),
   Binary(fn(Context, &str, &str) -> FunctionResult),
@@ -66,16 +68,22 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "extension" => Unary(extension),
     "file_name" => Unary(file_name),
     "file_stem" => Unary(file_stem),
+    "glob" => UnaryToList(glob),
     "home_directory" => Nullary(|_| dir("home", dirs::home_dir)),
     "invocation_directory" => Nullary(invocation_directory),
     "invocation_directory_native" => Nullary(invocation_directory_native),
     "is_dependency" => Nullary(is_dependency),
+    "is_directory" => Unary(is_directory),
+    "is_file" => Unary(is_file),
     "join" => BinaryPlus(join),
+    "json_get" => Binary(json_get),
+    "json_get_file" => Binary(json_get_file),
     "just_executable" => Nullary(just_executable),
     "just_pid" => Nullary(just_pid),
     "justfile" => Nullary(justfile),
     "justfile_directory" => Nullary(justfile_directory),
     "kebabcase" => Unary(kebabcase),
+    "len" => UnaryList(len),
     "lowercamelcase" => Unary(lowercamelcase),
     "lowercase" => Unary(lowercase),
     "module_directory" => Nullary(module_directory),
@@ -88,8 +96,10 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "prepend" => Binary(prepend),
     "quote" => Unary(quote),
     "read" => Unary(read),
+    "read_directory" => UnaryToList(read_directory),
     "replace" => Ternary(replace),
     "replace_regex" => Ternary(replace_regex),
+    "require" => Unary(require),
     "semver_matches" => Binary(semver_matches),
     "sha256" => Unary(sha256),
     "sha256_file" => Unary(sha256_file),
@@ -101,6 +111,8 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "source_file" => Nullary(source_file),
     "style" => Unary(style),
     "titlecase" => Unary(titlecase),
+    "toml_get" => Binary(toml_get),
+    "toml_get_file" => Binary(toml_get_file),
     "trim" => Unary(trim),
     "trim_end" => Unary(trim_end),
     "trim_end_match" => Binary(trim_end_match),
@@ -111,7 +123,10 @@ pub(crate) fn get(name: &str) -> Option<Function> {
     "uppercamelcase" => Unary(uppercamelcase),
     "uppercase" => Unary(uppercase),
     "uuid" => Nullary(uuid),
+    "which" => Unary(which),
     "without_extension" => Unary(without_extension),
+    "yaml_get" => Binary(yaml_get),
+    "yaml_get_file" => Binary(yaml_get_file),
     _ => return None,
   };
   Some(function)
@@ -121,7 +136,7 @@ impl Function {
   pub(crate) fn argc(&self) -> RangeInclusive<usize> {
     match *self {
       Nullary(_) => 0..=0,
-      Unary(_) => 1..=1,
+      Unary(_) | UnaryList(_) | UnaryToList(_) => 1..=1,
       UnaryOpt(_) => 1..=2,
       UnaryPlus(_) => 1..=usize::MAX,
       Binary(_) => 2..=2,
@@ -249,6 +264,36 @@ fn datetime_utc(_context: Context, format: &str) -> FunctionResult {
   Ok(chrono::Utc::now().format(format).to_string())
 }
 
+/// Look up `key_path` in `text`, a document in `format`. `location`
+/// describes where the document came from in error messages.
+fn data_get(format: DataFormat, text: &str, key_path: &str, location: &str) -> FunctionResult {
+  let key_path = key_path.parse::<KeyPath>()?;
+
+  let value = format
+    .parse(text)
+    .map_err(|err| format!("Failed to parse {format}{location}: {err}"))?;
+
+  let value = key_path.get(&value, location)?;
+
+  match value {
+    serde_json::Value::String(string) => Ok(string.clone()),
+    _ => Ok(value.to_string()),
+  }
+}
+
+/// Look up `key_path` in the file at `path`, a document in `format`
+fn data_get_file(
+  context: Context,
+  format: DataFormat,
+  path: &str,
+  key_path: &str,
+) -> FunctionResult {
+  let text = fs::read_to_string(context.evaluator.context.working_directory().join(path))
+    .map_err(|err| format!("I/O error reading `{path}`: {err}"))?;
+
+  data_get(format, &text, key_path, &format!(" in `{path}`"))
+}
+
 fn encode_uri_component(_context: Context, s: &str) -> FunctionResult {
   static PERCENT_ENCODE: percent_encoding::AsciiSet = percent_encoding::NON_ALPHANUMERIC
     .remove(b'-')
@@ -327,6 +372,47 @@ fn file_stem(_context: Context, path: &str) -> FunctionResult {
     .ok_or_else(|| format!("Could not extract file stem from `{path}`"))
 }
 
+fn glob(context: Context, pattern: &str) -> ListResult {
+  let invalid = |err| format!("Invalid glob pattern `{pattern}`: {err}");
+
+  // validate before joining with the justfile directory, so that error
+  // positions refer to `pattern`
+  glob::Pattern::new(pattern).map_err(invalid)?;
+
+  let justfile_directory = justfile_directory(context)?;
+
+  let relative = Path::new(pattern).is_relative();
+
+  let absolute = if relative {
+    format!("{}/{pattern}", glob::Pattern::escape(&justfile_directory))
+  } else {
+    pattern.into()
+  };
+
+  let matches = glob::glob(&absolute).map_err(invalid)?;
+
+  let mut paths = Vec::new();
+
+  for path in matches {
+    let path = path.map_err(|err| format!("I/O error matching `{pattern}`: {err}"))?;
+
+    let path = if relative {
+      path.strip_prefix(&justfile_directory).unwrap_or(&path)
+    } else {
+      &path
+    };
+
+    paths.push(
+      path
+        .to_str()
+        .ok_or_else(|| format!("Path is not valid unicode: {}", path.display()))?
+        .to_owned(),
+    );
+  }
+
+  Ok(paths)
+}
+
 fn invocation_directory(context: Context) -> FunctionResult {
   Platform::convert_native_path(
     &context.evaluator.context.search.working_directory,
@@ -360,6 +446,30 @@ fn is_dependency(context: Context) -> FunctionResult {
   Ok(context.evaluator.is_dependency.to_string())
 }
 
+fn is_directory(context: Context, path: &str) -> FunctionResult {
+  Ok(
+    context
+      .evaluator
+      .context
+      .working_directory()
+      .join(path)
+      .is_dir()
+      .to_string(),
+  )
+}
+
+fn is_file(context: Context, path: &str) -> FunctionResult {
+  Ok(
+    context
+      .evaluator
+      .context
+      .working_directory()
+      .join(path)
+      .is_file()
+      .to_string(),
+  )
+}
+
 fn prepend(_context: Context, prefix: &str, s: &str) -> FunctionResult {
   Ok(
     s.split_whitespace()
@@ -377,6 +487,14 @@ fn join(_context: Context, base: &str, with: &str, and: &[String]) -> FunctionRe
   Ok(result.to_string())
 }
 
+fn json_get(_context: Context, text: &str, key_path: &str) -> FunctionResult {
+  data_get(DataFormat::Json, text, key_path, "")
+}
+
+fn json_get_file(context: Context, path: &str, key_path: &str) -> FunctionResult {
+  data_get_file(context, DataFormat::Json, path, key_path)
+}
+
 fn just_executable(_context: Context) -> FunctionResult {
   let exe_path =
     env::current_exe().map_err(|e| format!("Error getting current executable: {e}"))?;
@@ -442,6 +560,10 @@ fn lowercamelcase(_context: Context, s: &str) -> FunctionResult {
   Ok(s.to_lower_camel_case())
 }
 
+fn len(_context: Context, list: &[String]) -> FunctionResult {
+  Ok(list.len().to_string())
+}
+
 fn lowercase(_context: Context, s: &str) -> FunctionResult {
   Ok(s.to_lowercase())
 }
@@ -507,6 +629,32 @@ fn read(context: Context, filename: &str) -> FunctionResult {
     .map_err(|err| format!("I/O error reading `{filename}`: {err}"))
 }
 
+fn read_directory(context: Context, path: &str) -> ListResult {
+  let entries = fs::read_dir(context.evaluator.context.working_directory().join(path))
+    .map_err(|err| format!("I/O error reading directory `{path}`: {err}"))?;
+
+  let mut paths = Vec::new();
+
+  for entry in entries {
+    let entry = entry.map_err(|err| format!("I/O error reading directory `{path}`: {err}"))?;
+
+    let file_name = entry.file_name();
+
+    let file_name = file_name.to_str().ok_or_else(|| {
+      format!(
+        "File name is not valid unicode: {}",
+        Path::new(&file_name).display()
+      )
+    })?;
+
+    paths.push(Utf8Path::new(path).join(file_name).into_string());
+  }
+
+  paths.sort();
+
+  Ok(paths)
+}
+
 fn replace(_context: Context, s: &str, from: &str, to: &str) -> FunctionResult {
   Ok(s.replace(from, to))
 }
@@ -520,6 +668,16 @@ fn replace_regex(_context: Context, s: &str, regex: &str, replacement: &str) ->
   )
 }
 
+fn require(context: Context, name: &str) -> FunctionResult {
+  let path = which(context, name)?;
+
+  if path.is_empty() {
+    Err(format!("Could not find executable `{name}` in PATH"))
+  } else {
+    Ok(path)
+  }
+}
+
 fn sha256(_context: Context, s: &str) -> FunctionResult {
   use sha2::{Digest, Sha256};
   let mut hasher = Sha256::new();
@@ -545,6 +703,18 @@ fn shell(context: Context, command: &str, args: &[String]) -> FunctionResult {
     .chain(args.iter().map(String::as_str))
     .collect::<Vec<&str>>();
 
+  // like backticks, commands are not run when planning
+  if context.evaluator.context.config.plan {
+    return Ok(format!(
+      "shell({})",
+      args
+        .iter()
+        .map(|arg| format!("'{arg}'"))
+        .collect::<Vec<String>>()
+        .join(", ")
+    ));
+  }
+
   context
     .evaluator
     .run_command(command, &args)
@@ -621,6 +791,14 @@ fn titlecase(_context: Context, s: &str) -> FunctionResult {
   Ok(s.to_title_case())
 }
 
+fn toml_get(_context: Context, text: &str, key_path: &str) -> FunctionResult {
+  data_get(DataFormat::Toml, text, key_path, "")
+}
+
+fn toml_get_file(context: Context, path: &str, key_path: &str) -> FunctionResult {
+  data_get_file(context, DataFormat::Toml, path, key_path)
+}
+
 fn trim(_context: Context, s: &str) -> FunctionResult {
   Ok(s.trim().to_owned())
 }
@@ -661,6 +839,17 @@ fn uuid(_context: Context) -> FunctionResult {
   Ok(uuid::Uuid::new_v4().to_string())
 }
 
+fn which(context: Context, name: &str) -> FunctionResult {
+  let Some(path) = context.evaluator.which(name) else {
+    return Ok(String::new());
+  };
+
+  path
+    .to_str()
+    .map(str::to_owned)
+    .ok_or_else(|| format!("Executable path is not valid unicode: {}", path.display()))
+}
+
 fn without_extension(_context: Context, path: &str) -> FunctionResult {
   let parent = Utf8Path::new(path)
     .parent()
@@ -673,6 +862,14 @@ fn without_extension(_context: Context, path: &str) -> FunctionResult {
   Ok(parent.join(file_stem).to_string())
 }
 
+fn yaml_get(_context: Context, text: &str, key_path: &str) -> FunctionResult {
+  data_get(DataFormat::Yaml, text, key_path, "")
+}
+
+fn yaml_get_file(context: Context, path: &str, key_path: &str) -> FunctionResult {
+  data_get_file(context, DataFormat::Yaml, path, key_path)
+}
+
 /// Check whether a string processes properly as semver (e.x. "0.1.0")
 /// and matches a given semver requirement (e.x. ">=0.1.0")
 fn semver_matches(_context: Context, version: &str, requirement: &str) -> FunctionResult {
@@ -0,0 +1,194 @@
+use {super::*, CompileErrorKind::*};
+
+pub(crate) struct FunctionResolver<'src: 'run, 'run> {
+  functions: &'run Table<'src, UserFunction<'src>>,
+  resolved: BTreeSet<&'src str>,
+  stack: Vec<&'src str>,
+}
+
+impl<'src: 'run, 'run> FunctionResolver<'src, 'run> {
+  pub(crate) fn resolve_functions(
+    functions: &'run Table<'src, UserFunction<'src>>,
+  ) -> CompileResult<'src> {
+    let mut resolver = Self {
+      functions,
+      resolved: BTreeSet::new(),
+      stack: Vec::new(),
+    };
+
+    for name in functions.keys() {
+      resolver.resolve_function(name)?;
+    }
+
+    Ok(())
+  }
+
+  /// Check that every call to a user-defined function in `expression` refers
+  /// to a function that exists and passes the right number of arguments.
+  pub(crate) fn resolve_calls(
+    functions: &Table<'src, UserFunction<'src>>,
+    expression: &Expression<'src>,
+  ) -> CompileResult<'src> {
+    for (name, arguments) in Self::calls(expression) {
+      Self::resolve_call(functions, name, arguments)?;
+    }
+
+    Ok(())
+  }
+
+  fn calls<'expression>(
+    expression: &'expression Expression<'src>,
+  ) -> impl Iterator<Item = (Name<'src>, &'expression [Expression<'src>])> {
+    expression.walk().filter_map(|expression| match expression {
+      Expression::Call {
+        thunk: Thunk::User { name, args },
+      } => Some((*name, args.as_slice())),
+      _ => None,
+    })
+  }
+
+  fn resolve_call<'functions>(
+    functions: &'functions Table<'src, UserFunction<'src>>,
+    name: Name<'src>,
+    arguments: &[Expression<'src>],
+  ) -> CompileResult<'src, &'functions UserFunction<'src>> {
+    let Some(function) = functions.get(name.lexeme()) else {
+      return Err(name.error(UnknownFunction {
+        function: name.lexeme(),
+      }));
+    };
+
+    let expected = function.parameters.len();
+
+    if arguments.len() != expected {
+      return Err(name.error(FunctionArgumentCountMismatch {
+        function: name.lexeme(),
+        found: arguments.len(),
+        expected: expected..=expected,
+      }));
+    }
+
+    Ok(function)
+  }
+
+  fn resolve_function(&mut self, name: &'src str) -> CompileResult<'src> {
+    if self.resolved.contains(name) {
+      return Ok(());
+    }
+
+    self.stack.push(name);
+
+    let function = &self.functions[name];
+
+    for (i, parameter) in function.parameters.iter().enumerate() {
+      if function.parameters[..i]
+        .iter()
+        .any(|previous| previous.lexeme() == parameter.lexeme())
+      {
+        return Err(parameter.error(DuplicateFunctionParameter {
+          function: name,
+          parameter: parameter.lexeme(),
+        }));
+      }
+    }
+
+    for variable in function.body.variables() {
+      let variable_name = variable.lexeme();
+
+      let defined = function
+        .parameters
+        .iter()
+        .any(|parameter| parameter.lexeme() == variable_name)
+        || constants().contains_key(variable_name);
+
+      if !defined {
+        return Err(variable.error(UndefinedVariable {
+          variable: variable_name,
+        }));
+      }
+    }
+
+    if let Some(path) = function.body.qualified_variables().next() {
+      return Err(path.last().error(UndefinedQualifiedVariable {
+        variable: path.to_string(),
+      }));
+    }
+
+    for (callee, arguments) in Self::calls(&function.body) {
+      Self::resolve_call(self.functions, callee, arguments)?;
+
+      let callee = callee.lexeme();
+
+      if self.stack.contains(&callee) {
+        self.stack.push(callee);
+        return Err(self.functions[callee].name.error(CircularFunctionCall {
+          function: callee,
+          circle: self.stack.clone(),
+        }));
+      }
+
+      self.resolve_function(callee)?;
+    }
+
+    self.stack.pop();
+
+    self.resolved.insert(name);
+
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  analysis_error! {
+    name:   circular_function_call,
+    input:  "fn a() := b()\nfn b() := a()",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  1,
+    kind:   CircularFunctionCall{function: "a", circle: vec!["a", "b", "a"]},
+  }
+
+  analysis_error! {
+    name:   self_function_call,
+    input:  "fn a(x) := a(x)",
+    offset: 3,
+    line:   0,
+    column: 3,
+    width:  1,
+    kind:   CircularFunctionCall{function: "a", circle: vec!["a", "a"]},
+  }
+
+  analysis_error! {
+    name:   duplicate_function_parameter,
+    input:  "fn a(x, x) := x",
+    offset: 8,
+    line:   0,
+    column: 8,
+    width:  1,
+    kind:   DuplicateFunctionParameter{function: "a", parameter: "x"},
+  }
+
+  analysis_error! {
+    name:   undefined_variable_in_function,
+    input:  "foo := 'bar'\nfn a(x) := x + foo",
+    offset: 28,
+    line:   1,
+    column: 15,
+    width:  3,
+    kind:   UndefinedVariable{variable: "foo"},
+  }
+
+  analysis_error! {
+    name:   user_function_argument_count,
+    input:  "fn a(x) := x\nb := a()",
+    offset: 18,
+    line:   1,
+    column: 5,
+    width:  1,
+    kind:   FunctionArgumentCountMismatch{function: "a", found: 0, expected: 1..=1},
+  }
+}
@@ -0,0 +1,146 @@
+use super::*;
+
+/// Recipes and the dependencies between them, as printed by `--graph`
+pub(crate) struct Graph<'src, 'run> {
+  recipes: Vec<&'run Recipe<'src>>,
+}
+
+impl<'src, 'run> Graph<'src, 'run> {
+  /// Graph of all recipes in `module` and its submodules
+  pub(crate) fn module(module: &'run Justfile<'src>) -> Self {
+    let mut graph = Self {
+      recipes: Vec::new(),
+    };
+    graph.add_module(module);
+    graph
+  }
+
+  /// Graph of `recipe` and its transitive dependencies
+  pub(crate) fn recipe(recipe: &'run Recipe<'src>) -> Self {
+    let mut graph = Self {
+      recipes: Vec::new(),
+    };
+    graph.add_recipe(recipe);
+    graph
+  }
+
+  fn add_module(&mut self, module: &'run Justfile<'src>) {
+    for recipe in module.recipes.values() {
+      self.add_recipe(recipe);
+    }
+
+    for submodule in module.modules.values() {
+      self.add_module(submodule);
+    }
+  }
+
+  fn add_recipe(&mut self, recipe: &'run Recipe<'src>) {
+    if self
+      .recipes
+      .iter()
+      .any(|existing| existing.namepath == recipe.namepath)
+    {
+      return;
+    }
+
+    self.recipes.push(recipe);
+
+    for dependency in &recipe.dependencies {
+      self.add_recipe(&dependency.recipe);
+    }
+  }
+
+  /// Dependency edges, as `(from, to, label, subsequent)`
+  fn edges(&self) -> impl Iterator<Item = (usize, usize, String, bool)> + '_ {
+    self
+      .recipes
+      .iter()
+      .enumerate()
+      .flat_map(move |(from, recipe)| {
+        recipe
+          .dependencies
+          .iter()
+          .enumerate()
+          .map(move |(i, dependency)| {
+            let to = self
+              .recipes
+              .iter()
+              .position(|recipe| recipe.namepath == dependency.recipe.namepath)
+              .unwrap();
+
+            let label = dependency
+              .arguments
+              .iter()
+              .map(ToString::to_string)
+              .chain(dependency.iteration.iter().map(ToString::to_string))
+              .collect::<Vec<String>>()
+              .join(" ");
+
+            (from, to, label, i >= recipe.priors)
+          })
+      })
+  }
+
+  pub(crate) fn render(&self, format: GraphFormat) -> String {
+    let mut lines = Vec::new();
+
+    match format {
+      GraphFormat::Dot => {
+        let quote = |text: &str| format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""));
+
+        lines.push("digraph justfile {".to_owned());
+
+        for recipe in &self.recipes {
+          lines.push(format!("  {}", quote(&recipe.namepath.to_string())));
+        }
+
+        for (from, to, label, subsequent) in self.edges() {
+          let mut attributes = Vec::new();
+
+          if !label.is_empty() {
+            attributes.push(format!("label={}", quote(&label)));
+          }
+
+          if subsequent {
+            attributes.push("style=dashed".to_owned());
+          }
+
+          let attributes = if attributes.is_empty() {
+            String::new()
+          } else {
+            format!(" [{}]", attributes.join(", "))
+          };
+
+          lines.push(format!(
+            "  {} -> {}{attributes}",
+            quote(&self.recipes[from].namepath.to_string()),
+            quote(&self.recipes[to].namepath.to_string()),
+          ));
+        }
+
+        lines.push("}".to_owned());
+      }
+      GraphFormat::Mermaid => {
+        let quote = |text: &str| format!("\"{}\"", text.replace('"', "#quot;"));
+
+        lines.push("flowchart TD".to_owned());
+
+        for (i, recipe) in self.recipes.iter().enumerate() {
+          lines.push(format!("  n{i}[{}]", quote(&recipe.namepath.to_string())));
+        }
+
+        for (from, to, label, subsequent) in self.edges() {
+          let arrow = if subsequent { "-.->" } else { "-->" };
+
+          if label.is_empty() {
+            lines.push(format!("  n{from} {arrow} n{to}"));
+          } else {
+            lines.push(format!("  n{from} {arrow}|{}| n{to}", quote(&label)));
+          }
+        }
+      }
+    }
+
+    lines.join("\n")
+  }
+}
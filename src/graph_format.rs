@@ -0,0 +1,8 @@
+use super::*;
+
+#[derive(Debug, Default, PartialEq, Clone, Copy, ValueEnum)]
+pub(crate) enum GraphFormat {
+  #[default]
+  Dot,
+  Mermaid,
+}
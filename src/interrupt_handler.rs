@@ -1,8 +1,20 @@
-use super::*;
+use {super::*, wait_timeout::ChildExt};
+
+/// How long timed out processes have to exit after being asked to terminate,
+/// before they are killed
+const TIMEOUT_GRACE_PERIOD: Duration = Duration::from_secs(5);
 
 pub(crate) struct InterruptHandler {
   blocks: u32,
+  /// Running child processes, and the isolations they were spawned within
+  children: BTreeMap<u32, Option<u64>>,
   interrupted: bool,
+  /// Open isolations, and the isolations they were opened within
+  isolations: BTreeMap<u64, Option<u64>>,
+  next_isolation: u64,
+  process_groups: BTreeSet<u32>,
+  /// Isolations whose child processes are being terminated
+  terminating: BTreeSet<u64>,
   verbosity: Verbosity,
 }
 
@@ -34,7 +46,12 @@ impl InterruptHandler {
   const fn new() -> Self {
     Self {
       blocks: 0,
+      children: BTreeMap::new(),
       interrupted: false,
+      isolations: BTreeMap::new(),
+      next_isolation: 0,
+      process_groups: BTreeSet::new(),
+      terminating: BTreeSet::new(),
       verbosity: Verbosity::default(),
     }
   }
@@ -42,6 +59,13 @@ impl InterruptHandler {
   fn interrupt(&mut self) {
     self.interrupted = true;
 
+    // children in their own process groups don't receive interrupts from the
+    // terminal, so forward them. Errors are ignored, since there is nothing
+    // useful to do about them from within a signal handler.
+    for &pid in &self.process_groups {
+      Platform::interrupt_process_group(pid).ok();
+    }
+
     if self.blocks > 0 {
       return;
     }
@@ -74,11 +98,146 @@ impl InterruptHandler {
 
     self.blocks -= 1;
 
-    if self.interrupted {
+    if self.interrupted && self.blocks == 0 {
       Self::exit();
     }
   }
 
+  pub(crate) fn interrupted(&self) -> bool {
+    self.interrupted
+  }
+
+  /// Run `function` on the current thread within a new isolation, spawning
+  /// child processes in their own process groups, so that terminating the
+  /// isolation also terminates their descendants. The isolation is closed
+  /// when `function` returns.
+  pub(crate) fn isolate<T, F: FnOnce(&Isolation) -> T>(function: F) -> T {
+    let isolation = Isolation::new();
+    isolation.run(|| function(&isolation))
+  }
+
+  pub(crate) fn open_isolation(&mut self, parent: Option<u64>) -> u64 {
+    let id = self.next_isolation;
+    self.next_isolation += 1;
+    self.isolations.insert(id, parent);
+    id
+  }
+
+  pub(crate) fn close_isolation(&mut self, id: u64) {
+    self.isolations.remove(&id);
+    self.terminating.remove(&id);
+  }
+
+  /// `isolation` followed by the isolations it was opened within
+  fn ancestors(&self, isolation: Option<u64>) -> impl Iterator<Item = u64> + '_ {
+    iter::successors(isolation, |id| self.isolations.get(id).copied().flatten())
+  }
+
+  /// Spawn `command` and track it as a child process until it exits. Fails
+  /// without spawning if the isolation the current thread is running within
+  /// is being terminated.
+  ///
+  /// If `timeout` is given, or if running within an isolation, `command` is
+  /// run in its own process group. If `timeout` is given, the process group
+  /// is terminated, and then killed, if `command` does not exit in time, in
+  /// which case an error of kind `io::ErrorKind::TimedOut` is returned.
+  pub(crate) fn spawn(command: &mut Command, timeout: Option<Duration>) -> io::Result<ExitStatus> {
+    let isolation = Isolation::current();
+
+    let mut child = {
+      let mut instance = Self::instance();
+
+      if instance
+        .ancestors(isolation)
+        .any(|id| instance.terminating.contains(&id))
+      {
+        return Err(io::Error::new(
+          io::ErrorKind::Interrupted,
+          "child processes are being terminated",
+        ));
+      }
+
+      let process_group = timeout.is_some() || isolation.is_some();
+
+      if process_group {
+        Platform::new_process_group(command);
+      }
+
+      let child = command.spawn()?;
+      instance.children.insert(child.id(), isolation);
+      if process_group {
+        instance.process_groups.insert(child.id());
+      }
+      child
+    };
+
+    let status = match timeout {
+      Some(timeout) => Self::wait_timeout(&mut child, timeout),
+      None => child.wait(),
+    };
+
+    let mut instance = Self::instance();
+    instance.children.remove(&child.id());
+    instance.process_groups.remove(&child.id());
+
+    status
+  }
+
+  fn wait_timeout(child: &mut process::Child, timeout: Duration) -> io::Result<ExitStatus> {
+    if let Some(status) = child.wait_timeout(timeout)? {
+      return Ok(status);
+    }
+
+    Platform::terminate_process_group(child.id())?;
+
+    let exited = child.wait_timeout(TIMEOUT_GRACE_PERIOD)?.is_some();
+
+    // kill anything left in the process group, even if its leader exited
+    if let Err(io_error) = Platform::kill_process_group(child.id()) {
+      if !exited {
+        return Err(io_error);
+      }
+    }
+
+    child.wait()?;
+
+    Err(io::Error::new(
+      io::ErrorKind::TimedOut,
+      format!("timed out after {}", humantime::format_duration(timeout)),
+    ))
+  }
+
+  /// Terminate child processes running within isolation `id`, including
+  /// those within isolations nested inside it, and refuse to spawn new ones
+  /// until the isolation is closed
+  pub(crate) fn terminate_isolation(&mut self, id: u64) {
+    self.terminating.insert(id);
+
+    for (&child, &isolation) in &self.children {
+      if !self.ancestors(isolation).any(|ancestor| ancestor == id) {
+        continue;
+      }
+
+      let result = if self.process_groups.contains(&child) {
+        Platform::terminate_process_group(child)
+      } else {
+        Platform::terminate(child)
+      };
+
+      if let Err(io_error) = result {
+        if self.verbosity.loud() {
+          eprintln!(
+            "{}",
+            Error::Internal {
+              message: format!("failed to terminate child process {child}: {io_error}"),
+            }
+            .color_display(Color::auto().stderr())
+          );
+        }
+      }
+    }
+  }
+
   pub(crate) fn guard<T, F: FnOnce() -> T>(function: F) -> T {
     let _guard = InterruptGuard::new();
     function()
@@ -0,0 +1,87 @@
+use {super::*, std::cell::Cell};
+
+thread_local! {
+  static CURRENT: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// A group of child processes, spawned by threads running within the
+/// isolation, which can be terminated together without affecting child
+/// processes outside of it. Isolations may be nested, in which case
+/// terminating the outer isolation also terminates the inner one.
+pub(crate) struct Isolation {
+  id: u64,
+}
+
+impl Isolation {
+  pub(crate) fn new() -> Self {
+    Self {
+      id: InterruptHandler::instance().open_isolation(Self::current()),
+    }
+  }
+
+  /// The isolation which the current thread is running within, if any
+  pub(crate) fn current() -> Option<u64> {
+    CURRENT.with(Cell::get)
+  }
+
+  /// Run `function` on the current thread within this isolation
+  pub(crate) fn run<T, F: FnOnce() -> T>(&self, function: F) -> T {
+    let previous = CURRENT.with(|current| current.replace(Some(self.id)));
+    let result = function();
+    CURRENT.with(|current| current.set(previous));
+    result
+  }
+
+  /// Terminate child processes running within this isolation, and refuse to
+  /// spawn new ones within it
+  pub(crate) fn terminate(&self) {
+    InterruptHandler::instance().terminate_isolation(self.id);
+  }
+}
+
+impl Drop for Isolation {
+  fn drop(&mut self) {
+    InterruptHandler::instance().close_isolation(self.id);
+  }
+}
+
+#[cfg(test)]
+#[cfg(unix)]
+mod tests {
+  use super::*;
+
+  fn spawn() -> io::Result<ExitStatus> {
+    InterruptHandler::spawn(&mut Command::new("true"), None)
+  }
+
+  #[test]
+  fn terminating_isolation_does_not_affect_siblings() {
+    let a = Isolation::new();
+    let b = Isolation::new();
+
+    a.terminate();
+
+    assert_eq!(a.run(spawn).unwrap_err().kind(), io::ErrorKind::Interrupted);
+    assert!(b.run(spawn).unwrap().success());
+    assert!(spawn().unwrap().success());
+  }
+
+  #[test]
+  fn terminating_isolation_affects_nested_isolations() {
+    let outer = Isolation::new();
+    let inner = outer.run(Isolation::new);
+
+    outer.terminate();
+
+    assert_eq!(
+      inner.run(spawn).unwrap_err().kind(),
+      io::ErrorKind::Interrupted
+    );
+  }
+
+  #[test]
+  fn isolation_is_reset_when_isolate_returns() {
+    InterruptHandler::isolate(Isolation::terminate);
+    assert!(InterruptHandler::isolate(|_| spawn()).unwrap().success());
+  }
+}
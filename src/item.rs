@@ -6,6 +6,7 @@ pub(crate) enum Item<'src> {
   Alias(Alias<'src, Name<'src>>),
   Assignment(Assignment<'src>),
   Comment(&'src str),
+  Function(UserFunction<'src>),
   Import {
     absolute: Option<PathBuf>,
     optional: bool,
@@ -14,12 +15,14 @@ pub(crate) enum Item<'src> {
   },
   Module {
     absolute: Option<PathBuf>,
+    arguments: Vec<(Name<'src>, StringLiteral<'src>)>,
     doc: Option<String>,
     groups: Vec<String>,
     name: Name<'src>,
     optional: bool,
     relative: Option<StringLiteral<'src>>,
   },
+  Parameter(ModuleParameter<'src>),
   Recipe(UnresolvedRecipe<'src>),
   Set(Set<'src>),
   Unexport {
@@ -33,6 +36,7 @@ impl Display for Item<'_> {
       Self::Alias(alias) => write!(f, "{alias}"),
       Self::Assignment(assignment) => write!(f, "{assignment}"),
       Self::Comment(comment) => write!(f, "{comment}"),
+      Self::Function(function) => write!(f, "{function}"),
       Self::Import {
         relative, optional, ..
       } => {
@@ -45,6 +49,7 @@ impl Display for Item<'_> {
         write!(f, " {relative}")
       }
       Self::Module {
+        arguments,
         name,
         relative,
         optional,
@@ -62,8 +67,23 @@ impl Display for Item<'_> {
           write!(f, " {path}")?;
         }
 
+        if !arguments.is_empty() {
+          write!(f, " (")?;
+
+          for (i, (name, value)) in arguments.iter().enumerate() {
+            if i > 0 {
+              write!(f, ", ")?;
+            }
+
+            write!(f, "{name}={value}")?;
+          }
+
+          write!(f, ")")?;
+        }
+
         Ok(())
       }
+      Self::Parameter(parameter) => write!(f, "{parameter}"),
       Self::Recipe(recipe) => write!(f, "{}", recipe.color_display(Color::never())),
       Self::Set(set) => write!(f, "{set}"),
       Self::Unexport { name } => write!(f, "unexport {name}"),
@@ -0,0 +1,22 @@
+use super::*;
+
+/// The `for NAME in iterable` clause of a dependency which is invoked once for
+/// each element of a list, e.g. `(deploy r for r in regions)`
+#[derive(PartialEq, Debug, Clone, Serialize)]
+pub(crate) struct Iteration<'src> {
+  pub(crate) condition: Option<Condition<'src>>,
+  pub(crate) iterable: Expression<'src>,
+  pub(crate) name: Name<'src>,
+}
+
+impl Display for Iteration<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "for {} in {}", self.name, self.iterable)?;
+
+    if let Some(condition) = &self.condition {
+      write!(f, " if {condition}")?;
+    }
+
+    Ok(())
+  }
+}
@@ -2,10 +2,8 @@ use {super::*, serde::Serialize};
 
 #[derive(Debug)]
 struct Invocation<'src: 'run, 'run> {
-  arguments: Vec<&'run str>,
-  module: &'run Justfile<'src>,
+  arguments: &'run [Vec<String>],
   recipe: &'run Recipe<'src>,
-  scope: &'run Scope<'src, 'run>,
 }
 
 #[derive(Debug, PartialEq, Serialize)]
@@ -14,14 +12,17 @@ pub(crate) struct Justfile<'src> {
   pub(crate) assignments: Table<'src, Assignment<'src>>,
   pub(crate) doc: Option<String>,
   #[serde(rename = "first", serialize_with = "keyed::serialize_option")]
-  pub(crate) default: Option<Rc<Recipe<'src>>>,
+  pub(crate) default: Option<Arc<Recipe<'src>>>,
+  #[serde(skip)]
+  pub(crate) dotenv: OnceLock<BTreeMap<String, String>>,
+  pub(crate) functions: Table<'src, UserFunction<'src>>,
   #[serde(skip)]
   pub(crate) loaded: Vec<PathBuf>,
   pub(crate) groups: Vec<String>,
   pub(crate) modules: Table<'src, Justfile<'src>>,
   #[serde(skip)]
   pub(crate) name: Option<Name<'src>>,
-  pub(crate) recipes: Table<'src, Rc<Recipe<'src>>>,
+  pub(crate) recipes: Table<'src, Arc<Recipe<'src>>>,
   pub(crate) settings: Settings<'src>,
   pub(crate) source: PathBuf,
   pub(crate) unexports: HashSet<String>,
@@ -115,15 +116,21 @@ impl<'src> Justfile<'src> {
 
         let scope = scope.child();
 
-        command.export(&self.settings, &dotenv, &scope, &self.unexports);
-
-        let status = InterruptHandler::guard(|| command.status()).map_err(|io_error| {
-          Error::CommandInvoke {
+        command.export(
+          &self.settings,
+          &dotenv,
+          &scope,
+          &self.unexports,
+          &search.working_directory,
+        );
+
+        let status = command
+          .status_guard()
+          .map_err(|io_error| Error::CommandInvoke {
             binary: binary.clone(),
             arguments: arguments.clone(),
             io_error,
-          }
-        })?;
+          })?;
 
         if !status.success() {
           return Err(Error::CommandStatus {
@@ -131,36 +138,12 @@ impl<'src> Justfile<'src> {
             arguments: arguments.clone(),
             status,
           });
-        };
+        }
 
         return Ok(());
       }
       Subcommand::Evaluate { variable, .. } => {
-        if let Some(variable) = variable {
-          if let Some(value) = scope.value(variable) {
-            print!("{value}");
-          } else {
-            return Err(Error::EvalUnknownVariable {
-              suggestion: self.suggest_variable(variable),
-              variable: variable.clone(),
-            });
-          }
-        } else {
-          let width = scope.names().fold(0, |max, name| name.len().max(max));
-
-          for binding in scope.bindings() {
-            if !binding.private {
-              println!(
-                "{0:1$} := \"{2}\"",
-                binding.name.lexeme(),
-                width,
-                binding.value
-              );
-            }
-          }
-        }
-
-        return Ok(());
+        return self.evaluate(config, &dotenv, variable.as_deref(), &scope, search);
       }
       _ => {}
     }
@@ -169,23 +152,13 @@ impl<'src> Justfile<'src> {
 
     let groups = ArgumentParser::parse_arguments(self, &arguments)?;
 
-    let arena: Arena<Scope> = Arena::new();
-    let mut invocations = Vec::<Invocation>::new();
-    let mut scopes = BTreeMap::new();
-
-    for group in &groups {
-      invocations.push(self.invocation(
-        &arena,
-        &group.arguments,
-        config,
-        &dotenv,
-        &scope,
-        &group.path,
-        0,
-        &mut scopes,
-        search,
-      )?);
-    }
+    let invocations = groups
+      .iter()
+      .map(|group| Invocation {
+        arguments: &group.arguments,
+        recipe: self.invocation_recipe(&group.path),
+      })
+      .collect::<Vec<Invocation>>();
 
     if config.one && invocations.len() > 1 {
       return Err(Error::ExcessInvocations {
@@ -193,27 +166,63 @@ impl<'src> Justfile<'src> {
       });
     }
 
-    let mut ran = Ran::default();
-    for invocation in invocations {
-      let context = ExecutionContext {
+    let arena: Arena<Scope> = Arena::new();
+
+    let scopes = self.scopes(
+      &arena,
+      config,
+      &dotenv,
+      Self::recipe_modules(
         config,
-        dotenv: &dotenv,
-        module: invocation.module,
-        scope: invocation.scope,
-        search,
-      };
+        invocations.iter().map(|invocation| invocation.recipe),
+      ),
+      &scope,
+      search,
+    )?;
+
+    let context = ExecutionContext {
+      config,
+      dotenv: &dotenv,
+      module: self,
+      scope: &scope,
+      search,
+    };
 
+    if config.plan {
+      let plan = Plan::default();
+
+      Self::run_invocations(&plan, &context, &scopes, &invocations)?;
+
+      print!("{plan}");
+
+      return Ok(());
+    }
+
+    Self::run_invocations(&Runner, &context, &scopes, &invocations)
+  }
+
+  fn run_invocations<'run>(
+    visitor: &impl Visitor<'src>,
+    context: &ExecutionContext<'src, 'run>,
+    scopes: &Scopes<'src, 'run>,
+    invocations: &[Invocation<'src, 'run>],
+  ) -> RunResult<'src> {
+    // arguments are checked before any recipe runs
+    for invocation in invocations {
+      invocation.recipe.check_arguments(invocation.arguments)?;
+    }
+
+    let ran = Ran::default();
+
+    for invocation in invocations {
       Self::run_recipe(
-        &invocation
-          .arguments
-          .iter()
-          .copied()
-          .map(str::to_string)
-          .collect::<Vec<String>>(),
-        &context,
-        &mut ran,
+        visitor,
+        invocation.arguments,
+        &context.recipe_context(scopes, invocation.recipe),
+        scopes,
+        &ran,
         invocation.recipe,
-        false,
+        Reason::Invocation,
       )?;
     }
 
@@ -240,61 +249,247 @@ impl<'src> Justfile<'src> {
     self
       .recipes
       .get(name)
-      .map(Rc::as_ref)
+      .map(Arc::as_ref)
       .or_else(|| self.aliases.get(name).map(|alias| alias.target.as_ref()))
   }
 
-  fn invocation<'run>(
+  fn invocation_recipe(&self, path: &[String]) -> &Recipe<'src> {
+    let (name, modules) = path.split_last().unwrap();
+
+    let mut module = self;
+    for name in modules {
+      module = module.modules.get(name).unwrap();
+    }
+
+    module.get_recipe(name).unwrap()
+  }
+
+  /// Print the value of `variable`, or of all public variables if `variable`
+  /// is `None`. `variable` may also be a `::`-separated path to a variable in
+  /// a submodule, or to a submodule, in which case all of its public variables
+  /// are printed.
+  fn evaluate<'run>(
+    &'run self,
+    config: &'run Config,
+    dotenv: &'run BTreeMap<String, String>,
+    variable: Option<&str>,
+    root: &'run Scope<'src, 'run>,
+    search: &'run Search,
+  ) -> RunResult<'src> {
+    let Some(variable) = variable else {
+      Self::print_scope(root);
+      return Ok(());
+    };
+
+    if let Some(value) = root.value(variable) {
+      print!("{value}");
+      return Ok(());
+    }
+
+    let mut module = self;
+    let mut path = Vec::new();
+    let mut rest = variable.split("::").collect::<Vec<&str>>();
+
+    while let Some(submodule) = rest.first().and_then(|name| module.modules.get(name)) {
+      module = submodule;
+      path.push(submodule.name());
+      rest.remove(0);
+    }
+
+    let unknown = || Error::EvalUnknownVariable {
+      suggestion: module.suggest_variable(rest.first().copied().unwrap_or(variable)),
+      variable: variable.into(),
+    };
+
+    if path.is_empty()
+      || rest.len() > 1
+      || rest
+        .first()
+        .is_some_and(|name| !module.assignments.contains_key(name))
+    {
+      return Err(unknown());
+    }
+
+    let arena: Arena<Scope> = Arena::new();
+
+    let scopes = self.scopes(&arena, config, dotenv, [path.clone()], root, search)?;
+
+    let (_module, scope, _dotenv) = scopes[&path];
+
+    match rest.first() {
+      Some(name) => print!("{}", scope.value(name).ok_or_else(unknown)?),
+      None => Self::print_scope(scope),
+    }
+
+    Ok(())
+  }
+
+  fn print_scope(scope: &Scope) {
+    let width = scope.names().fold(0, |max, name| name.len().max(max));
+
+    for binding in scope.bindings() {
+      if !binding.private {
+        println!(
+          "{0:1$} := {2}",
+          binding.name.lexeme(),
+          width,
+          binding.value.literal(),
+        );
+      }
+    }
+  }
+
+  /// The paths of the modules containing `recipes` and, unless dependencies
+  /// are disabled, the recipes they depend on
+  fn recipe_modules<'run>(
+    config: &Config,
+    recipes: impl Iterator<Item = &'run Recipe<'src>>,
+  ) -> BTreeSet<Vec<&'src str>>
+  where
+    'src: 'run,
+  {
+    let mut paths = BTreeSet::new();
+    let mut seen = BTreeSet::new();
+    let mut stack = recipes.collect::<Vec<&Recipe>>();
+
+    while let Some(recipe) = stack.pop() {
+      if !seen.insert(&recipe.namepath) {
+        continue;
+      }
+
+      paths.insert(recipe.namepath.module());
+
+      if !config.no_dependencies {
+        stack.extend(
+          recipe
+            .dependencies
+            .iter()
+            .map(|dependency| dependency.recipe.as_ref()),
+        );
+      }
+    }
+
+    paths
+  }
+
+  /// Evaluate the scopes of the modules at `paths`. Modules are evaluated
+  /// after their parents, which serve as their parent scopes. Each module's
+  /// environment file variables are those of its parent, overridden by those
+  /// of its own environment file, if it loads one.
+  fn scopes<'run>(
     &'run self,
     arena: &'run Arena<Scope<'src, 'run>>,
-    arguments: &[&'run str],
     config: &'run Config,
     dotenv: &'run BTreeMap<String, String>,
-    parent: &'run Scope<'src, 'run>,
-    path: &'run [String],
-    position: usize,
-    scopes: &mut BTreeMap<&'run [String], &'run Scope<'src, 'run>>,
+    paths: impl IntoIterator<Item = Vec<&'src str>>,
+    root: &'run Scope<'src, 'run>,
     search: &'run Search,
-  ) -> RunResult<'src, Invocation<'src, 'run>> {
-    if position + 1 == path.len() {
-      let recipe = self.get_recipe(&path[position]).unwrap();
-      Ok(Invocation {
-        arguments: arguments.into(),
-        module: self,
-        recipe,
-        scope: parent,
-      })
-    } else {
-      let module = self.modules.get(&path[position]).unwrap();
+  ) -> RunResult<'src, Scopes<'src, 'run>> {
+    let paths = paths.into_iter().collect::<BTreeSet<Vec<&str>>>();
+
+    let mut scopes = Scopes::new();
+    scopes.insert(Vec::new(), (self, root, dotenv));
+
+    for path in paths {
+      for i in 1..=path.len() {
+        if scopes.contains_key(&path[..i]) {
+          continue;
+        }
+
+        let (parent, parent_scope, parent_dotenv) = scopes[&path[..i - 1]];
+
+        let module = parent.modules.get(path[i - 1]).unwrap();
+
+        let dotenv = module.submodule_dotenv(config, parent_dotenv)?;
 
-      let scope = if let Some(scope) = scopes.get(&path[..position]) {
-        scope
-      } else {
         let scope = Evaluator::evaluate_assignments(
           config,
           dotenv,
           module,
           &BTreeMap::new(),
-          parent,
+          parent_scope,
           search,
         )?;
-        let scope = arena.alloc(scope);
-        scopes.insert(path, scope);
-        scopes.get(path).unwrap()
-      };
-
-      module.invocation(
-        arena,
-        arguments,
-        config,
-        dotenv,
-        scope,
-        path,
-        position + 1,
-        scopes,
-        search,
-      )
+
+        scopes.insert(path[..i].to_vec(), (module, arena.alloc(scope), dotenv));
+      }
     }
+
+    Ok(scopes)
+  }
+
+  /// References to variables in submodules made by this module's recipes
+  pub(crate) fn qualified_variables(&self) -> BTreeSet<&Namepath<'src>> {
+    let mut expressions = Vec::new();
+
+    for recipe in self.recipes.values() {
+      expressions.extend(
+        recipe
+          .parameters
+          .iter()
+          .filter_map(|parameter| parameter.default.as_ref()),
+      );
+
+      for dependency in &recipe.dependencies {
+        expressions.extend(&dependency.arguments);
+
+        if let Some(Iteration {
+          condition,
+          iterable,
+          ..
+        }) = &dependency.iteration
+        {
+          expressions.push(iterable);
+
+          if let Some(condition) = condition {
+            expressions.push(&condition.lhs);
+            expressions.push(&condition.rhs);
+          }
+        }
+      }
+
+      for line in &recipe.body {
+        for fragment in &line.fragments {
+          if let Fragment::Interpolation { expression, .. } = fragment {
+            expressions.push(expression);
+          }
+        }
+      }
+    }
+
+    expressions
+      .into_iter()
+      .flat_map(Expression::qualified_variables)
+      .collect()
+  }
+
+  /// The environment file variables of a submodule, which are those of its
+  /// parent, `parent`, overridden by those of its own environment file, if it
+  /// has environment file settings. The environment file is loaded relative
+  /// to the submodule's directory, only once, so that every use of the
+  /// submodule's variables sees the same environment. The environment file of
+  /// the root module is loaded separately, relative to the search working
+  /// directory.
+  pub(crate) fn submodule_dotenv<'run>(
+    &'run self,
+    config: &Config,
+    parent: &'run BTreeMap<String, String>,
+  ) -> RunResult<'src, &'run BTreeMap<String, String>> {
+    if !config.load_dotenv || !self.is_submodule() || !self.settings.dotenv() {
+      return Ok(parent);
+    }
+
+    if let Some(dotenv) = self.dotenv.get() {
+      return Ok(dotenv);
+    }
+
+    let own = load_dotenv(config, &self.settings, &self.working_directory)?;
+
+    Ok(
+      self
+        .dotenv
+        .get_or_init(|| parent.clone().into_iter().chain(own).collect()),
+    )
   }
 
   pub(crate) fn is_submodule(&self) -> bool {
@@ -305,63 +500,180 @@ impl<'src> Justfile<'src> {
     self.name.map(|name| name.lexeme()).unwrap_or_default()
   }
 
-  fn run_recipe(
-    arguments: &[String],
+  fn run_recipe<V: Visitor<'src>>(
+    visitor: &V,
+    arguments: &[Vec<String>],
     context: &ExecutionContext<'src, '_>,
-    ran: &mut Ran<'src>,
+    scopes: &Scopes<'src, '_>,
+    ran: &Ran<'src>,
     recipe: &Recipe<'src>,
-    is_dependency: bool,
+    reason: Reason<'src>,
   ) -> RunResult<'src> {
-    if ran.has_run(&recipe.namepath, arguments) {
-      return Ok(());
-    }
+    let mutex = ran.mutex(&recipe.namepath, arguments);
 
-    if !context.config.yes && !recipe.confirm()? {
-      return Err(Error::NotConfirmed {
-        recipe: recipe.name(),
-      });
+    let mut ran_recipe = mutex.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if *ran_recipe {
+      return visitor.skip(arguments, context, recipe, reason);
     }
 
+    visitor.confirm(context, recipe)?;
+
     let (outer, positional) =
-      Evaluator::evaluate_parameters(context, is_dependency, arguments, &recipe.parameters)?;
+      Evaluator::evaluate_parameters(context, reason.is_dependency(), arguments, recipe)?;
 
     let scope = outer.child();
 
     let mut evaluator = Evaluator::new(context, true, &scope);
 
     if !context.config.no_dependencies {
-      for Dependency { recipe, arguments } in recipe.dependencies.iter().take(recipe.priors) {
-        let arguments = arguments
-          .iter()
-          .map(|argument| evaluator.evaluate_expression(argument))
-          .collect::<RunResult<Vec<String>>>()?;
-
-        Self::run_recipe(&arguments, context, ran, recipe, true)?;
-      }
+      Self::run_dependencies(visitor, context, scopes, recipe, false, &mut evaluator, ran)?;
     }
 
-    recipe.run(context, &scope, &positional, is_dependency)?;
+    visitor.visit(context, &positional, reason, recipe, &scope)?;
 
     if !context.config.no_dependencies {
-      let mut ran = Ran::default();
+      Self::run_dependencies(
+        visitor,
+        context,
+        scopes,
+        recipe,
+        true,
+        &mut evaluator,
+        &Ran::default(),
+      )?;
+    }
 
-      for Dependency { recipe, arguments } in recipe.subsequents() {
-        let mut evaluated = Vec::new();
+    *ran_recipe = true;
 
-        for argument in arguments {
-          evaluated.push(evaluator.evaluate_expression(argument)?);
+    Ok(())
+  }
+
+  /// Run the prior dependencies of `parent`, or its subsequents if
+  /// `subsequents` is true
+  fn run_dependencies<'run, V: Visitor<'src>>(
+    visitor: &V,
+    context: &ExecutionContext<'src, 'run>,
+    scopes: &Scopes<'src, 'run>,
+    parent: &'run Recipe<'src>,
+    subsequents: bool,
+    evaluator: &mut Evaluator<'src, 'run>,
+    ran: &Ran<'src>,
+  ) -> RunResult<'src>
+  where
+    'src: 'run,
+  {
+    let parallel = parent.is_parallel();
+
+    let reason = || {
+      if subsequents {
+        Reason::Subsequent {
+          parent: parent.namepath.clone(),
+        }
+      } else {
+        Reason::Dependency {
+          parallel,
+          parent: parent.namepath.clone(),
         }
+      }
+    };
+
+    let dependencies = if subsequents {
+      parent.subsequents().collect::<Vec<&Dependency>>()
+    } else {
+      parent.dependencies.iter().take(parent.priors).collect()
+    };
 
-        Self::run_recipe(&evaluated, context, &mut ran, recipe, true)?;
+    let mut evaluated = Vec::new();
+
+    for dependency in dependencies {
+      for arguments in evaluator.evaluate_dependency(dependency)? {
+        evaluated.push((&dependency.recipe, arguments));
       }
     }
 
-    ran.ran(&recipe.namepath, arguments.to_vec());
+    for (recipe, arguments) in &evaluated {
+      recipe.check_arguments(arguments)?;
+    }
 
-    Ok(())
+    if !parallel || !V::CONCURRENT || evaluated.len() < 2 {
+      for (recipe, arguments) in evaluated {
+        Self::run_recipe(
+          visitor,
+          &arguments,
+          &context.recipe_context(scopes, recipe),
+          scopes,
+          ran,
+          recipe,
+          reason(),
+        )?;
+      }
+
+      return Ok(());
+    }
+
+    let jobs = context
+      .config
+      .jobs
+      .map_or(evaluated.len(), NonZeroUsize::get)
+      .min(evaluated.len());
+
+    let queue = Mutex::new(evaluated.into_iter());
+    let failure = Mutex::new(None);
+
+    // run dependencies in their own process groups, so that when one fails,
+    // the others can be terminated along with any processes they started
+    InterruptHandler::isolate(|isolation| {
+      thread::scope(|thread_scope| {
+        for _ in 0..jobs {
+          thread_scope.spawn(|| {
+            isolation.run(|| loop {
+              if failure
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .is_some()
+                || InterruptHandler::instance().interrupted()
+              {
+                break;
+              }
+
+              let Some((recipe, arguments)) =
+                queue.lock().unwrap_or_else(PoisonError::into_inner).next()
+              else {
+                break;
+              };
+
+              if let Err(error) = Self::run_recipe(
+                visitor,
+                &arguments,
+                &context.recipe_context(scopes, recipe),
+                scopes,
+                ran,
+                recipe,
+                reason(),
+              ) {
+                let mut failure = failure.lock().unwrap_or_else(PoisonError::into_inner);
+
+                if failure.is_none() {
+                  *failure = Some(error);
+                  isolation.terminate();
+                }
+
+                break;
+              }
+            });
+          });
+        }
+      });
+    });
+
+    match failure.into_inner().unwrap_or_else(PoisonError::into_inner) {
+      Some(error) => Err(error),
+      None => Ok(()),
+    }
   }
 
-  pub(crate) fn modules(&self, config: &Config) -> Vec<&Justfile> {
+  pub(crate) fn modules(&self, config: &Config) -> Vec<&Justfile<'_>> {
     let mut modules = self.modules.values().collect::<Vec<&Justfile>>();
 
     if config.unsorted {
@@ -376,7 +688,7 @@ impl<'src> Justfile<'src> {
     modules
   }
 
-  pub(crate) fn public_recipes(&self, config: &Config) -> Vec<&Recipe> {
+  pub(crate) fn public_recipes(&self, config: &Config) -> Vec<&Recipe<'_>> {
     let mut recipes = self
       .recipes
       .values()
@@ -408,7 +720,7 @@ impl<'src> Justfile<'src> {
 
     for submodule in self.modules.values() {
       for group in submodule.groups() {
-        groups.push((&[], submodule.name.unwrap().offset, group.to_string()));
+        groups.push((&[], submodule.name.unwrap().offset, group.clone()));
       }
     }
 
@@ -428,7 +740,8 @@ impl<'src> Justfile<'src> {
 
 impl ColorDisplay for Justfile<'_> {
   fn fmt(&self, f: &mut Formatter, color: Color) -> fmt::Result {
-    let mut items = self.recipes.len() + self.assignments.len() + self.aliases.len();
+    let mut items =
+      self.recipes.len() + self.assignments.len() + self.functions.len() + self.aliases.len();
     for (name, assignment) in &self.assignments {
       if assignment.export {
         write!(f, "export ")?;
@@ -439,6 +752,13 @@ impl ColorDisplay for Justfile<'_> {
         write!(f, "\n\n")?;
       }
     }
+    for function in self.functions.values() {
+      write!(f, "{function}")?;
+      items -= 1;
+      if items != 0 {
+        write!(f, "\n\n")?;
+      }
+    }
     for alias in self.aliases.values() {
       write!(f, "{alias}")?;
       items -= 1;
@@ -0,0 +1,189 @@
+use super::*;
+
+/// A path to a value inside of a structured document, e.g. `a.b[0]`
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct KeyPath {
+  segments: Vec<Segment>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Segment {
+  Index(usize),
+  Key(String),
+}
+
+impl KeyPath {
+  /// Look up the value at this path in `document`. On failure, the error
+  /// message names the segment of the path that could not be found, and
+  /// includes `location`, which describes where the document came from.
+  pub(crate) fn get<'a>(
+    &self,
+    document: &'a serde_json::Value,
+    location: &str,
+  ) -> Result<&'a serde_json::Value, String> {
+    use serde_json::Value;
+
+    let mut value = document;
+
+    for (i, segment) in self.segments.iter().enumerate() {
+      let found = match (segment, value) {
+        (Segment::Index(index), Value::Array(array)) => array.get(*index),
+        (Segment::Key(key), Value::Object(object)) => object.get(key),
+        _ => {
+          let parent = if i == 0 {
+            "document".into()
+          } else {
+            format!("`{}`", self.prefix(i))
+          };
+
+          let kind = match value {
+            Value::Array(_) => "an array",
+            Value::Bool(_) => "a boolean",
+            Value::Null => "null",
+            Value::Number(_) => "a number",
+            Value::Object(_) => "an object",
+            Value::String(_) => "a string",
+          };
+
+          return Err(format!(
+            "{segment} of key path `{self}` not found{location}: {parent} is {kind}",
+          ));
+        }
+      };
+
+      value = found.ok_or_else(|| format!("{segment} of key path `{self}` not found{location}"))?;
+    }
+
+    Ok(value)
+  }
+
+  fn prefix(&self, len: usize) -> Self {
+    Self {
+      segments: self.segments[..len].to_vec(),
+    }
+  }
+}
+
+impl Display for Segment {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::Index(index) => write!(f, "Index `{index}`"),
+      Self::Key(key) => write!(f, "Key `{key}`"),
+    }
+  }
+}
+
+impl str::FromStr for KeyPath {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || format!("Invalid key path `{s}`");
+
+    let mut segments = Vec::new();
+
+    let mut rest = s;
+
+    loop {
+      let end = rest.find(['.', '[']).unwrap_or(rest.len());
+
+      let key = &rest[..end];
+
+      if !key.is_empty() {
+        segments.push(Segment::Key(key.into()));
+      } else if !segments.is_empty() || !rest.starts_with('[') {
+        return Err(invalid());
+      }
+
+      rest = &rest[end..];
+
+      while let Some(index) = rest.strip_prefix('[') {
+        let close = index.find(']').ok_or_else(invalid)?;
+        segments.push(Segment::Index(
+          index[..close].parse().map_err(|_| invalid())?,
+        ));
+        rest = &index[close + 1..];
+      }
+
+      if rest.is_empty() {
+        break;
+      }
+
+      rest = rest.strip_prefix('.').ok_or_else(invalid)?;
+    }
+
+    Ok(Self { segments })
+  }
+}
+
+impl Display for KeyPath {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    for (i, segment) in self.segments.iter().enumerate() {
+      match segment {
+        Segment::Index(index) => write!(f, "[{index}]")?,
+        Segment::Key(key) => {
+          if i > 0 {
+            write!(f, ".")?;
+          }
+          write!(f, "{key}")?;
+        }
+      }
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    #[track_caller]
+    fn case(s: &str) {
+      assert_eq!(s.parse::<KeyPath>().unwrap().to_string(), s);
+    }
+
+    case("a");
+    case("a.b");
+    case("a[0]");
+    case("a.b[0][1].c");
+    case("[0].a");
+  }
+
+  #[test]
+  fn invalid() {
+    for s in [
+      "", ".", "a.", ".a", "a..b", "a[", "a[x]", "a[-1]", "a[0]b", "a.[0]",
+    ] {
+      assert_eq!(
+        s.parse::<KeyPath>().unwrap_err(),
+        format!("Invalid key path `{s}`"),
+      );
+    }
+  }
+
+  #[test]
+  fn get() {
+    let document = serde_json::json!({"a": {"b": [1, {"c": "d"}]}});
+
+    let get = |path: &str| path.parse::<KeyPath>().unwrap().get(&document, "").cloned();
+
+    assert_eq!(get("a.b[1].c"), Ok("d".into()));
+    assert_eq!(
+      get("a.x"),
+      Err("Key `x` of key path `a.x` not found".into())
+    );
+    assert_eq!(
+      get("a.b[2]"),
+      Err("Index `2` of key path `a.b[2]` not found".into())
+    );
+    assert_eq!(
+      get("a.b.c"),
+      Err("Key `c` of key path `a.b.c` not found: `a.b` is an array".into()),
+    );
+    assert_eq!(
+      get("[0]"),
+      Err("Index `0` of key path `[0]` not found: document is an object".into()),
+    );
+  }
+}
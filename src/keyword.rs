@@ -15,15 +15,24 @@ pub(crate) enum Keyword {
   Export,
   Fallback,
   False,
+  Fn,
+  For,
   If,
   IgnoreComments,
   Import,
+  In,
+  InheritSettings,
+  Int,
+  Match,
   Mod,
+  Param,
+  Path,
   PositionalArguments,
   Quiet,
   ScriptInterpreter,
   Set,
   Shell,
+  Super,
   Tempdir,
   True,
   Unexport,
@@ -11,6 +11,10 @@ use {super::*, CompileErrorKind::*, TokenKind::*};
 pub(crate) struct Lexer<'src> {
   /// Char iterator
   chars: Chars<'src>,
+  /// Open format string stack, containing the format string start token,
+  /// format string kind, and the interpolation stack depth at which the
+  /// format string's text is lexed
+  format_strings: Vec<(Token<'src>, StringKind, usize)>,
   /// Indentation stack
   indentation: Vec<&'src str>,
   /// Interpolation token start stack
@@ -58,6 +62,7 @@ impl<'src> Lexer<'src> {
     };
 
     Self {
+      format_strings: Vec::new(),
       indentation: vec![""],
       tokens: Vec::new(),
       token_start: start,
@@ -285,24 +290,30 @@ impl<'src> Lexer<'src> {
   /// Consume the text and produce a series of tokens
   fn tokenize(mut self) -> CompileResult<'src, Vec<Token<'src>>> {
     loop {
-      if self.token_start.column == 0 {
+      if self.token_start.column == 0 && self.format_string_text().is_none() {
         self.lex_line_start()?;
       }
 
       match self.next {
         Some(first) => {
-          if let Some(&interpolation_start) = self.interpolation_stack.last() {
+          if let Some((start, kind)) = self.format_string_text() {
+            self.lex_format_string_text(start, kind)?;
+          } else if let Some(&interpolation_start) = self.interpolation_stack.last() {
             self.lex_interpolation(interpolation_start, first)?;
           } else if self.recipe_body {
             self.lex_body()?;
           } else {
             self.lex_normal(first)?;
-          };
+          }
         }
         None => break,
       }
     }
 
+    if let Some((start, kind)) = self.format_string_text() {
+      return Err(start.error(kind.unterminated_error_kind()));
+    }
+
     if let Some(&interpolation_start) = self.interpolation_stack.last() {
       return Err(Self::unterminated_interpolation_error(interpolation_start));
     }
@@ -402,7 +413,7 @@ impl<'src> Lexer<'src> {
           }
 
           self.token(Whitespace);
-        };
+        }
 
         Ok(())
       }
@@ -470,6 +481,16 @@ impl<'src> Lexer<'src> {
     }
   }
 
+  /// If the innermost open format string's text is being lexed, and not one
+  /// of its interpolations, return its start token and kind
+  fn format_string_text(&self) -> Option<(Token<'src>, StringKind)> {
+    self
+      .format_strings
+      .last()
+      .filter(|(_, _, depth)| *depth == self.interpolation_stack.len())
+      .map(|&(start, kind, _)| (start, kind))
+  }
+
   /// Lex token beginning with `start` outside of a recipe body
   fn lex_normal(&mut self, start: char) -> CompileResult<'src> {
     match start {
@@ -478,30 +499,40 @@ impl<'src> Lexer<'src> {
       '!' => self.lex_choices('!', &[('=', BangEquals), ('~', BangTilde)], None),
       '#' => self.lex_comment(),
       '$' => self.lex_single(Dollar),
+      '%' => self.lex_single(Percent),
       '&' => self.lex_digraph('&', '&', AmpersandAmpersand),
       '(' => self.lex_delimiter(ParenL),
       ')' => self.lex_delimiter(ParenR),
       '*' => self.lex_single(Asterisk),
       '+' => self.lex_single(Plus),
       ',' => self.lex_single(Comma),
+      '-' => self.lex_single(Minus),
       '/' => self.lex_single(Slash),
       ':' => self.lex_colon(),
+      '<' => self.lex_choices('<', &[('=', LessEquals)], Some(Less)),
       '=' => self.lex_choices(
         '=',
-        &[('=', EqualsEquals), ('~', EqualsTilde)],
+        &[
+          ('=', EqualsEquals),
+          ('>', EqualsGreater),
+          ('~', EqualsTilde),
+        ],
         Some(Equals),
       ),
+      '>' => self.lex_choices('>', &[('=', GreaterEquals)], Some(Greater)),
       '?' => self.lex_single(QuestionMark),
       '@' => self.lex_single(At),
       '[' => self.lex_delimiter(BracketL),
       '\\' => self.lex_escape(),
       '\n' | '\r' => self.lex_eol(),
       '\u{feff}' => self.lex_single(ByteOrderMark),
+      '0'..='9' => self.lex_integer(),
       ']' => self.lex_delimiter(BracketR),
       '`' | '"' | '\'' => self.lex_string(),
       '{' => self.lex_delimiter(BraceL),
       '|' => self.lex_digraph('|', '|', BarBar),
       '}' => self.lex_delimiter(BraceR),
+      'f' if self.rest()[1..].starts_with(['\'', '"']) => self.lex_format_string_start(),
       _ if Self::is_identifier_start(start) => self.lex_identifier(),
       _ => {
         self.advance()?;
@@ -592,6 +623,79 @@ impl<'src> Lexer<'src> {
     }
   }
 
+  /// Lex format string start: f'|f"
+  fn lex_format_string_start(&mut self) -> CompileResult<'src> {
+    self.presume('f')?;
+
+    let Some(kind) = StringKind::from_token_start(self.rest()) else {
+      self.advance()?;
+      return Err(self.internal_error("Lexer::lex_format_string_start: invalid string start"));
+    };
+
+    self.presume_str(kind.delimiter())?;
+
+    if kind.indented() {
+      return Err(self.error(IndentedFormatString));
+    }
+
+    self.token(FormatStringStart);
+
+    self.format_strings.push((
+      self.tokens[self.tokens.len() - 1],
+      kind,
+      self.interpolation_stack.len(),
+    ));
+
+    Ok(())
+  }
+
+  /// Lex format string text, up to the next interpolation or the end of the
+  /// format string
+  fn lex_format_string_text(
+    &mut self,
+    start: Token<'src>,
+    kind: StringKind,
+  ) -> CompileResult<'src> {
+    let mut escape = false;
+
+    let interpolation = loop {
+      if self.next.is_none() {
+        return Err(start.error(kind.unterminated_error_kind()));
+      } else if kind.processes_escape_sequences() && self.next_is('\\') && !escape {
+        escape = true;
+      } else if self.rest_starts_with("{{{{") && !escape {
+        self.skip(4)?;
+        continue;
+      } else if self.rest_starts_with("{{") && !escape {
+        break true;
+      } else if self.rest_starts_with(kind.delimiter()) && !escape {
+        break false;
+      } else {
+        escape = false;
+      }
+
+      self.advance()?;
+    };
+
+    // emit text token containing text so far
+    if self.current_token_length() > 0 {
+      self.token(Text);
+    }
+
+    if interpolation {
+      self.lex_double(InterpolationStart)?;
+      self
+        .interpolation_stack
+        .push(self.tokens[self.tokens.len() - 1]);
+    } else {
+      self.presume_str(kind.delimiter())?;
+      self.token(FormatStringEnd);
+      self.format_strings.pop();
+    }
+
+    Ok(())
+  }
+
   fn lex_dedent(&mut self) {
     assert_eq!(self.current_token_length(), 0);
     self.token(Dedent);
@@ -735,6 +839,8 @@ impl<'src> Lexer<'src> {
 
     if self.accepted('=')? {
       self.token(ColonEquals);
+    } else if self.accepted(':')? {
+      self.token(ColonColon);
     } else {
       self.token(Colon);
       self.recipe_body_pending = true;
@@ -806,6 +912,31 @@ impl<'src> Lexer<'src> {
     Ok(())
   }
 
+  /// Lex integer: [0-9]+
+  fn lex_integer(&mut self) -> CompileResult<'src> {
+    let digits = self.rest().chars().take_while(char::is_ascii_digit).count();
+
+    // names may not start with digits, so `0foo` is an error, and not an
+    // integer followed by a name
+    if self
+      .rest()
+      .chars()
+      .nth(digits)
+      .is_some_and(Self::is_identifier_start)
+    {
+      self.advance()?;
+      return Err(self.error(UnknownStartOfToken));
+    }
+
+    for _ in 0..digits {
+      self.advance()?;
+    }
+
+    self.token(Integer);
+
+    Ok(())
+  }
+
   /// Lex comment: #[^\r\n]
   fn lex_comment(&mut self) -> CompileResult<'src> {
     self.presume('#')?;
@@ -982,18 +1113,26 @@ mod tests {
       BracketR => "]",
       ByteOrderMark => "\u{feff}",
       Colon => ":",
+      ColonColon => "::",
       ColonEquals => ":=",
       Comma => ",",
       Dollar => "$",
       Eol => "\n",
       Equals => "=",
       EqualsEquals => "==",
+      EqualsGreater => "=>",
       EqualsTilde => "=~",
+      Greater => ">",
+      GreaterEquals => ">=",
       Indent => "  ",
       InterpolationEnd => "}}",
       InterpolationStart => "{{",
+      Less => "<",
+      LessEquals => "<=",
+      Minus => "-",
       ParenL => "(",
       ParenR => ")",
+      Percent => "%",
       Plus => "+",
       QuestionMark => "?",
       Slash => "/",
@@ -1003,7 +1142,8 @@ mod tests {
       Dedent | Eof => "",
 
       // Variable lexemes
-      Text | StringToken | Backtick | Identifier | Comment | Unspecified => {
+      Text | StringToken | Backtick | Identifier | Integer | Comment | FormatStringEnd
+      | FormatStringStart | Unspecified => {
         panic!("Token {kind:?} has no default lexeme")
       }
     }
@@ -1060,6 +1200,134 @@ mod tests {
     tokens: (Identifier:"foo"),
   }
 
+  test! {
+    name:   integer,
+    text:   "0 123",
+    tokens: (Integer:"0", Whitespace, Integer:"123"),
+  }
+
+  test! {
+    name:   arithmetic_operators,
+    text:   "a + b - c * d % e",
+    tokens: (
+      Identifier:"a",
+      Whitespace,
+      Plus,
+      Whitespace,
+      Identifier:"b",
+      Whitespace,
+      Minus,
+      Whitespace,
+      Identifier:"c",
+      Whitespace,
+      Asterisk,
+      Whitespace,
+      Identifier:"d",
+      Whitespace,
+      Percent,
+      Whitespace,
+      Identifier:"e",
+    ),
+  }
+
+  test! {
+    name:   comparison_operators,
+    text:   "< <= > >=",
+    tokens: (Less, Whitespace, LessEquals, Whitespace, Greater, Whitespace, GreaterEquals),
+  }
+
+  test! {
+    name:   match_arm,
+    text:   "'a' => b",
+    tokens: (StringToken:"'a'", Whitespace, EqualsGreater, Whitespace, Identifier:"b"),
+  }
+
+  test! {
+    name:   dash_is_part_of_name,
+    text:   "a-b - c",
+    tokens: (Identifier:"a-b", Whitespace, Minus, Whitespace, Identifier:"c"),
+  }
+
+  test! {
+    name:   format_string,
+    text:   "f'a{{b}}c'",
+    tokens: (
+      FormatStringStart:"f'",
+      Text:"a",
+      InterpolationStart,
+      Identifier:"b",
+      InterpolationEnd,
+      Text:"c",
+      FormatStringEnd:"'",
+    ),
+  }
+
+  test! {
+    name:   format_string_empty,
+    text:   "f\"\"",
+    tokens: (FormatStringStart:"f\"", FormatStringEnd:"\""),
+  }
+
+  test! {
+    name:   format_string_escaped_interpolation,
+    text:   "f'{{{{a}}'",
+    tokens: (FormatStringStart:"f'", Text:"{{{{a}}", FormatStringEnd:"'"),
+  }
+
+  test! {
+    name:   format_string_escaped_delimiter,
+    text:   r#"f"a\"{{b}}""#,
+    tokens: (
+      FormatStringStart:"f\"",
+      Text:"a\\\"",
+      InterpolationStart,
+      Identifier:"b",
+      InterpolationEnd,
+      FormatStringEnd:"\"",
+    ),
+  }
+
+  test! {
+    name:   format_string_nested,
+    text:   "f'{{f\"{{a}}\"}}'",
+    tokens: (
+      FormatStringStart:"f'",
+      InterpolationStart,
+      FormatStringStart:"f\"",
+      InterpolationStart,
+      Identifier:"a",
+      InterpolationEnd,
+      FormatStringEnd:"\"",
+      InterpolationEnd,
+      FormatStringEnd:"'",
+    ),
+  }
+
+  test! {
+    name:   format_string_in_recipe_body,
+    text:   "foo:\n  {{f'{{a}}'}}",
+    tokens: (
+      Identifier:"foo",
+      Colon,
+      Eol,
+      Indent,
+      InterpolationStart,
+      FormatStringStart:"f'",
+      InterpolationStart,
+      Identifier:"a",
+      InterpolationEnd,
+      FormatStringEnd:"'",
+      InterpolationEnd,
+      Dedent,
+    ),
+  }
+
+  test! {
+    name:   f_followed_by_whitespace_is_name,
+    text:   "f 'a'",
+    tokens: (Identifier:"f", Whitespace, StringToken:"'a'"),
+  }
+
   test! {
     name:   comment,
     text:   "# hello",
@@ -1964,6 +2232,19 @@ mod tests {
     ),
   }
 
+  test! {
+    name: tokenize_namepath_dependency,
+    text: "a: b::c",
+    tokens: (
+      Identifier:"a",
+      Colon,
+      Whitespace:" ",
+      Identifier:"b",
+      ColonColon,
+      Identifier:"c",
+    ),
+  }
+
   test! {
     name: tokenize_order,
     text: "
@@ -2116,7 +2397,7 @@ mod tests {
 
   error! {
     name:   tokenize_unknown,
-    input:  "%",
+    input:  "^",
     offset: 0,
     line:   0,
     column: 0,
@@ -2176,8 +2457,8 @@ mod tests {
   }
 
   error! {
-    name:   invalid_name_start_dash,
-    input:  "-foo",
+    name:   invalid_name_start_digits,
+    input:  "123foo",
     offset: 0,
     line:   0,
     column: 0,
@@ -2195,6 +2476,26 @@ mod tests {
     kind:   UnknownStartOfToken,
   }
 
+  error! {
+    name:   unterminated_format_string,
+    input:  "a := f'b{{c}}",
+    offset: 5,
+    line:   0,
+    column: 5,
+    width:  2,
+    kind:   UnterminatedString,
+  }
+
+  error! {
+    name:   indented_format_string,
+    input:  "a := f'''b'''",
+    offset: 5,
+    line:   0,
+    column: 5,
+    width:  4,
+    kind:   IndentedFormatString,
+  }
+
   error! {
     name:   unterminated_string,
     input:  r#"a = ""#,
@@ -2257,7 +2558,7 @@ mod tests {
 
   error! {
     name:   unexpected_character_after_at,
-    input:  "@%",
+    input:  "@^",
     offset: 1,
     line:   0,
     column: 1,
@@ -9,13 +9,16 @@ pub(crate) use {
     alias::Alias,
     alias_style::AliasStyle,
     analyzer::Analyzer,
+    arg_attribute::ArgAttribute,
     argument_parser::ArgumentParser,
+    arithmetic_operator::ArithmeticOperator,
     assignment::Assignment,
     assignment_resolver::AssignmentResolver,
     ast::Ast,
     attribute::{Attribute, AttributeDiscriminant},
     attribute_set::AttributeSet,
     binding::Binding,
+    cache::{Cache, CacheEntry},
     color::Color,
     color_display::ColorDisplay,
     command_color::CommandColor,
@@ -30,22 +33,30 @@ pub(crate) use {
     config_error::ConfigError,
     constants::constants,
     count::Count,
+    data_format::DataFormat,
     delimiter::Delimiter,
     dependency::Dependency,
     dump_format::DumpFormat,
+    duration_literal::DurationLiteral,
     enclosure::Enclosure,
     error::Error,
     evaluator::Evaluator,
-    execution_context::ExecutionContext,
+    execution_context::{ExecutionContext, Scopes},
     executor::Executor,
     expression::Expression,
     fragment::Fragment,
     function::Function,
+    function_resolver::FunctionResolver,
+    graph::Graph,
+    graph_format::GraphFormat,
     interpreter::Interpreter,
     interrupt_guard::InterruptGuard,
     interrupt_handler::InterruptHandler,
+    isolation::Isolation,
     item::Item,
+    iteration::Iteration,
     justfile::Justfile,
+    key_path::KeyPath,
     keyed::Keyed,
     keyword::Keyword,
     lexer::Lexer,
@@ -53,6 +64,8 @@ pub(crate) use {
     list::List,
     load_dotenv::load_dotenv,
     loader::Loader,
+    match_arm::MatchArm,
+    module_parameter::ModuleParameter,
     module_path::ModulePath,
     name::Name,
     namepath::Namepath,
@@ -61,16 +74,22 @@ pub(crate) use {
     output_error::OutputError,
     parameter::Parameter,
     parameter_kind::ParameterKind,
+    parameter_type::ParameterType,
     parser::Parser,
+    pattern::Pattern,
+    plan::Plan,
     platform::Platform,
     platform_interface::PlatformInterface,
     position::Position,
     positional::Positional,
     ran::Ran,
     range_ext::RangeExt,
+    reason::Reason,
     recipe::Recipe,
     recipe_resolver::RecipeResolver,
     recipe_signature::RecipeSignature,
+    recipe_usage::RecipeUsage,
+    runner::Runner,
     scope::Scope,
     search::Search,
     search_config::SearchConfig,
@@ -91,12 +110,19 @@ pub(crate) use {
     token::Token,
     token_kind::TokenKind,
     unresolved_dependency::UnresolvedDependency,
+    unresolved_module::UnresolvedModule,
     unresolved_recipe::UnresolvedRecipe,
     unstable_feature::UnstableFeature,
     use_color::UseColor,
+    user_function::UserFunction,
+    value::Value,
     variables::Variables,
     verbosity::Verbosity,
+    visitor::Visitor,
+    walk::Walk,
     warning::Warning,
+    watcher::Watcher,
+    which::which,
   },
   camino::Utf8Path,
   clap::ValueEnum,
@@ -113,22 +139,23 @@ pub(crate) use {
   snafu::{ResultExt, Snafu},
   std::{
     borrow::Cow,
-    cmp,
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     env,
-    ffi::OsString,
+    ffi::{OsStr, OsString},
     fmt::{self, Debug, Display, Formatter},
     fs,
     io::{self, Read, Seek, Write},
     iter::{self, FromIterator},
     mem,
+    num::NonZeroUsize,
     ops::Deref,
     ops::{Index, Range, RangeInclusive},
     path::{self, Path, PathBuf},
     process::{self, Command, ExitStatus, Stdio},
-    rc::Rc,
     str::{self, Chars},
-    sync::{Mutex, MutexGuard, OnceLock},
+    sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError},
+    thread,
+    time::{Duration, Instant, SystemTime},
     vec,
   },
   strum::{Display, EnumDiscriminants, EnumString, IntoStaticStr},
@@ -152,6 +179,7 @@ pub use {request::Response, unindent::unindent};
 type CompileResult<'a, T = ()> = Result<T, CompileError<'a>>;
 type ConfigResult<T> = Result<T, ConfigError>;
 type FunctionResult = Result<String, String>;
+type ListResult = Result<Vec<String>, String>;
 type RunResult<'a, T = ()> = Result<T, Error<'a>>;
 type SearchResult<T> = Result<T, SearchError>;
 
@@ -182,13 +210,16 @@ pub mod request;
 mod alias;
 mod alias_style;
 mod analyzer;
+mod arg_attribute;
 mod argument_parser;
+mod arithmetic_operator;
 mod assignment;
 mod assignment_resolver;
 mod ast;
 mod attribute;
 mod attribute_set;
 mod binding;
+mod cache;
 mod color;
 mod color_display;
 mod command_color;
@@ -204,9 +235,11 @@ mod config;
 mod config_error;
 mod constants;
 mod count;
+mod data_format;
 mod delimiter;
 mod dependency;
 mod dump_format;
+mod duration_literal;
 mod enclosure;
 mod error;
 mod evaluator;
@@ -215,11 +248,17 @@ mod executor;
 mod expression;
 mod fragment;
 mod function;
+mod function_resolver;
+mod graph;
+mod graph_format;
 mod interpreter;
 mod interrupt_guard;
 mod interrupt_handler;
+mod isolation;
 mod item;
+mod iteration;
 mod justfile;
+mod key_path;
 mod keyed;
 mod keyword;
 mod lexer;
@@ -227,6 +266,8 @@ mod line;
 mod list;
 mod load_dotenv;
 mod loader;
+mod match_arm;
+mod module_parameter;
 mod module_path;
 mod name;
 mod namepath;
@@ -235,17 +276,23 @@ mod output;
 mod output_error;
 mod parameter;
 mod parameter_kind;
+mod parameter_type;
 mod parser;
+mod pattern;
+mod plan;
 mod platform;
 mod platform_interface;
 mod position;
 mod positional;
 mod ran;
 mod range_ext;
+mod reason;
 mod recipe;
 mod recipe_resolver;
 mod recipe_signature;
+mod recipe_usage;
 mod run;
+mod runner;
 mod scope;
 mod search;
 mod search_config;
@@ -267,9 +314,16 @@ mod token;
 mod token_kind;
 mod unindent;
 mod unresolved_dependency;
+mod unresolved_module;
 mod unresolved_recipe;
 mod unstable_feature;
 mod use_color;
+mod user_function;
+mod value;
 mod variables;
 mod verbosity;
+mod visitor;
+mod walk;
 mod warning;
+mod watcher;
+mod which;
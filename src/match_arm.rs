@@ -0,0 +1,32 @@
+use super::*;
+
+/// An arm of a `match` expression, e.g. `'linux' => 'tar.gz'`
+#[derive(PartialEq, Debug, Clone)]
+pub(crate) struct MatchArm<'src> {
+  pub(crate) pattern: StringLiteral<'src>,
+  /// The string or integer token the pattern was parsed from
+  pub(crate) token: Token<'src>,
+  pub(crate) value: Expression<'src>,
+}
+
+impl Display for MatchArm<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    if self.token.kind == TokenKind::Integer {
+      write!(f, "{} => {}", self.token.lexeme(), self.value)
+    } else {
+      write!(f, "{} => {}", self.pattern, self.value)
+    }
+  }
+}
+
+impl Serialize for MatchArm<'_> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    let mut seq = serializer.serialize_seq(None)?;
+    seq.serialize_element(&self.pattern)?;
+    seq.serialize_element(&self.value)?;
+    seq.end()
+  }
+}
@@ -0,0 +1,14 @@
+use super::*;
+
+/// A module parameter, e.g `param port := "8080"`
+pub(crate) type ModuleParameter<'src> = Binding<'src, Option<Expression<'src>>>;
+
+impl Display for ModuleParameter<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "param {}", self.name)?;
+    if let Some(default) = &self.value {
+      write!(f, " := {default}")?;
+    }
+    Ok(())
+  }
+}
@@ -8,6 +8,30 @@ impl<'src> Namepath<'src> {
     Self(self.0.iter().copied().chain(iter::once(name)).collect())
   }
 
+  /// The last name in the path
+  pub(crate) fn last(&self) -> Name<'src> {
+    *self.0.last().unwrap()
+  }
+
+  /// The names of the modules leading up to the last name in the path
+  pub(crate) fn module(&self) -> Vec<&'src str> {
+    self.0[..self.0.len() - 1]
+      .iter()
+      .map(|name| name.lexeme())
+      .collect()
+  }
+
+  /// The names in the path
+  pub(crate) fn lexemes(&self) -> Vec<&'src str> {
+    self.0.iter().map(|name| name.lexeme()).collect()
+  }
+
+  /// The last name in the path and the names of the modules leading up to it
+  pub(crate) fn split_last(&self) -> (Name<'src>, &[Name<'src>]) {
+    let (last, modules) = self.0.split_last().unwrap();
+    (*last, modules)
+  }
+
   pub(crate) fn spaced(&self) -> ModulePath {
     ModulePath {
       path: self.0.iter().map(|name| name.lexeme().into()).collect(),
@@ -16,6 +40,12 @@ impl<'src> Namepath<'src> {
   }
 }
 
+impl<'src> From<Name<'src>> for Namepath<'src> {
+  fn from(name: Name<'src>) -> Self {
+    Self(vec![name])
+  }
+}
+
 impl Display for Namepath<'_> {
   fn fmt(&self, f: &mut Formatter) -> fmt::Result {
     for (i, name) in self.0.iter().enumerate() {
@@ -21,6 +21,7 @@ impl<'src> Node<'src> for Item<'src> {
       Self::Alias(alias) => alias.tree(),
       Self::Assignment(assignment) => assignment.tree(),
       Self::Comment(comment) => comment.tree(),
+      Self::Function(function) => function.tree(),
       Self::Import {
         relative, optional, ..
       } => {
@@ -33,6 +34,7 @@ impl<'src> Node<'src> for Item<'src> {
         tree.push(format!("{relative}"))
       }
       Self::Module {
+        arguments,
         name,
         optional,
         relative,
@@ -50,6 +52,22 @@ impl<'src> Node<'src> for Item<'src> {
           tree = tree.push(format!("{relative}"));
         }
 
+        if !arguments.is_empty() {
+          tree =
+            tree.push(Tree::list(arguments.iter().map(|(name, value)| {
+              Tree::atom(name.lexeme()).push(format!("{value}"))
+            })));
+        }
+
+        tree
+      }
+      Self::Parameter(parameter) => {
+        let mut tree = Tree::atom(Keyword::Param.lexeme()).push(parameter.name.lexeme());
+
+        if let Some(default) = &parameter.value {
+          tree.push_mut(default.tree());
+        }
+
         tree
       }
       Self::Recipe(recipe) => recipe.tree(),
@@ -71,6 +89,20 @@ impl<'src> Node<'src> for Alias<'src, Name<'src>> {
   }
 }
 
+impl<'src> Node<'src> for UserFunction<'src> {
+  fn tree(&self) -> Tree<'src> {
+    Tree::atom(Keyword::Fn.lexeme())
+      .push(self.name.lexeme())
+      .push(Tree::list(
+        self
+          .parameters
+          .iter()
+          .map(|parameter| Tree::atom(parameter.lexeme())),
+      ))
+      .push(self.body.tree())
+  }
+}
+
 impl<'src> Node<'src> for Assignment<'src> {
   fn tree(&self) -> Tree<'src> {
     if self.export {
@@ -89,6 +121,9 @@ impl<'src> Node<'src> for Expression<'src> {
   fn tree(&self) -> Tree<'src> {
     match self {
       Self::And { lhs, rhs } => Tree::atom("&&").push(lhs.tree()).push(rhs.tree()),
+      Self::Arithmetic { lhs, operator, rhs } => Tree::atom(operator.to_string())
+        .push(lhs.tree())
+        .push(rhs.tree()),
       Self::Assert {
         condition: Condition { lhs, rhs, operator },
         error,
@@ -103,7 +138,7 @@ impl<'src> Node<'src> for Expression<'src> {
         let mut tree = Tree::atom("call");
         match thunk {
           Nullary { name, .. } => tree.push_mut(name.lexeme()),
-          Unary { name, arg, .. } => {
+          Unary { name, arg, .. } | UnaryList { name, arg, .. } | UnaryToList { name, arg, .. } => {
             tree.push_mut(name.lexeme());
             tree.push_mut(arg.tree());
           }
@@ -156,6 +191,29 @@ impl<'src> Node<'src> for Expression<'src> {
             tree.push_mut(b.tree());
             tree.push_mut(c.tree());
           }
+          User { name, args } => {
+            tree.push_mut(name.lexeme());
+            for arg in args {
+              tree.push_mut(arg.tree());
+            }
+          }
+        }
+        tree
+      }
+      Self::Comprehension {
+        condition,
+        element,
+        iterable,
+        name,
+      } => {
+        let mut tree = Tree::atom("comprehension");
+        tree.push_mut(element.tree());
+        tree.push_mut(name.lexeme());
+        tree.push_mut(iterable.tree());
+        if let Some(Condition { lhs, rhs, operator }) = condition {
+          tree.push_mut(lhs.tree());
+          tree.push_mut(operator.to_string());
+          tree.push_mut(rhs.tree());
         }
         tree
       }
@@ -173,13 +231,33 @@ impl<'src> Node<'src> for Expression<'src> {
         tree.push_mut(otherwise.tree());
         tree
       }
+      Self::FormatString { contents, .. } => Tree::atom("f").push(contents.tree()),
       Self::Group { contents } => Tree::List(vec![contents.tree()]),
+      Self::Index { lhs, index } => Tree::atom("index").push(lhs.tree()).push(index.tree()),
+      Self::Int { contents } => Tree::atom(Keyword::Int.lexeme()).push(contents.tree()),
+      Self::Integer { token } => Tree::string(token.lexeme()),
       Self::Join { lhs: None, rhs } => Tree::atom("/").push(rhs.tree()),
       Self::Join {
         lhs: Some(lhs),
         rhs,
       } => Tree::atom("/").push(lhs.tree()).push(rhs.tree()),
+      Self::List { elements } => Tree::atom("list").extend(elements.iter().map(Node::tree)),
+      Self::Match {
+        arms,
+        default,
+        subject,
+      } => {
+        let mut tree = Tree::atom(Keyword::Match.lexeme());
+        tree.push_mut(subject.tree());
+        for MatchArm { pattern, value, .. } in arms {
+          tree.push_mut(Tree::list([Tree::string(&pattern.cooked), value.tree()]));
+        }
+        tree.push_mut(Tree::list([Tree::atom("_"), default.tree()]));
+        tree
+      }
       Self::Or { lhs, rhs } => Tree::atom("||").push(lhs.tree()).push(rhs.tree()),
+      Self::QualifiedVariable { path } => Tree::atom(path.to_string()),
+      Self::Splat { contents } => Tree::atom("*").push(contents.tree()),
       Self::StringLiteral {
         string_literal: StringLiteral { cooked, .. },
       } => Tree::string(cooked),
@@ -222,12 +300,27 @@ impl<'src> Node<'src> for UnresolvedRecipe<'src> {
       let mut subsequents = Tree::atom("sups");
 
       for (i, dependency) in self.dependencies.iter().enumerate() {
-        let mut d = Tree::atom(dependency.recipe.lexeme());
+        let mut d = Tree::atom(dependency.recipe.to_string());
 
         for argument in &dependency.arguments {
           d.push_mut(argument.tree());
         }
 
+        if let Some(Iteration {
+          condition,
+          iterable,
+          name,
+        }) = &dependency.iteration
+        {
+          let mut iteration = Tree::atom("for").push(name.lexeme()).push(iterable.tree());
+          if let Some(Condition { lhs, rhs, operator }) = condition {
+            iteration.push_mut(lhs.tree());
+            iteration.push_mut(operator.to_string());
+            iteration.push_mut(rhs.tree());
+          }
+          d.push_mut(iteration);
+        }
+
         if i < self.priors {
           dependencies.push_mut(d);
         } else {
@@ -295,7 +388,8 @@ impl<'src> Node<'src> for Set<'src> {
       | Setting::Quiet(value)
       | Setting::Unstable(value)
       | Setting::WindowsPowerShell(value)
-      | Setting::IgnoreComments(value) => {
+      | Setting::IgnoreComments(value)
+      | Setting::InheritSettings(value) => {
         set.push_mut(value.to_string());
       }
       Setting::ScriptInterpreter(Interpreter { command, arguments })
@@ -306,6 +400,11 @@ impl<'src> Node<'src> for Set<'src> {
           set.push_mut(Tree::string(&argument.cooked));
         }
       }
+      Setting::Path(directories) => {
+        for directory in directories {
+          set.push_mut(Tree::string(&directory.cooked));
+        }
+      }
       Setting::DotenvFilename(value)
       | Setting::DotenvPath(value)
       | Setting::Tempdir(value)
@@ -3,14 +3,89 @@ use super::*;
 /// A single function parameter
 #[derive(PartialEq, Debug, Clone, Serialize)]
 pub(crate) struct Parameter<'src> {
+  /// Values which arguments must be one of, set with `[arg]`
+  pub(crate) choices: Vec<String>,
   /// An optional default expression
   pub(crate) default: Option<Expression<'src>>,
   /// Export parameter as environment variable
   pub(crate) export: bool,
+  /// Help text, set with `[arg]`
+  pub(crate) help: Option<String>,
   /// The kind of parameter
   pub(crate) kind: ParameterKind,
+  /// Long option name, set with `[arg]`
+  pub(crate) long: Option<String>,
   /// The parameter name
   pub(crate) name: Name<'src>,
+  /// Type which arguments must have, set with `[arg]`
+  #[serde(rename = "type")]
+  pub(crate) parameter_type: Option<ParameterType>,
+  /// Pattern which arguments must match, set with `[arg]`
+  pub(crate) pattern: Option<Pattern>,
+  /// Short option name, set with `[arg]`
+  pub(crate) short: Option<char>,
+  /// Value of flag options, set with `[arg]`
+  pub(crate) value: Option<String>,
+}
+
+impl<'src> Parameter<'src> {
+  /// Check that `value` is one of this parameter's choices, matches its
+  /// pattern, and has its type, returning what was expected if not
+  pub(crate) fn check(&self, value: &str) -> Result<(), String> {
+    if !self.choices.is_empty() && !self.choices.iter().any(|choice| choice == value) {
+      return Err(format!(
+        "one of {}",
+        self
+          .choices
+          .iter()
+          .map(|choice| format!("`{choice}`"))
+          .collect::<Vec<String>>()
+          .join(", ")
+      ));
+    }
+
+    if let Some(pattern) = &self.pattern {
+      if !pattern.is_match(value) {
+        return Err(format!("a match for `{pattern}`"));
+      }
+    }
+
+    if let Some(parameter_type) = self.parameter_type {
+      if !parameter_type.check(value) {
+        return Err(parameter_type.description().into());
+      }
+    }
+
+    Ok(())
+  }
+
+  /// Flags take no value on the command line, and are bound to `value` when
+  /// passed and the default, or the empty string, when not
+  pub(crate) fn is_flag(&self) -> bool {
+    self.value.is_some()
+  }
+
+  pub(crate) fn is_option(&self) -> bool {
+    self.long.is_some() || self.short.is_some()
+  }
+
+  pub(crate) fn is_required(&self) -> bool {
+    self.default.is_none() && self.kind != ParameterKind::Star && !self.is_flag()
+  }
+
+  /// Command-line options which set this parameter, long option first
+  pub(crate) fn options(&self) -> Vec<String> {
+    self
+      .long
+      .iter()
+      .map(|long| format!("--{long}"))
+      .chain(self.short.iter().map(|short| format!("-{short}")))
+      .collect()
+  }
+
+  pub(crate) fn signature(&self) -> ParameterSignature<'_, 'src> {
+    ParameterSignature(self)
+  }
 }
 
 impl ColorDisplay for Parameter<'_> {
@@ -28,3 +103,22 @@ impl ColorDisplay for Parameter<'_> {
     Ok(())
   }
 }
+
+/// A parameter as shown in recipe signatures, preceded by its option, if any
+pub(crate) struct ParameterSignature<'a, 'src>(&'a Parameter<'src>);
+
+impl ColorDisplay for ParameterSignature<'_, '_> {
+  fn fmt(&self, f: &mut Formatter, color: Color) -> fmt::Result {
+    if let Some(option) = self.0.options().first() {
+      write!(f, "{}", color.annotation().paint(option))?;
+
+      if self.0.is_flag() {
+        return Ok(());
+      }
+
+      write!(f, " ")?;
+    }
+
+    write!(f, "{}", self.0.color_display(color))
+  }
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+/// The type of a parameter, set with `[arg]`, which arguments must satisfy
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, EnumString, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub(crate) enum ParameterType {
+  /// `true` or `false`
+  Boolean,
+  /// A decimal integer, optionally preceded by a sign
+  Integer,
+  /// A path which exists, relative to the invocation directory
+  Path,
+}
+
+impl ParameterType {
+  pub(crate) fn check(self, value: &str) -> bool {
+    match self {
+      Self::Boolean => value == "true" || value == "false",
+      Self::Integer => value.parse::<i64>().is_ok(),
+      Self::Path => Path::new(value).exists(),
+    }
+  }
+
+  pub(crate) fn description(self) -> &'static str {
+    match self {
+      Self::Boolean => "`true` or `false`",
+      Self::Integer => "an integer",
+      Self::Path => "an existing path",
+    }
+  }
+}
@@ -104,6 +104,11 @@ impl<'run, 'src> Parser<'run, 'src> {
     self.next_are(&[kind])
   }
 
+  /// Check if the next token, without skipping whitespace, is of kind `kind`
+  fn next_is_adjacent(&self, kind: TokenKind) -> bool {
+    self.tokens.get(self.next_token).map(|token| token.kind) == Some(kind)
+  }
+
   /// Check if the next significant tokens are of kinds `kinds`
   ///
   /// The first token in `kinds` will be added to the expected token set.
@@ -269,20 +274,51 @@ impl<'run, 'src> Parser<'run, 'src> {
   /// Accept a dependency
   fn accept_dependency(&mut self) -> CompileResult<'src, Option<UnresolvedDependency<'src>>> {
     if let Some(recipe) = self.accept_name()? {
+      let recipe = self.parse_namepath(recipe)?;
       Ok(Some(UnresolvedDependency {
         arguments: Vec::new(),
+        iteration: None,
         recipe,
       }))
     } else if self.accepted(ParenL)? {
       let recipe = self.parse_name()?;
+      let recipe = self.parse_namepath(recipe)?;
 
       let mut arguments = Vec::new();
 
+      let mut iteration = None;
+
       while !self.accepted(ParenR)? {
-        arguments.push(self.parse_expression()?);
+        arguments.push(self.parse_element()?);
+
+        if self.accepted_keyword(Keyword::For)? {
+          let name = self.parse_name()?;
+          self.expect_keyword(Keyword::In)?;
+          let iterable = self.parse_expression()?;
+
+          let condition = if self.accepted_keyword(Keyword::If)? {
+            Some(self.parse_condition()?)
+          } else {
+            None
+          };
+
+          self.expect(ParenR)?;
+
+          iteration = Some(Iteration {
+            condition,
+            iterable,
+            name,
+          });
+
+          break;
+        }
       }
 
-      Ok(Some(UnresolvedDependency { recipe, arguments }))
+      Ok(Some(UnresolvedDependency {
+        recipe,
+        arguments,
+        iteration,
+      }))
     } else {
       Ok(None)
     }
@@ -346,6 +382,9 @@ impl<'run, 'src> Parser<'run, 'src> {
               self.parse_assignment(true, take_attributes())?,
             ));
           }
+          Some(Keyword::Fn) if self.next_are(&[Identifier, Identifier, ParenL]) => {
+            items.push(Item::Function(self.parse_function(take_attributes())?));
+          }
           Some(Keyword::Unexport)
             if self.next_are(&[Identifier, Identifier, Eof])
               || self.next_are(&[Identifier, Identifier, Eol]) =>
@@ -374,6 +413,7 @@ impl<'run, 'src> Parser<'run, 'src> {
             if self.next_are(&[Identifier, Identifier, Comment])
               || self.next_are(&[Identifier, Identifier, Eof])
               || self.next_are(&[Identifier, Identifier, Eol])
+              || self.next_are(&[Identifier, Identifier, ParenL])
               || self.next_are(&[Identifier, Identifier, Identifier, StringToken])
               || self.next_are(&[Identifier, Identifier, StringToken])
               || self.next_are(&[Identifier, QuestionMark]) =>
@@ -393,6 +433,8 @@ impl<'run, 'src> Parser<'run, 'src> {
               None
             };
 
+            let arguments = self.parse_module_arguments()?;
+
             let attributes = take_attributes();
 
             attributes.ensure_valid_attributes(
@@ -418,12 +460,23 @@ impl<'run, 'src> Parser<'run, 'src> {
             items.push(Item::Module {
               groups,
               absolute: None,
+              arguments,
               doc,
               name,
               optional,
               relative,
             });
           }
+          Some(Keyword::Param)
+            if self.next_are(&[Identifier, Identifier, ColonEquals])
+              || self.next_are(&[Identifier, Identifier, Comment])
+              || self.next_are(&[Identifier, Identifier, Eof])
+              || self.next_are(&[Identifier, Identifier, Eol]) =>
+          {
+            items.push(Item::Parameter(
+              self.parse_module_parameter(take_attributes())?,
+            ));
+          }
           Some(Keyword::Set)
             if self.next_are(&[Identifier, Identifier, ColonEquals])
               || self.next_are(&[Identifier, Identifier, Comment, Eof])
@@ -526,8 +579,98 @@ impl<'run, 'src> Parser<'run, 'src> {
     })
   }
 
-  /// Parse an expression, e.g. `1 + 2`
-  fn parse_expression(&mut self) -> CompileResult<'src, Expression<'src>> {
+  /// Parse module arguments, e.g `(name="api", port="8080")`
+  fn parse_module_arguments(
+    &mut self,
+  ) -> CompileResult<'src, Vec<(Name<'src>, StringLiteral<'src>)>> {
+    let mut arguments = Vec::new();
+
+    if self.accepted(ParenL)? {
+      while !self.next_is(ParenR) {
+        let name = self.parse_name()?;
+        self.expect(Equals)?;
+        arguments.push((name, self.parse_string_literal()?));
+
+        if !self.accepted(Comma)? {
+          break;
+        }
+      }
+
+      self.expect(ParenR)?;
+    }
+
+    Ok(arguments)
+  }
+
+  /// Parse a module parameter, e.g `param port := "8080"`
+  fn parse_module_parameter(
+    &mut self,
+    attributes: AttributeSet<'src>,
+  ) -> CompileResult<'src, ModuleParameter<'src>> {
+    self.presume_keyword(Keyword::Param)?;
+    let name = self.parse_name()?;
+
+    let value = if self.accepted(ColonEquals)? {
+      Some(self.parse_expression()?)
+    } else {
+      None
+    };
+
+    self.expect_eol()?;
+
+    let private = attributes.contains(AttributeDiscriminant::Private);
+
+    attributes.ensure_valid_attributes(
+      "Module parameter",
+      *name,
+      &[AttributeDiscriminant::Private],
+    )?;
+
+    Ok(ModuleParameter {
+      constant: false,
+      export: false,
+      file_depth: self.file_depth,
+      name,
+      private: private || name.lexeme().starts_with('_'),
+      value,
+    })
+  }
+
+  /// Parse a function definition, e.g. `fn tag(name, version) := name + ":" + version`
+  fn parse_function(
+    &mut self,
+    attributes: AttributeSet<'src>,
+  ) -> CompileResult<'src, UserFunction<'src>> {
+    self.presume_keyword(Keyword::Fn)?;
+    let name = self.parse_name()?;
+    self.presume(ParenL)?;
+
+    let mut parameters = Vec::new();
+
+    while !self.next_is(ParenR) {
+      parameters.push(self.parse_name()?);
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(ParenR)?;
+    self.expect(ColonEquals)?;
+    let body = self.parse_expression()?;
+    self.expect_eol()?;
+
+    attributes.ensure_valid_attributes("Function", *name, &[])?;
+
+    Ok(UserFunction {
+      body,
+      name,
+      parameters,
+    })
+  }
+
+  /// Return an error if the maximum parsing recursion depth has been reached
+  fn check_recursion_depth(&self) -> CompileResult<'src> {
     if self.recursion_depth == if cfg!(windows) { 48 } else { 256 } {
       let token = self.next()?;
       return Err(CompileError::new(
@@ -536,6 +679,13 @@ impl<'run, 'src> Parser<'run, 'src> {
       ));
     }
 
+    Ok(())
+  }
+
+  /// Parse an expression, e.g. `1 + 2`
+  fn parse_expression(&mut self) -> CompileResult<'src, Expression<'src>> {
+    self.check_recursion_depth()?;
+
     self.recursion_depth += 1;
 
     let disjunct = self.parse_disjunct()?;
@@ -576,6 +726,8 @@ impl<'run, 'src> Parser<'run, 'src> {
   fn parse_conjunct(&mut self) -> CompileResult<'src, Expression<'src>> {
     if self.accepted_keyword(Keyword::If)? {
       self.parse_conditional()
+    } else if self.next_is_match()? {
+      self.parse_match()
     } else if self.accepted(Slash)? {
       let lhs = None;
       let rhs = self.parse_conjunct()?.into();
@@ -625,6 +777,91 @@ impl<'run, 'src> Parser<'run, 'src> {
     })
   }
 
+  /// Check if the next tokens are a match expression. `match` is only a
+  /// keyword when followed by an expression and `{`, so that justfiles which
+  /// use `match` as a name continue to work.
+  fn next_is_match(&mut self) -> CompileResult<'src, bool> {
+    if !self.next_is(Identifier) || Keyword::Match != self.next()?.lexeme() {
+      return Ok(false);
+    }
+
+    let expected_tokens = self.expected_tokens.clone();
+    let next_token = self.next_token;
+    let recursion_depth = self.recursion_depth;
+    let unstable_features = self.unstable_features.clone();
+
+    self.advance()?;
+
+    let is_match = self.parse_expression().is_ok() && self.next_is(BraceL);
+
+    self.expected_tokens = expected_tokens;
+    self.next_token = next_token;
+    self.recursion_depth = recursion_depth;
+    self.unstable_features = unstable_features;
+
+    Ok(is_match)
+  }
+
+  /// Parse a match expression, e.g. `match os() { "linux" => "tar.gz", _ =>
+  /// "zip" }`
+  fn parse_match(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let keyword = self.advance()?;
+
+    let subject = self.parse_expression()?.into();
+
+    self.expect(BraceL)?;
+
+    let mut arms = Vec::<MatchArm>::new();
+
+    let default = loop {
+      if self.next_is(BraceR) {
+        return Err(keyword.error(CompileErrorKind::MissingMatchDefault));
+      }
+
+      if self.next_is(Identifier) && self.next()?.lexeme() == "_" {
+        self.advance()?;
+        self.expect(EqualsGreater)?;
+        let default = self.parse_expression()?;
+        self.accepted(Comma)?;
+        break default;
+      }
+
+      let (token, pattern) = if let Some(integer) = self.accept(Integer)? {
+        (integer, StringLiteral::from_raw(integer.lexeme()))
+      } else {
+        self.parse_string_literal_token()?
+      };
+
+      if arms.iter().any(|arm| arm.pattern.cooked == pattern.cooked) {
+        return Err(token.error(CompileErrorKind::DuplicateMatchArm {
+          pattern: pattern.cooked,
+        }));
+      }
+
+      self.expect(EqualsGreater)?;
+
+      let value = self.parse_expression()?;
+
+      arms.push(MatchArm {
+        pattern,
+        token,
+        value,
+      });
+
+      if !self.accepted(Comma)? && !self.next_is(BraceR) {
+        return Err(self.unexpected_token()?);
+      }
+    };
+
+    self.expect(BraceR)?;
+
+    Ok(Expression::Match {
+      arms,
+      default: default.into(),
+      subject,
+    })
+  }
+
   fn parse_condition(&mut self) -> CompileResult<'src, Condition<'src>> {
     let lhs = self.parse_expression()?;
     let operator = if self.accepted(BangEquals)? {
@@ -633,6 +870,14 @@ impl<'run, 'src> Parser<'run, 'src> {
       ConditionalOperator::RegexMatch
     } else if self.accepted(BangTilde)? {
       ConditionalOperator::RegexMismatch
+    } else if self.accepted(Less)? {
+      ConditionalOperator::Less
+    } else if self.accepted(LessEquals)? {
+      ConditionalOperator::LessEqual
+    } else if self.accepted(Greater)? {
+      ConditionalOperator::Greater
+    } else if self.accepted(GreaterEquals)? {
+      ConditionalOperator::GreaterEqual
     } else {
       self.expect(EqualsEquals)?;
       ConditionalOperator::Equality
@@ -663,12 +908,32 @@ impl<'run, 'src> Parser<'run, 'src> {
       && tokens.next().is_some_and(|token| token.kind == StringToken)
   }
 
-  /// Parse a value, e.g. `(bar)`
+  /// Parse a value, e.g. `(bar)`, followed by any number of indices, e.g.
+  /// `bar[0]`
   fn parse_value(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let mut value = self.parse_unindexed_value()?;
+
+    while self.accepted(BracketL)? {
+      let index = self.parse_expression()?;
+      self.expect(BracketR)?;
+      value = Expression::Index {
+        lhs: value.into(),
+        index: index.into(),
+      };
+    }
+
+    Ok(value)
+  }
+
+  fn parse_unindexed_value(&mut self) -> CompileResult<'src, Expression<'src>> {
     if self.next_is(StringToken) || self.next_is_shell_expanded_string() {
       Ok(Expression::StringLiteral {
         string_literal: self.parse_string_literal()?,
       })
+    } else if self.next_is(FormatStringStart) {
+      self.parse_format_string()
+    } else if let Some(token) = self.accept(Integer)? {
+      Ok(Expression::Integer { token })
     } else if self.next_is(Backtick) {
       let next = self.next()?;
       let kind = StringKind::from_string_or_backtick(next)?;
@@ -693,6 +958,12 @@ impl<'run, 'src> Parser<'run, 'src> {
         let error = Box::new(self.parse_expression()?);
         self.expect(ParenR)?;
         Ok(Expression::Assert { condition, error })
+      } else if self.next_are(&[Identifier, ParenL]) && Keyword::Int == self.next()?.lexeme() {
+        self.presume_keyword(Keyword::Int)?;
+        self.presume(ParenL)?;
+        let contents = self.parse_integer_expression()?.into();
+        self.expect(ParenR)?;
+        Ok(Expression::Int { contents })
       } else {
         let name = self.parse_name()?;
 
@@ -701,6 +972,9 @@ impl<'run, 'src> Parser<'run, 'src> {
           Ok(Expression::Call {
             thunk: Thunk::resolve(name, arguments)?,
           })
+        } else if self.next_is_adjacent(ColonColon) {
+          let path = self.parse_namepath(name)?;
+          Ok(Expression::QualifiedVariable { path })
         } else {
           Ok(Expression::Variable { name })
         }
@@ -710,11 +984,204 @@ impl<'run, 'src> Parser<'run, 'src> {
       let contents = self.parse_expression()?.into();
       self.expect(ParenR)?;
       Ok(Expression::Group { contents })
+    } else if self.next_is(BracketL) {
+      self.parse_list()
     } else {
       Err(self.unexpected_token()?)
     }
   }
 
+  /// Parse an integer expression inside of `int(…)`, e.g. `port + 1`
+  fn parse_integer_expression(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let mut lhs = self.parse_integer_term()?;
+
+    loop {
+      let operator = if self.accepted(Plus)? {
+        ArithmeticOperator::Addition
+      } else if self.accepted(Minus)? {
+        ArithmeticOperator::Subtraction
+      } else {
+        break;
+      };
+
+      let rhs = self.parse_integer_term()?;
+
+      lhs = Expression::Arithmetic {
+        lhs: lhs.into(),
+        operator,
+        rhs: rhs.into(),
+      };
+    }
+
+    Ok(lhs)
+  }
+
+  /// Parse an integer term inside of `int(…)`, e.g. `shards * 2`
+  fn parse_integer_term(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let mut lhs = self.parse_integer_factor()?;
+
+    loop {
+      let operator = if self.accepted(Asterisk)? {
+        ArithmeticOperator::Multiplication
+      } else if self.accepted(Percent)? {
+        ArithmeticOperator::Remainder
+      } else {
+        break;
+      };
+
+      let rhs = self.parse_integer_factor()?;
+
+      lhs = Expression::Arithmetic {
+        lhs: lhs.into(),
+        operator,
+        rhs: rhs.into(),
+      };
+    }
+
+    Ok(lhs)
+  }
+
+  /// Parse an integer factor inside of `int(…)`, which is either a value or a
+  /// parenthesized integer expression
+  fn parse_integer_factor(&mut self) -> CompileResult<'src, Expression<'src>> {
+    if self.accepted(ParenL)? {
+      self.check_recursion_depth()?;
+      self.recursion_depth += 1;
+      let contents = self.parse_integer_expression()?.into();
+      self.recursion_depth -= 1;
+      self.expect(ParenR)?;
+      Ok(Expression::Group { contents })
+    } else {
+      self.parse_value()
+    }
+  }
+
+  /// Parse a format string, e.g. `f"{{name}}-{{version}}.tar.gz"`, into a
+  /// left-associative concatenation of its text and interpolations. The
+  /// concatenation always starts with text, so that interpolated lists are
+  /// joined into strings, and interpolations are wrapped in groups, so that
+  /// they can be told apart from text when the format string is displayed.
+  fn parse_format_string(&mut self) -> CompileResult<'src, Expression<'src>> {
+    let start = self.presume(FormatStringStart)?;
+
+    let kind = StringKind::from_token_start(&start.lexeme()[1..]).ok_or_else(|| {
+      start.error(CompileErrorKind::Internal {
+        message: "Parser::parse_format_string: invalid format string start".into(),
+      })
+    })?;
+
+    let mut contents = Expression::StringLiteral {
+      string_literal: StringLiteral {
+        cooked: String::new(),
+        expand: false,
+        kind,
+        raw: "",
+      },
+    };
+
+    let mut empty = true;
+
+    loop {
+      let fragment = if let Some(text) = self.accept(Text)? {
+        let cooked = if kind.processes_escape_sequences() {
+          Self::cook_string(text, text.lexeme())?
+        } else {
+          text.lexeme().to_owned()
+        };
+
+        Expression::StringLiteral {
+          string_literal: StringLiteral {
+            cooked: cooked.replace("{{{{", "{{"),
+            expand: false,
+            kind,
+            raw: text.lexeme(),
+          },
+        }
+      } else if self.accepted(InterpolationStart)? {
+        let contents = self.parse_expression()?.into();
+        self.expect(InterpolationEnd)?;
+        Expression::Group { contents }
+      } else {
+        self.expect(FormatStringEnd)?;
+        break;
+      };
+
+      contents = if empty && matches!(fragment, Expression::StringLiteral { .. }) {
+        fragment
+      } else {
+        Expression::Concatenation {
+          lhs: contents.into(),
+          rhs: fragment.into(),
+        }
+      };
+
+      empty = false;
+    }
+
+    Ok(Expression::FormatString {
+      contents: contents.into(),
+      kind,
+    })
+  }
+
+  /// Parse a list, e.g. `["a", *b]`, or a list comprehension, e.g.
+  /// `[x + ".txt" for x in b if x != "c"]`
+  fn parse_list(&mut self) -> CompileResult<'src, Expression<'src>> {
+    self.presume(BracketL)?;
+
+    let mut elements = Vec::new();
+
+    while !self.next_is(BracketR) {
+      let element = self.parse_element()?;
+
+      if elements.is_empty()
+        && !matches!(element, Expression::Splat { .. })
+        && self.accepted_keyword(Keyword::For)?
+      {
+        let name = self.parse_name()?;
+        self.expect_keyword(Keyword::In)?;
+        let iterable = self.parse_expression()?.into();
+
+        let condition = if self.accepted_keyword(Keyword::If)? {
+          Some(self.parse_condition()?)
+        } else {
+          None
+        };
+
+        self.expect(BracketR)?;
+
+        return Ok(Expression::Comprehension {
+          condition,
+          element: element.into(),
+          iterable,
+          name,
+        });
+      }
+
+      elements.push(element);
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(BracketR)?;
+
+    Ok(Expression::List { elements })
+  }
+
+  /// Parse a list element or dependency argument, which may be splatted, e.g.
+  /// `*args`
+  fn parse_element(&mut self) -> CompileResult<'src, Expression<'src>> {
+    if self.accepted(Asterisk)? {
+      Ok(Expression::Splat {
+        contents: self.parse_value()?.into(),
+      })
+    } else {
+      self.parse_expression()
+    }
+  }
+
   /// Parse a string literal, e.g. `"FOO"`, returning the string literal and the string token
   fn parse_string_literal_token(
     &mut self,
@@ -858,6 +1325,19 @@ impl<'run, 'src> Parser<'run, 'src> {
     self.expect(Identifier).map(Name::from_identifier)
   }
 
+  /// Parse the rest of a namepath starting with `first`, e.g. `foo::bar`. The
+  /// `::` separators must immediately follow the preceding name.
+  fn parse_namepath(&mut self, first: Name<'src>) -> CompileResult<'src, Namepath<'src>> {
+    let mut namepath = Namepath::from(first);
+
+    while self.next_is_adjacent(ColonColon) {
+      self.advance()?;
+      namepath = namepath.join(self.parse_name()?);
+    }
+
+    Ok(namepath)
+  }
+
   /// Parse sequence of comma-separated expressions
   fn parse_sequence(&mut self) -> CompileResult<'src, Vec<Expression<'src>>> {
     self.presume(ParenL)?;
@@ -964,6 +1444,10 @@ impl<'run, 'src> Parser<'run, 'src> {
     let private =
       name.lexeme().starts_with('_') || attributes.contains(AttributeDiscriminant::Private);
 
+    let mut parameters = positional.into_iter().chain(variadic).collect::<Vec<_>>();
+
+    Self::apply_arg_attributes(name, &attributes, &mut parameters)?;
+
     let mut doc = doc.map(ToOwned::to_owned);
 
     for attribute in &attributes {
@@ -982,13 +1466,123 @@ impl<'run, 'src> Parser<'run, 'src> {
       import_offsets: self.import_offsets.clone(),
       name,
       namepath: self.module_namepath.join(name),
-      parameters: positional.into_iter().chain(variadic).collect(),
+      parameters,
       priors,
       private,
       quiet,
     })
   }
 
+  /// Configure recipe parameters with `[arg]` attributes
+  fn apply_arg_attributes(
+    recipe: Name<'src>,
+    attributes: &AttributeSet<'src>,
+    parameters: &mut [Parameter<'src>],
+  ) -> CompileResult<'src> {
+    let mut configured = BTreeSet::new();
+    let mut options = BTreeSet::new();
+
+    for attribute in attributes {
+      let Attribute::Arg(arg) = attribute else {
+        continue;
+      };
+
+      let ArgAttribute {
+        choices,
+        help,
+        long,
+        name,
+        pattern,
+        short,
+        value,
+        value_type,
+      } = &**arg;
+
+      let Some(parameter) = parameters
+        .iter_mut()
+        .find(|parameter| parameter.name.lexeme() == name.cooked)
+      else {
+        return Err(recipe.error(CompileErrorKind::UndefinedArgAttribute {
+          recipe: recipe.lexeme(),
+          parameter: name.cooked.clone(),
+        }));
+      };
+
+      if !configured.insert(parameter.name.lexeme()) {
+        return Err(recipe.error(CompileErrorKind::DuplicateArgAttribute {
+          recipe: recipe.lexeme(),
+          parameter: name.cooked.clone(),
+        }));
+      }
+
+      if (long.is_some() || short.is_some()) && parameter.kind.is_variadic() {
+        return Err(recipe.error(CompileErrorKind::VariadicOption {
+          parameter: parameter.name.lexeme(),
+        }));
+      }
+
+      parameter.help = help.as_ref().map(|help| help.cooked.clone());
+      parameter.long = long.as_ref().map(|long| long.cooked.clone());
+      parameter.short = short.as_ref().and_then(|short| short.cooked.chars().next());
+      parameter.value = value.as_ref().map(|value| value.cooked.clone());
+
+      parameter.choices = choices
+        .iter()
+        .flat_map(|choices| choices.cooked.split_whitespace())
+        .map(str::to_owned)
+        .collect();
+      parameter.parameter_type = value_type
+        .as_ref()
+        .and_then(|value_type| value_type.cooked.parse().ok());
+      parameter.pattern = pattern
+        .as_ref()
+        .and_then(|pattern| Pattern::new(&pattern.cooked).ok());
+
+      // paths are relative to the invocation directory, which is unknown
+      // until run time
+      if parameter.parameter_type != Some(ParameterType::Path) {
+        let default = match &parameter.default {
+          Some(Expression::StringLiteral { string_literal }) => {
+            Some(string_literal.cooked.as_str())
+          }
+          Some(Expression::Integer { token }) => Some(token.lexeme()),
+          _ => None,
+        };
+
+        if let Some(default) = default {
+          if let Err(expected) = parameter.check(default) {
+            return Err(recipe.error(CompileErrorKind::InvalidParameterDefault {
+              parameter: parameter.name.lexeme(),
+              default: default.to_owned(),
+              expected,
+            }));
+          }
+        }
+
+        if let Some(value) = &parameter.value {
+          if let Err(expected) = parameter.check(value) {
+            return Err(recipe.error(CompileErrorKind::InvalidFlagValue {
+              parameter: parameter.name.lexeme(),
+              value: value.clone(),
+              expected,
+            }));
+          }
+        }
+      }
+
+      for option in parameter.options() {
+        if !options.insert(option.clone()) {
+          return Err(recipe.error(CompileErrorKind::DuplicateOption {
+            recipe: recipe.lexeme(),
+            option,
+          }));
+        }
+      }
+    }
+
+    Ok(())
+  }
+
   /// Parse a recipe parameter
   fn parse_parameter(&mut self, kind: ParameterKind) -> CompileResult<'src, Parameter<'src>> {
     let export = self.accepted(Dollar)?;
@@ -1002,10 +1596,17 @@ impl<'run, 'src> Parser<'run, 'src> {
     };
 
     Ok(Parameter {
+      choices: Vec::new(),
       default,
       export,
+      help: None,
       kind,
+      long: None,
       name,
+      parameter_type: None,
+      pattern: None,
+      short: None,
+      value: None,
     })
   }
 
@@ -1035,7 +1636,7 @@ impl<'run, 'src> Parser<'run, 'src> {
               return Err(self.unexpected_token()?);
             }
           }
-        };
+        }
 
         lines.push(Line { fragments, number });
       }
@@ -1093,6 +1694,7 @@ impl<'run, 'src> Parser<'run, 'src> {
       Keyword::Export => Some(Setting::Export(self.parse_set_bool()?)),
       Keyword::Fallback => Some(Setting::Fallback(self.parse_set_bool()?)),
       Keyword::IgnoreComments => Some(Setting::IgnoreComments(self.parse_set_bool()?)),
+      Keyword::InheritSettings => Some(Setting::InheritSettings(self.parse_set_bool()?)),
       Keyword::PositionalArguments => Some(Setting::PositionalArguments(self.parse_set_bool()?)),
       Keyword::Quiet => Some(Setting::Quiet(self.parse_set_bool()?)),
       Keyword::Unstable => Some(Setting::Unstable(self.parse_set_bool()?)),
@@ -1109,6 +1711,7 @@ impl<'run, 'src> Parser<'run, 'src> {
     let set_value = match keyword {
       Keyword::DotenvFilename => Some(Setting::DotenvFilename(self.parse_string_literal()?)),
       Keyword::DotenvPath => Some(Setting::DotenvPath(self.parse_string_literal()?)),
+      Keyword::Path => Some(Setting::Path(self.parse_string_literal_list()?)),
       Keyword::ScriptInterpreter => Some(Setting::ScriptInterpreter(self.parse_interpreter()?)),
       Keyword::Shell => Some(Setting::Shell(self.parse_interpreter()?)),
       Keyword::Tempdir => Some(Setting::Tempdir(self.parse_string_literal()?)),
@@ -1149,6 +1752,25 @@ impl<'run, 'src> Parser<'run, 'src> {
     Ok(Interpreter { arguments, command })
   }
 
+  /// Parse list of string literals, i.e., `['bin', 'node_modules/.bin']`
+  fn parse_string_literal_list(&mut self) -> CompileResult<'src, Vec<StringLiteral<'src>>> {
+    self.expect(BracketL)?;
+
+    let mut list = Vec::new();
+
+    while !self.next_is(BracketR) {
+      list.push(self.parse_string_literal()?);
+
+      if !self.accepted(Comma)? {
+        break;
+      }
+    }
+
+    self.expect(BracketR)?;
+
+    Ok(list)
+  }
+
   /// Item attributes, i.e., `[macos]` or `[confirm: "warning!"]`
   fn parse_attributes(&mut self) -> CompileResult<'src, Option<(Token<'src>, AttributeSet<'src>)>> {
     let mut attributes = BTreeMap::new();
@@ -1163,12 +1785,25 @@ impl<'run, 'src> Parser<'run, 'src> {
         let name = self.parse_name()?;
 
         let mut arguments = Vec::new();
+        let mut keywords = Vec::new();
 
         if self.accepted(Colon)? {
           arguments.push(self.parse_string_literal()?);
         } else if self.accepted(ParenL)? {
           loop {
-            arguments.push(self.parse_string_literal()?);
+            if self.next_is(Identifier) {
+              let keyword = self.parse_name()?;
+              let value = if self.accepted(Equals)? {
+                Some(self.parse_string_literal()?)
+              } else {
+                None
+              };
+              keywords.push((keyword, value));
+            } else if let Some(integer) = self.accept(Integer)? {
+              arguments.push(StringLiteral::from_raw(integer.lexeme()));
+            } else {
+              arguments.push(self.parse_string_literal()?);
+            }
 
             if !self.accepted(Comma)? {
               break;
@@ -1177,7 +1812,7 @@ impl<'run, 'src> Parser<'run, 'src> {
           self.expect(ParenR)?;
         }
 
-        let attribute = Attribute::new(name, arguments)?;
+        let attribute = Attribute::new(name, arguments, keywords)?;
 
         let first = attributes.get(&attribute).or_else(|| {
           if attribute.repeatable() {
@@ -2178,6 +2813,12 @@ mod tests {
     tree: (justfile (set export true)),
   }
 
+  test! {
+    name: set_inherit_settings_implicit,
+    text: "set inherit-settings",
+    tree: (justfile (set inherit_settings true)),
+  }
+
   test! {
     name: set_export_false,
     text: "set export := false",
@@ -2310,6 +2951,18 @@ mod tests {
     tree: (justfile (set working_directory "foo")),
   }
 
+  test! {
+    name: set_path,
+    text: "set path := ['foo', 'bar',]",
+    tree: (justfile (set path "foo" "bar")),
+  }
+
+  test! {
+    name: set_path_empty,
+    text: "set path := []",
+    tree: (justfile (set path)),
+  }
+
   test! {
     name: conditional,
     text: "a := if b == c { d } else { e }",
@@ -2322,6 +2975,30 @@ mod tests {
     tree: (justfile (assignment a (if b != c d e))),
   }
 
+  test! {
+    name: conditional_less,
+    text: "a := if b < c { d } else { e }",
+    tree: (justfile (assignment a (if b < c d e))),
+  }
+
+  test! {
+    name: conditional_less_equal,
+    text: "a := if b <= c { d } else { e }",
+    tree: (justfile (assignment a (if b <= c d e))),
+  }
+
+  test! {
+    name: conditional_greater,
+    text: "a := if b > c { d } else { e }",
+    tree: (justfile (assignment a (if b > c d e))),
+  }
+
+  test! {
+    name: conditional_greater_equal,
+    text: "a := if b >= c { d } else { e }",
+    tree: (justfile (assignment a (if b >= c d e))),
+  }
+
   test! {
     name: conditional_concatenations,
     text: "a := if b0 + b1 == c0 + c1 { d0 + d1 } else { e0 + e1 }",
@@ -2352,6 +3029,132 @@ mod tests {
     tree: (justfile (assignment a (if b == c d (if b == c d e)))),
   }
 
+  test! {
+    name: match_expression,
+    text: "a := match b { 'c' => d, 1 => e, _ => f }",
+    tree: (justfile (assignment a (match b ("c" d) ("1" e) (_ f)))),
+  }
+
+  test! {
+    name: match_expression_trailing_comma,
+    text: "a := match b {\n  'c' => d,\n  _ => f,\n}",
+    tree: (justfile (assignment a (match b ("c" d) (_ f)))),
+  }
+
+  test! {
+    name: match_expression_default_only,
+    text: "a := match b { _ => f }",
+    tree: (justfile (assignment a (match b (_ f)))),
+  }
+
+  test! {
+    name: format_string,
+    text: "a := f'b{{c}}d'",
+    tree: (justfile (assignment a (f (+ (+ "b" (c)) "d")))),
+  }
+
+  test! {
+    name: format_string_starting_with_interpolation,
+    text: "a := f'{{b}}{{c}}'",
+    tree: (justfile (assignment a (f (+ (+ "" (b)) (c))))),
+  }
+
+  test! {
+    name: format_string_text_only,
+    text: "a := f'b'",
+    tree: (justfile (assignment a (f "b"))),
+  }
+
+  test! {
+    name: format_string_expression,
+    text: "a := f'{{b + c}}'",
+    tree: (justfile (assignment a (f (+ "" ((+ b c)))))),
+  }
+
+  test! {
+    name: int,
+    text: "a := int(b)",
+    tree: (justfile (assignment a (int b))),
+  }
+
+  test! {
+    name: int_arithmetic,
+    text: "a := int(b + 1 - c)",
+    tree: (justfile (assignment a (int (- (+ b "1") c)))),
+  }
+
+  test! {
+    name: int_precedence,
+    text: "a := int(b + c * d % e)",
+    tree: (justfile (assignment a (int (+ b (% (* c d) e))))),
+  }
+
+  test! {
+    name: int_group,
+    text: "a := int((b + c) * d)",
+    tree: (justfile (assignment a (int (* ((+ b c)) d)))),
+  }
+
+  test! {
+    name: int_function_arguments_are_strings,
+    text: "a := int(len(b + c))",
+    tree: (justfile (assignment a (int (call len (+ b c))))),
+  }
+
+  test! {
+    name: int_variable,
+    text: "a := int + b",
+    tree: (justfile (assignment a (+ int b))),
+  }
+
+  test! {
+    name: integer_literal,
+    text: "a := 8080",
+    tree: (justfile (assignment a "8080")),
+  }
+
+  test! {
+    name: list,
+    text: "a := ['b', c, *d,]",
+    tree: (justfile (assignment a (list "b" c (* d)))),
+  }
+
+  test! {
+    name: list_empty,
+    text: "a := []",
+    tree: (justfile (assignment a (list))),
+  }
+
+  test! {
+    name: list_index,
+    text: "a := b[0][c]",
+    tree: (justfile (assignment a (index (index b "0") c))),
+  }
+
+  test! {
+    name: list_comprehension,
+    text: "a := [x + 'y' for x in b if x != 'c']",
+    tree: (justfile (assignment a (comprehension (+ x "y") x b x != "c"))),
+  }
+
+  test! {
+    name: dependency_splat,
+    text: "a: (b *c)",
+    tree: (justfile (recipe a (deps (b (* c))))),
+  }
+
+  test! {
+    name: dependency_iteration,
+    text: "a: (b x for x in xs)",
+    tree: (justfile (recipe a (deps (b x (for x xs))))),
+  }
+
+  test! {
+    name: dependency_iteration_condition,
+    text: "a: (b x for x in xs if x != 'c')",
+    tree: (justfile (recipe a (deps (b x (for x xs x != "c"))))),
+  }
+
   test! {
     name: import,
     text: "import \"some/file/path.txt\"     \n",
@@ -2388,6 +3191,30 @@ mod tests {
     tree: (justfile (mod ? foo "some/file/path.txt")),
   }
 
+  test! {
+    name: module_with_arguments,
+    text: "mod foo \"foo.just\" (a=\"x\", b=\"y\")",
+    tree: (justfile (mod foo "foo.just" ((a "x") (b "y")))),
+  }
+
+  test! {
+    name: module_without_path_with_arguments,
+    text: "mod foo (a=\"x\",)",
+    tree: (justfile (mod foo ((a "x")))),
+  }
+
+  test! {
+    name: module_parameter,
+    text: "param foo",
+    tree: (justfile (param foo)),
+  }
+
+  test! {
+    name: module_parameter_with_default,
+    text: "param foo := 'bar' + baz",
+    tree: (justfile (param foo (+ "bar" baz))),
+  }
+
   test! {
     name: assert,
     text: "a := assert(foo == \"bar\", \"error\")",
@@ -2443,7 +3270,10 @@ mod tests {
     kind:   UnexpectedToken {
       expected: vec![
         Backtick,
+        BracketL,
+        FormatStringStart,
         Identifier,
+        Integer,
         ParenL,
         StringToken,
       ],
@@ -2461,7 +3291,10 @@ mod tests {
     kind:   UnexpectedToken {
       expected: vec![
         Backtick,
+        BracketL,
+        FormatStringStart,
         Identifier,
+        Integer,
         ParenL,
         StringToken,
       ],
@@ -2505,7 +3338,10 @@ mod tests {
     kind: UnexpectedToken{
       expected: vec![
         Backtick,
+        BracketL,
+        FormatStringStart,
         Identifier,
+        Integer,
         ParenL,
         ParenR,
         Slash,
@@ -2525,7 +3361,10 @@ mod tests {
     kind:   UnexpectedToken{
       expected: vec![
         Backtick,
+        BracketL,
+        FormatStringStart,
         Identifier,
+        Integer,
         ParenL,
         ParenR,
         Slash,
@@ -2717,36 +3556,6 @@ mod tests {
     },
   }
 
-  error! {
-    name:   unknown_function,
-    input:  "a := foo()",
-    offset: 5,
-    line:   0,
-    column: 5,
-    width:  3,
-    kind:   UnknownFunction{function: "foo"},
-  }
-
-  error! {
-    name:   unknown_function_in_interpolation,
-    input:  "a:\n echo {{bar()}}",
-    offset: 11,
-    line:   1,
-    column: 8,
-    width:  3,
-    kind:   UnknownFunction{function: "bar"},
-  }
-
-  error! {
-    name:   unknown_function_in_default,
-    input:  "a f=baz():",
-    offset: 4,
-    line:   0,
-    column: 4,
-    width:  3,
-    kind:   UnknownFunction{function: "baz"},
-  }
-
   error! {
     name: function_argument_count_nullary,
     input: "x := arch('foo')",
@@ -0,0 +1,43 @@
+use super::*;
+
+/// A regular expression which parameter arguments must match in their
+/// entirety, set with `[arg]`
+#[derive(Debug, Clone)]
+pub(crate) struct Pattern {
+  regex: Regex,
+  source: String,
+}
+
+impl Pattern {
+  pub(crate) fn new(source: &str) -> Result<Self, regex::Error> {
+    Ok(Self {
+      regex: Regex::new(&format!("^(?:{source})$"))?,
+      source: source.into(),
+    })
+  }
+
+  pub(crate) fn is_match(&self, haystack: &str) -> bool {
+    self.regex.is_match(haystack)
+  }
+}
+
+impl Display for Pattern {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.source)
+  }
+}
+
+impl Serialize for Pattern {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    serializer.serialize_str(&self.source)
+  }
+}
+
+impl PartialEq for Pattern {
+  fn eq(&self, other: &Self) -> bool {
+    self.source == other.source
+  }
+}
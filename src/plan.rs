@@ -0,0 +1,163 @@
+use super::*;
+
+/// The recipes that `just --plan` reports would run, in the order that they
+/// would run, including recipes which would be skipped because they already
+/// ran with the same arguments.
+#[derive(Default)]
+pub(crate) struct Plan<'src> {
+  steps: Mutex<Vec<Step<'src>>>,
+}
+
+struct Step<'src> {
+  arguments: Vec<(Name<'src>, String)>,
+  reason: Reason<'src>,
+  recipe: Namepath<'src>,
+  skipped: bool,
+}
+
+impl<'src> Plan<'src> {
+  fn push(
+    &self,
+    reason: Reason<'src>,
+    recipe: &Recipe<'src>,
+    scope: &Scope<'src, '_>,
+    skipped: bool,
+  ) {
+    let arguments = recipe
+      .parameters
+      .iter()
+      .map(|parameter| {
+        (
+          parameter.name,
+          scope
+            .value(parameter.name.lexeme())
+            .map(ToString::to_string)
+            .unwrap_or_default(),
+        )
+      })
+      .collect();
+
+    self
+      .steps
+      .lock()
+      .unwrap_or_else(PoisonError::into_inner)
+      .push(Step {
+        arguments,
+        reason,
+        recipe: recipe.namepath.clone(),
+        skipped,
+      });
+  }
+}
+
+impl<'src> Visitor<'src> for Plan<'src> {
+  const CONCURRENT: bool = false;
+
+  fn confirm(
+    &self,
+    _context: &ExecutionContext<'src, '_>,
+    _recipe: &Recipe<'src>,
+  ) -> RunResult<'src> {
+    Ok(())
+  }
+
+  fn skip(
+    &self,
+    arguments: &[Vec<String>],
+    context: &ExecutionContext<'src, '_>,
+    recipe: &Recipe<'src>,
+    reason: Reason<'src>,
+  ) -> RunResult<'src> {
+    let (scope, _positional) =
+      Evaluator::evaluate_parameters(context, reason.is_dependency(), arguments, recipe)?;
+
+    self.push(reason, recipe, &scope, true);
+
+    Ok(())
+  }
+
+  fn visit(
+    &self,
+    _context: &ExecutionContext<'src, '_>,
+    _positional: &[String],
+    reason: Reason<'src>,
+    recipe: &Recipe<'src>,
+    scope: &Scope<'src, '_>,
+  ) -> RunResult<'src> {
+    self.push(reason, recipe, scope, false);
+
+    Ok(())
+  }
+}
+
+impl Display for Plan<'_> {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    let steps = self.steps.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let invocations = steps
+      .iter()
+      .map(|step| {
+        iter::once(step.recipe.to_string())
+          .chain(
+            step
+              .arguments
+              .iter()
+              .map(|(name, value)| format!("{name}={value:?}")),
+          )
+          .collect::<Vec<String>>()
+          .join(" ")
+      })
+      .collect::<Vec<String>>();
+
+    let count = steps.iter().filter(|step| !step.skipped).count();
+
+    let number_width = count.to_string().len() + 1;
+
+    let invocation_width = invocations
+      .iter()
+      .map(|invocation| UnicodeWidthStr::width(invocation.as_str()))
+      .max()
+      .unwrap_or_default();
+
+    let mut number = 0;
+
+    for (step, invocation) in steps.iter().zip(invocations) {
+      let prefix = if step.skipped {
+        "-".to_owned()
+      } else {
+        number += 1;
+        format!("{number}.")
+      };
+
+      let mut notes = Vec::new();
+
+      match &step.reason {
+        Reason::Dependency { parallel, parent } => notes.push(if *parallel {
+          format!("parallel dependency of {parent}")
+        } else {
+          format!("dependency of {parent}")
+        }),
+        Reason::Invocation => {}
+        Reason::Subsequent { parent } => notes.push(format!("subsequent of {parent}")),
+      }
+
+      if step.skipped {
+        notes.push("skipped, already run".into());
+      }
+
+      if notes.is_empty() {
+        writeln!(f, "{prefix:number_width$} {invocation}")?;
+      } else {
+        writeln!(
+          f,
+          "{prefix:number_width$} {invocation}{:padding$}  # {}",
+          "",
+          notes.join(", "),
+          padding = invocation_width - UnicodeWidthStr::width(invocation.as_str()),
+        )?;
+      }
+    }
+
+    Ok(())
+  }
+}
@@ -33,11 +33,39 @@ impl PlatformInterface for Platform {
     fs::set_permissions(path, permissions)
   }
 
+  fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::metadata(path)
+      .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+  }
+
   fn signal_from_exit_status(exit_status: ExitStatus) -> Option<i32> {
     use std::os::unix::process::ExitStatusExt;
     exit_status.signal()
   }
 
+  fn terminate(pid: u32) -> io::Result<()> {
+    signal(pid_t(pid)?, libc::SIGTERM)
+  }
+
+  fn new_process_group(command: &mut Command) {
+    use std::os::unix::process::CommandExt;
+    command.process_group(0);
+  }
+
+  fn interrupt_process_group(pid: u32) -> io::Result<()> {
+    signal_process_group(pid, libc::SIGINT)
+  }
+
+  fn terminate_process_group(pid: u32) -> io::Result<()> {
+    signal_process_group(pid, libc::SIGTERM)
+  }
+
+  fn kill_process_group(pid: u32) -> io::Result<()> {
+    signal_process_group(pid, libc::SIGKILL)
+  }
+
   fn convert_native_path(_working_directory: &Path, path: &Path) -> FunctionResult {
     path
       .to_str()
@@ -46,6 +74,31 @@ impl PlatformInterface for Platform {
   }
 }
 
+#[cfg(unix)]
+fn pid_t(pid: u32) -> io::Result<libc::pid_t> {
+  libc::pid_t::try_from(pid).map_err(|error| io::Error::new(io::ErrorKind::InvalidInput, error))
+}
+
+#[cfg(unix)]
+fn signal(pid: libc::pid_t, signal: libc::c_int) -> io::Result<()> {
+  // SAFETY: `kill` has no memory safety preconditions
+  if unsafe { libc::kill(pid, signal) } == 0 {
+    Ok(())
+  } else {
+    Err(io::Error::last_os_error())
+  }
+}
+
+/// Send `signal` to every process in the process group led by `pid`,
+/// succeeding if the group has already exited
+#[cfg(unix)]
+fn signal_process_group(pid: u32, signal: libc::c_int) -> io::Result<()> {
+  match self::signal(-pid_t(pid)?, signal) {
+    Err(io_error) if io_error.raw_os_error() == Some(libc::ESRCH) => Ok(()),
+    result => result,
+  }
+}
+
 #[cfg(windows)]
 impl PlatformInterface for Platform {
   fn make_shebang_command(
@@ -91,12 +144,50 @@ impl PlatformInterface for Platform {
     Ok(())
   }
 
+  fn is_executable(path: &Path) -> bool {
+    // windows has no execute permission, so any file is executable
+    path.is_file()
+  }
+
   fn signal_from_exit_status(_exit_status: process::ExitStatus) -> Option<i32> {
     // The rust standard library does not expose a way to extract a signal from a
     // windows process exit status, so just return None
     None
   }
 
+  fn terminate(pid: u32) -> io::Result<()> {
+    let status = Command::new("taskkill")
+      .args(["/T", "/F", "/PID", &pid.to_string()])
+      .stdout(Stdio::null())
+      .stderr(Stdio::null())
+      .status()?;
+
+    if status.success() {
+      Ok(())
+    } else {
+      Err(io::Error::other(format!("taskkill failed: {status}")))
+    }
+  }
+
+  fn new_process_group(_command: &mut Command) {
+    // children share the console with `just`, and `taskkill /T` terminates
+    // the whole process tree, so a new process group is not needed
+  }
+
+  fn interrupt_process_group(_pid: u32) -> io::Result<()> {
+    // console interrupts are delivered to every process attached to the
+    // console, so there is nothing to forward
+    Ok(())
+  }
+
+  fn terminate_process_group(pid: u32) -> io::Result<()> {
+    Self::terminate(pid)
+  }
+
+  fn kill_process_group(pid: u32) -> io::Result<()> {
+    Self::terminate(pid)
+  }
+
   fn convert_native_path(working_directory: &Path, path: &Path) -> FunctionResult {
     // Translate path from windows style to unix style
     let mut cygpath = Command::new("cygpath");
@@ -12,10 +12,29 @@ pub(crate) trait PlatformInterface {
   /// Set the execute permission on the file pointed to by `path`
   fn set_execute_permission(path: &Path) -> io::Result<()>;
 
+  /// Check if `path` is an executable file
+  fn is_executable(path: &Path) -> bool;
+
   /// Extract the signal from a process exit status, if it was terminated by a
   /// signal
   fn signal_from_exit_status(exit_status: ExitStatus) -> Option<i32>;
 
+  /// Ask the process with ID `pid` to terminate
+  fn terminate(pid: u32) -> io::Result<()>;
+
+  /// Run `command` in a new process group, so that it and its descendants
+  /// can be signaled together
+  fn new_process_group(command: &mut Command);
+
+  /// Forward an interrupt to the process group led by `pid`
+  fn interrupt_process_group(pid: u32) -> io::Result<()>;
+
+  /// Ask the process group led by `pid` to terminate
+  fn terminate_process_group(pid: u32) -> io::Result<()>;
+
+  /// Forcibly kill the process group led by `pid`
+  fn kill_process_group(pid: u32) -> io::Result<()>;
+
   /// Translate a path from a "native" path to a path the interpreter expects
   fn convert_native_path(working_directory: &Path, path: &Path) -> FunctionResult;
 }
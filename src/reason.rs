@@ -0,0 +1,19 @@
+use super::*;
+
+/// Why a recipe is run
+pub(crate) enum Reason<'src> {
+  Dependency {
+    parallel: bool,
+    parent: Namepath<'src>,
+  },
+  Invocation,
+  Subsequent {
+    parent: Namepath<'src>,
+  },
+}
+
+impl Reason<'_> {
+  pub(crate) fn is_dependency(&self) -> bool {
+    !matches!(self, Self::Invocation)
+  }
+}
@@ -2,7 +2,11 @@ use super::*;
 
 /// Return a `Error::Signal` if the process was terminated by a signal,
 /// otherwise return an `Error::UnknownFailure`
-fn error_from_signal(recipe: &str, line_number: Option<usize>, exit_status: ExitStatus) -> Error {
+fn error_from_signal(
+  recipe: &str,
+  line_number: Option<usize>,
+  exit_status: ExitStatus,
+) -> Error<'_> {
   match Platform::signal_from_exit_status(exit_status) {
     Some(signal) => Error::Signal {
       recipe,
@@ -16,6 +20,10 @@ fn error_from_signal(recipe: &str, line_number: Option<usize>, exit_status: Exit
   }
 }
 
+/// Environment variable containing the current attempt number of recipes with
+/// a `[retry]` attribute, starting at 1
+const ATTEMPT_ENVIRONMENT_VARIABLE: &str = "JUST_ATTEMPT";
+
 /// A recipe, e.g. `foo: bar baz`
 #[derive(PartialEq, Debug, Clone, Serialize)]
 pub(crate) struct Recipe<'src, D = Dependency<'src>> {
@@ -45,7 +53,7 @@ impl<'src, D> Recipe<'src, D> {
     self
       .parameters
       .iter()
-      .filter(|p| p.default.is_none() && p.kind != ParameterKind::Star)
+      .filter(|parameter| parameter.is_required())
       .count()
   }
 
@@ -57,6 +65,42 @@ impl<'src, D> Recipe<'src, D> {
     }
   }
 
+  /// Group positional arguments, as passed to dependencies, by parameter
+  pub(crate) fn group_arguments(&self, arguments: Vec<String>) -> Vec<Vec<String>> {
+    let mut arguments = arguments.into_iter();
+
+    self
+      .parameters
+      .iter()
+      .map(|parameter| {
+        if parameter.kind.is_variadic() {
+          arguments.by_ref().collect()
+        } else {
+          arguments.next().into_iter().collect()
+        }
+      })
+      .collect()
+  }
+
+  /// Check grouped `arguments` against the constraints set on parameters with
+  /// `[arg]`
+  pub(crate) fn check_arguments(&self, arguments: &[Vec<String>]) -> RunResult<'src> {
+    for (parameter, arguments) in self.parameters.iter().zip(arguments) {
+      for argument in arguments {
+        if let Err(expected) = parameter.check(argument) {
+          return Err(Error::InvalidArgument {
+            recipe: self.name(),
+            parameter: parameter.name.lexeme(),
+            argument: argument.clone(),
+            expected,
+          });
+        }
+      }
+    }
+
+    Ok(())
+  }
+
   pub(crate) fn name(&self) -> &'src str {
     self.name.lexeme()
   }
@@ -66,9 +110,13 @@ impl<'src, D> Recipe<'src, D> {
   }
 
   pub(crate) fn confirm(&self) -> RunResult<'src, bool> {
+    static PROMPT: Mutex<()> = Mutex::new(());
+
     if let Some(Attribute::Confirm(ref prompt)) =
       self.attributes.get(AttributeDiscriminant::Confirm)
     {
+      // parallel dependencies may ask for confirmation at the same time
+      let _prompt = PROMPT.lock().unwrap_or_else(PoisonError::into_inner);
       if let Some(prompt) = prompt {
         eprint!("{} ", prompt.cooked);
       } else {
@@ -100,6 +148,81 @@ impl<'src, D> Recipe<'src, D> {
     !self.private && !self.attributes.contains(AttributeDiscriminant::Private)
   }
 
+  pub(crate) fn is_parallel(&self) -> bool {
+    self.attributes.contains(AttributeDiscriminant::Parallel)
+  }
+
+  /// The number of times to retry this recipe if it fails with a non-zero
+  /// exit code, and how long to wait before each retry
+  pub(crate) fn retry(&self) -> Option<(u32, Duration)> {
+    self.attributes.iter().find_map(|attribute| {
+      if let Attribute::Retry { backoff, retries } = attribute {
+        Some((
+          *retries,
+          backoff
+            .as_ref()
+            .map(|backoff| backoff.duration)
+            .unwrap_or_default(),
+        ))
+      } else {
+        None
+      }
+    })
+  }
+
+  pub(crate) fn timeout(&self, config: &Config) -> Option<Duration> {
+    self
+      .attributes
+      .iter()
+      .find_map(|attribute| {
+        if let Attribute::Timeout(timeout) = attribute {
+          Some(timeout.duration)
+        } else {
+          None
+        }
+      })
+      .or(config.timeout)
+  }
+
+  /// The time remaining before a recipe started at `started` times out, or an
+  /// error if it already has
+  fn remaining(
+    &self,
+    config: &Config,
+    line_number: Option<usize>,
+    started: Instant,
+  ) -> RunResult<'src, Option<Duration>> {
+    let Some(timeout) = self.timeout(config) else {
+      return Ok(None);
+    };
+
+    let elapsed = started.elapsed();
+
+    match timeout.checked_sub(elapsed) {
+      Some(remaining) if !remaining.is_zero() => Ok(Some(remaining)),
+      _ => Err(Error::Timeout {
+        recipe: self.name(),
+        line_number,
+        elapsed,
+        timeout,
+      }),
+    }
+  }
+
+  fn timed_out(
+    &self,
+    config: &Config,
+    line_number: Option<usize>,
+    started: Instant,
+  ) -> Error<'src> {
+    Error::Timeout {
+      recipe: self.name(),
+      line_number,
+      elapsed: started.elapsed(),
+      timeout: self.timeout(config).unwrap_or_default(),
+    }
+  }
+
   pub(crate) fn is_script(&self) -> bool {
     self.shebang
   }
@@ -157,6 +280,212 @@ impl<'src, D> Recipe<'src, D> {
     self.attributes.contains(AttributeDiscriminant::NoQuiet)
   }
 
+  fn modified(&self, path: &Path) -> RunResult<'src, Option<SystemTime>> {
+    match fs::metadata(path).and_then(|metadata| metadata.modified()) {
+      Ok(modified) => Ok(Some(modified)),
+      Err(io_error) if io_error.kind() == io::ErrorKind::NotFound => Ok(None),
+      Err(io_error) => Err(Error::ModificationTime {
+        recipe: self.name(),
+        path: path.into(),
+        io_error,
+      }),
+    }
+  }
+
+  fn is_cached(&self) -> bool {
+    self.attributes.contains(AttributeDiscriminant::Cache)
+  }
+
+  /// Directory that `[sources]` and `[outputs]` paths are relative to
+  fn file_directory(&self, context: &ExecutionContext) -> PathBuf {
+    self
+      .working_directory(context)
+      .unwrap_or_else(|| context.config.invocation_directory.clone())
+  }
+
+  fn sources(&self, directory: &Path) -> RunResult<'src, Vec<PathBuf>> {
+    let mut paths = Vec::new();
+
+    for attribute in &self.attributes {
+      if let Attribute::Sources(sources) = attribute {
+        for source in sources {
+          let pattern = if Path::new(&source.cooked).is_absolute() {
+            source.cooked.clone()
+          } else {
+            format!(
+              "{}/{}",
+              glob::Pattern::escape(&directory.to_string_lossy()),
+              source.cooked,
+            )
+          };
+
+          // source patterns are validated when the attribute is parsed, and the
+          // directory prefix is escaped, so this cannot fail
+          let matches = glob::glob(&pattern).map_err(|pattern_error| {
+            Error::internal(format!(
+              "invalid source pattern `{pattern}`: {pattern_error}"
+            ))
+          })?;
+
+          for path in matches {
+            paths.push(path.map_err(|glob_error| Error::ModificationTime {
+              recipe: self.name(),
+              path: glob_error.path().into(),
+              io_error: glob_error.into(),
+            })?);
+          }
+        }
+      }
+    }
+
+    Ok(paths)
+  }
+
+  /// A recipe is up to date if it has outputs, all of its outputs exist, and
+  /// none of its sources are newer than its oldest output.
+  fn is_up_to_date(&self, context: &ExecutionContext) -> RunResult<'src, bool> {
+    if context.config.force {
+      return Ok(false);
+    }
+
+    let directory = self.file_directory(context);
+
+    let mut oldest = None;
+
+    for attribute in &self.attributes {
+      if let Attribute::Outputs(outputs) = attribute {
+        for output in outputs {
+          let Some(modified) = self.modified(&directory.join(&output.cooked))? else {
+            return Ok(false);
+          };
+
+          oldest = Some(oldest.map_or(modified, |oldest: SystemTime| oldest.min(modified)));
+        }
+      }
+    }
+
+    let Some(oldest) = oldest else {
+      return Ok(false);
+    };
+
+    for path in self.sources(&directory)? {
+      if self
+        .modified(&path)?
+        .is_some_and(|modified| modified > oldest)
+      {
+        return Ok(false);
+      }
+    }
+
+    Ok(true)
+  }
+
+  /// Hash of everything that can change what a `[cache]` recipe does: the
+  /// source of the file it is defined in, its evaluated body, its arguments,
+  /// its environment, and its sources.
+  fn cache_hash<'run>(
+    &self,
+    context: &ExecutionContext<'src, 'run>,
+    scope: &Scope<'src, 'run>,
+    positional: &[String],
+    lines: &[String],
+  ) -> RunResult<'src, String> {
+    fn update<'a>(hasher: &mut blake3::Hasher, items: impl IntoIterator<Item = &'a [u8]>) {
+      let items = items.into_iter().collect::<Vec<&[u8]>>();
+      hasher.update(&items.len().to_le_bytes());
+      for item in items {
+        hasher.update(&item.len().to_le_bytes());
+        hasher.update(item);
+      }
+    }
+
+    let mut hasher = blake3::Hasher::new();
+
+    update(
+      &mut hasher,
+      iter::once(self.namepath.to_string().as_bytes()),
+    );
+
+    update(&mut hasher, iter::once(self.name.token.src.as_bytes()));
+
+    update(&mut hasher, lines.iter().map(String::as_bytes));
+
+    update(&mut hasher, positional.iter().map(String::as_bytes));
+
+    let mut command = Command::new("");
+
+    command.export(
+      &context.module.settings,
+      context.dotenv,
+      scope,
+      &context.module.unexports,
+      &context.working_directory(),
+    );
+
+    let environment = command
+      .get_envs()
+      .map(|(name, value)| {
+        format!(
+          "{}={}",
+          name.to_string_lossy(),
+          value.unwrap_or_default().to_string_lossy()
+        )
+      })
+      .collect::<BTreeSet<String>>();
+
+    update(&mut hasher, environment.iter().map(String::as_bytes));
+
+    let mut sources = Vec::new();
+
+    for path in self.sources(&self.file_directory(context))? {
+      let mut file = blake3::Hasher::new();
+      file
+        .update_mmap_rayon(&path)
+        .map_err(|io_error| Error::SourceRead {
+          recipe: self.name(),
+          path: path.clone(),
+          io_error,
+        })?;
+      sources.push((path, file.finalize()));
+    }
+
+    update(
+      &mut hasher,
+      sources
+        .iter()
+        .flat_map(|(path, hash)| [path.as_os_str().as_encoded_bytes(), hash.as_bytes()]),
+    );
+
+    Ok(hasher.finalize().to_string())
+  }
+
+  /// Evaluate the lines of the recipe body that will be run, in the same order
+  /// as they are evaluated when running the recipe.
+  fn evaluate_lines<'run>(
+    &self,
+    context: &ExecutionContext<'src, 'run>,
+    evaluator: &mut Evaluator<'src, 'run>,
+  ) -> RunResult<'src, Vec<String>> {
+    let mut evaluated = Vec::new();
+
+    if self.is_script() {
+      for line in &self.body {
+        evaluated.push(evaluator.evaluate_line(line, false)?);
+      }
+    } else {
+      let mut continued = false;
+      for line in &self.body {
+        if !continued && context.module.settings.ignore_comments && line.is_comment() {
+          continue;
+        }
+        evaluated.push(evaluator.evaluate_line(line, continued)?);
+        continued = line.is_continuation();
+      }
+    }
+
+    Ok(evaluated)
+  }
+
   pub(crate) fn run<'run>(
     &self,
     context: &ExecutionContext<'src, 'run>,
@@ -168,6 +497,31 @@ impl<'src, D> Recipe<'src, D> {
     let prefix = color.prefix();
     let suffix = color.suffix();
 
+    let mut evaluator = Evaluator::new(context, is_dependency, scope);
+
+    let mut cached = None;
+
+    let up_to_date = if self.is_up_to_date(context)? {
+      true
+    } else if self.is_cached() {
+      let lines = self.evaluate_lines(context, &mut evaluator)?;
+      let cache = Cache::new(&context.search.justfile)?;
+      let hash = self.cache_hash(context, scope, positional, &lines)?;
+      let up_to_date =
+        !context.config.force && cache.get(&self.namepath, positional)?.as_ref() == Some(&hash);
+      cached = Some((cache, hash, lines));
+      up_to_date
+    } else {
+      false
+    };
+
+    if up_to_date {
+      if context.config.explain || context.config.verbosity.loquacious() {
+        eprintln!("{prefix}===> Recipe `{}` is up to date{suffix}", self.name);
+      }
+      return Ok(());
+    }
+
     if context.config.verbosity.loquacious() {
       eprintln!("{prefix}===> Running recipe `{}`...{suffix}", self.name);
     }
@@ -178,13 +532,93 @@ impl<'src, D> Recipe<'src, D> {
       }
     }
 
-    let evaluator = Evaluator::new(context, is_dependency, scope);
+    let (cache, lines) = match cached {
+      Some((cache, hash, lines)) => (Some((cache, hash)), Some(lines)),
+      None => (None, None),
+    };
 
-    if self.is_script() {
-      self.run_script(context, scope, positional, evaluator)
-    } else {
-      self.run_linewise(context, scope, positional, evaluator)
+    let retry = self.retry();
+
+    // the timeout is a deadline for the whole recipe, including retries
+    let started = Instant::now();
+
+    let mut evaluator = Some(evaluator);
+
+    for attempt in 1.. {
+      let evaluator = evaluator
+        .take()
+        .unwrap_or_else(|| Evaluator::new(context, is_dependency, scope));
+
+      let result = if self.is_script() {
+        self.run_script(
+          context,
+          scope,
+          positional,
+          evaluator,
+          lines.clone(),
+          attempt,
+          started,
+        )
+      } else {
+        self.run_linewise(
+          context,
+          scope,
+          positional,
+          evaluator,
+          lines.clone(),
+          attempt,
+          started,
+        )
+      };
+
+      match (result, retry) {
+        (
+          Err(Error::Code {
+            line_number, code, ..
+          }),
+          Some((retries, backoff)),
+        ) if attempt <= retries => {
+          // don't wait past the deadline, so that the next attempt times out
+          let backoff = match self.remaining(context.config, line_number, started) {
+            Ok(Some(remaining)) => backoff.min(remaining),
+            Ok(None) => backoff,
+            Err(_) => Duration::ZERO,
+          };
+
+          if context.config.verbosity.loud() {
+            let line = line_number
+              .map(|line_number| format!(" on line {line_number}"))
+              .unwrap_or_default();
+            let delay = if backoff.is_zero() {
+              String::new()
+            } else {
+              format!(" in {}", humantime::format_duration(backoff))
+            };
+            eprintln!(
+              "{prefix}===> Recipe `{}` failed{line} with exit code {code}, retrying{delay} \
+              (attempt {} of {}){suffix}",
+              self.name,
+              attempt + 1,
+              retries + 1,
+            );
+          }
+
+          thread::sleep(backoff);
+        }
+        (result, _) => {
+          result?;
+          break;
+        }
+      }
+    }
+
+    if let Some((cache, hash)) = cache {
+      if !context.config.dry_run {
+        cache.insert(&self.namepath, positional, hash)?;
+      }
     }
+
+    Ok(())
   }
 
   fn run_linewise<'run>(
@@ -193,9 +627,14 @@ impl<'src, D> Recipe<'src, D> {
     scope: &Scope<'src, 'run>,
     positional: &[String],
     mut evaluator: Evaluator<'src, 'run>,
+    evaluated_lines: Option<Vec<String>>,
+    attempt: u32,
+    started: Instant,
   ) -> RunResult<'src, ()> {
     let config = &context.config;
 
+    let mut evaluated_lines = evaluated_lines.map(Vec::into_iter);
+
     let mut lines = self.body.iter().peekable();
     let mut line_number = self.line_number() + 1;
     loop {
@@ -217,7 +656,12 @@ impl<'src, D> Recipe<'src, D> {
         let line = lines.next().unwrap();
         line_number += 1;
         if !comment_line {
-          evaluated += &evaluator.evaluate_line(line, continued)?;
+          evaluated += &match &mut evaluated_lines {
+            Some(evaluated_lines) => evaluated_lines
+              .next()
+              .ok_or_else(|| Error::internal("ran out of evaluated lines"))?,
+            None => evaluator.evaluate_line(line, continued)?,
+          };
         }
         if line.is_continuation() && !comment_line {
           continued = true;
@@ -247,11 +691,12 @@ impl<'src, D> Recipe<'src, D> {
           || (context.module.settings.quiet && !self.no_quiet())
           || config.verbosity.quiet())
       {
-        let color = config
-          .highlight
-          .then(|| config.color.command(config.command_color))
-          .unwrap_or(config.color)
-          .stderr();
+        let color = if config.highlight {
+          config.color.command(config.command_color)
+        } else {
+          config.color
+        }
+        .stderr();
 
         if config.timestamp {
           eprint!(
@@ -294,9 +739,16 @@ impl<'src, D> Recipe<'src, D> {
         context.dotenv,
         scope,
         &context.module.unexports,
+        &context.working_directory(),
       );
 
-      match InterruptHandler::guard(|| cmd.status()) {
+      if self.retry().is_some() {
+        cmd.env(ATTEMPT_ENVIRONMENT_VARIABLE, attempt.to_string());
+      }
+
+      let timeout = self.remaining(config, Some(line_number), started)?;
+
+      match cmd.status_guard_timeout(timeout) {
         Ok(exit_status) => {
           if let Some(code) = exit_status.code() {
             if code != 0 && !infallible_line {
@@ -315,13 +767,16 @@ impl<'src, D> Recipe<'src, D> {
             ));
           }
         }
+        Err(io_error) if io_error.kind() == io::ErrorKind::TimedOut => {
+          return Err(self.timed_out(config, Some(line_number), started));
+        }
         Err(io_error) => {
           return Err(Error::Io {
             recipe: self.name(),
             io_error,
           });
         }
-      };
+      }
     }
   }
 
@@ -331,13 +786,16 @@ impl<'src, D> Recipe<'src, D> {
     scope: &Scope<'src, 'run>,
     positional: &[String],
     mut evaluator: Evaluator<'src, 'run>,
+    evaluated_lines: Option<Vec<String>>,
+    attempt: u32,
+    started: Instant,
   ) -> RunResult<'src, ()> {
     let config = &context.config;
 
-    let mut evaluated_lines = Vec::new();
-    for line in &self.body {
-      evaluated_lines.push(evaluator.evaluate_line(line, false)?);
-    }
+    let evaluated_lines = match evaluated_lines {
+      Some(evaluated_lines) => evaluated_lines,
+      None => self.evaluate_lines(context, &mut evaluator)?,
+    };
 
     if config.verbosity.loud() && (config.dry_run || self.quiet) {
       for line in &evaluated_lines {
@@ -435,10 +893,17 @@ impl<'src, D> Recipe<'src, D> {
       context.dotenv,
       scope,
       &context.module.unexports,
+      &context.working_directory(),
     );
 
+    if self.retry().is_some() {
+      command.env(ATTEMPT_ENVIRONMENT_VARIABLE, attempt.to_string());
+    }
+
+    let timeout = self.remaining(config, None, started)?;
+
     // run it!
-    match InterruptHandler::guard(|| command.status()) {
+    match command.status_guard_timeout(timeout) {
       Ok(exit_status) => exit_status.code().map_or_else(
         || Err(error_from_signal(self.name(), None, exit_status)),
         |code| {
@@ -454,6 +919,9 @@ impl<'src, D> Recipe<'src, D> {
           }
         },
       ),
+      Err(io_error) if io_error.kind() == io::ErrorKind::TimedOut => {
+        Err(self.timed_out(config, None, started))
+      }
       Err(io_error) => Err(executor.error(io_error, self.name())),
     }
   }
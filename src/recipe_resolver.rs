@@ -1,70 +1,160 @@
 use {super::*, CompileErrorKind::*};
 
 pub(crate) struct RecipeResolver<'src: 'run, 'run> {
-  unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
-  resolved_recipes: Table<'src, Rc<Recipe<'src>>>,
-  assignments: &'run Table<'src, Assignment<'src>>,
+  modules: BTreeMap<Vec<&'src str>, &'run UnresolvedModule<'src>>,
+  resolved_recipes: BTreeMap<Vec<&'src str>, Table<'src, Arc<Recipe<'src>>>>,
+  unresolved_recipes: BTreeMap<Vec<&'src str>, Table<'src, UnresolvedRecipe<'src>>>,
 }
 
 impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
+  /// Resolve the recipes of `root` and all of its submodules, returning them
+  /// grouped by module path. Dependencies may refer to recipes in other
+  /// modules, with `super` referring to the parent of the current module.
   pub(crate) fn resolve_recipes(
-    assignments: &'run Table<'src, Assignment<'src>>,
-    settings: &Settings,
-    unresolved_recipes: Table<'src, UnresolvedRecipe<'src>>,
-  ) -> CompileResult<'src, Table<'src, Rc<Recipe<'src>>>> {
+    root: &'run mut UnresolvedModule<'src>,
+  ) -> CompileResult<'src, BTreeMap<Vec<&'src str>, Table<'src, Arc<Recipe<'src>>>>> {
+    let mut unresolved_recipes = BTreeMap::new();
+    Self::take_recipes(root, &mut Vec::new(), &mut unresolved_recipes);
+
+    let mut modules = BTreeMap::new();
+    Self::index_modules(root, &mut Vec::new(), &mut modules);
+
     let mut resolver = Self {
-      resolved_recipes: Table::new(),
+      modules,
+      resolved_recipes: BTreeMap::new(),
       unresolved_recipes,
-      assignments,
     };
 
-    while let Some(unresolved) = resolver.unresolved_recipes.pop() {
-      resolver.resolve_recipe(&mut Vec::new(), unresolved)?;
+    let paths = resolver.modules.keys().cloned().collect::<Vec<Vec<&str>>>();
+
+    for path in &paths {
+      while let Some(unresolved) = resolver
+        .unresolved_recipes
+        .get_mut(path)
+        .and_then(Table::pop)
+      {
+        resolver.resolve_recipe(&mut Vec::new(), unresolved)?;
+      }
     }
 
-    for recipe in resolver.resolved_recipes.values() {
-      for (i, parameter) in recipe.parameters.iter().enumerate() {
-        if let Some(expression) = &parameter.default {
-          for variable in expression.variables() {
-            resolver.resolve_variable(&variable, &recipe.parameters[..i])?;
-          }
-        }
+    for recipes in resolver.resolved_recipes.values() {
+      for recipe in recipes.values() {
+        resolver.resolve_expressions(recipe)?;
       }
+    }
 
-      for dependency in &recipe.dependencies {
-        for argument in &dependency.arguments {
-          for variable in argument.variables() {
-            resolver.resolve_variable(&variable, &recipe.parameters)?;
-          }
-        }
+    Ok(resolver.resolved_recipes)
+  }
+
+  fn take_recipes(
+    module: &mut UnresolvedModule<'src>,
+    path: &mut Vec<&'src str>,
+    recipes: &mut BTreeMap<Vec<&'src str>, Table<'src, UnresolvedRecipe<'src>>>,
+  ) {
+    recipes.insert(path.clone(), mem::take(&mut module.recipes));
+
+    for (name, submodule) in module.modules.iter_mut() {
+      path.push(*name);
+      Self::take_recipes(submodule, path, recipes);
+      path.pop();
+    }
+  }
+
+  fn index_modules(
+    module: &'run UnresolvedModule<'src>,
+    path: &mut Vec<&'src str>,
+    modules: &mut BTreeMap<Vec<&'src str>, &'run UnresolvedModule<'src>>,
+  ) {
+    modules.insert(path.clone(), module);
+
+    for (name, submodule) in module.modules.iter() {
+      path.push(*name);
+      Self::index_modules(submodule, path, modules);
+      path.pop();
+    }
+  }
+
+  fn resolve_expressions(&self, recipe: &Recipe<'src>) -> CompileResult<'src> {
+    let module = self.modules[&recipe.namepath.module()];
+
+    for (i, parameter) in recipe.parameters.iter().enumerate() {
+      if let Some(expression) = &parameter.default {
+        Self::resolve_expression(module, expression, &recipe.parameters[..i], None)?;
       }
+    }
+
+    for dependency in &recipe.dependencies {
+      let mut expressions = dependency.arguments.iter().collect::<Vec<&Expression>>();
+
+      let mut bound = None;
+
+      if let Some(Iteration {
+        condition,
+        iterable,
+        name,
+      }) = &dependency.iteration
+      {
+        Self::resolve_expression(module, iterable, &recipe.parameters, None)?;
 
-      for line in &recipe.body {
-        if line.is_comment() && settings.ignore_comments {
-          continue;
+        if let Some(condition) = condition {
+          expressions.push(&condition.lhs);
+          expressions.push(&condition.rhs);
         }
 
-        for fragment in &line.fragments {
-          if let Fragment::Interpolation { expression, .. } = fragment {
-            for variable in expression.variables() {
-              resolver.resolve_variable(&variable, &recipe.parameters)?;
-            }
-          }
+        bound = Some(name.lexeme());
+      }
+
+      for expression in expressions {
+        Self::resolve_expression(module, expression, &recipe.parameters, bound)?;
+      }
+    }
+
+    for line in &recipe.body {
+      if line.is_comment() && module.settings.ignore_comments {
+        continue;
+      }
+
+      for fragment in &line.fragments {
+        if let Fragment::Interpolation { expression, .. } = fragment {
+          Self::resolve_expression(module, expression, &recipe.parameters, None)?;
         }
       }
     }
 
-    Ok(resolver.resolved_recipes)
+    Ok(())
+  }
+
+  /// Resolve the function calls and variables in `expression`, in which
+  /// `parameters` and the dependency iteration variable `bound` are in scope
+  fn resolve_expression(
+    module: &UnresolvedModule<'src>,
+    expression: &Expression<'src>,
+    parameters: &[Parameter],
+    bound: Option<&str>,
+  ) -> CompileResult<'src> {
+    FunctionResolver::resolve_calls(&module.functions, expression)?;
+
+    for variable in expression.variables() {
+      if bound != Some(variable.lexeme()) {
+        Self::resolve_variable(module, &variable, parameters)?;
+      }
+    }
+
+    for path in expression.qualified_variables() {
+      UnresolvedModule::resolve_variable(&module.modules, path)?;
+    }
+
+    Ok(())
   }
 
   fn resolve_variable(
-    &self,
+    module: &UnresolvedModule<'src>,
     variable: &Token<'src>,
     parameters: &[Parameter],
   ) -> CompileResult<'src> {
     let name = variable.lexeme();
 
-    let defined = self.assignments.contains_key(name)
+    let defined = module.assignments.contains_key(name)
       || parameters.iter().any(|p| p.name.lexeme() == name)
       || constants().contains_key(name);
 
@@ -75,53 +165,110 @@ impl<'src: 'run, 'run> RecipeResolver<'src, 'run> {
     Ok(())
   }
 
+  /// Find the module path and name of the recipe that `dependency` of
+  /// `recipe` refers to. Paths are relative to the module of `recipe`.
+  fn dependency_target(
+    &self,
+    recipe: &UnresolvedRecipe<'src>,
+    dependency: &UnresolvedDependency<'src>,
+  ) -> CompileResult<'src, (Vec<&'src str>, &'src str)> {
+    let mut path = recipe.namepath.module();
+
+    let (last, modules) = dependency.recipe.split_last();
+
+    for name in modules {
+      let found = if Keyword::Super == name.lexeme() {
+        path.pop().is_some()
+      } else {
+        path.push(name.lexeme());
+        self.modules.contains_key(&path)
+      };
+
+      if !found {
+        return Err(Self::unknown_dependency(recipe, dependency, *name));
+      }
+    }
+
+    Ok((path, last.lexeme()))
+  }
+
+  fn unknown_dependency(
+    recipe: &UnresolvedRecipe<'src>,
+    dependency: &UnresolvedDependency<'src>,
+    name: Name<'src>,
+  ) -> CompileError<'src> {
+    name.error(UnknownDependency {
+      recipe: recipe.name(),
+      unknown: dependency.recipe.to_string(),
+    })
+  }
+
   fn resolve_recipe(
     &mut self,
-    stack: &mut Vec<&'src str>,
+    stack: &mut Vec<Namepath<'src>>,
     recipe: UnresolvedRecipe<'src>,
-  ) -> CompileResult<'src, Rc<Recipe<'src>>> {
-    if let Some(resolved) = self.resolved_recipes.get(recipe.name()) {
-      return Ok(Rc::clone(resolved));
+  ) -> CompileResult<'src, Arc<Recipe<'src>>> {
+    let module = recipe.namepath.module();
+
+    if let Some(resolved) = self
+      .resolved_recipes
+      .get(&module)
+      .and_then(|recipes| recipes.get(recipe.name()))
+    {
+      return Ok(Arc::clone(resolved));
     }
 
-    stack.push(recipe.name());
+    stack.push(recipe.namepath.clone());
 
-    let mut dependencies: Vec<Rc<Recipe>> = Vec::new();
+    let mut dependencies: Vec<Arc<Recipe>> = Vec::new();
     for dependency in &recipe.dependencies {
-      let name = dependency.recipe.lexeme();
+      let (path, name) = self.dependency_target(&recipe, dependency)?;
 
-      if let Some(resolved) = self.resolved_recipes.get(name) {
+      if let Some(resolved) = self
+        .resolved_recipes
+        .get(&path)
+        .and_then(|recipes| recipes.get(name))
+      {
         // dependency already resolved
-        dependencies.push(Rc::clone(resolved));
-      } else if stack.contains(&name) {
-        let first = stack[0];
-        stack.push(first);
-        return Err(
-          dependency.recipe.error(CircularRecipeDependency {
-            recipe: recipe.name(),
-            circle: stack
-              .iter()
-              .skip_while(|name| **name != dependency.recipe.lexeme())
-              .copied()
-              .collect(),
-          }),
-        );
-      } else if let Some(unresolved) = self.unresolved_recipes.remove(name) {
+        dependencies.push(Arc::clone(resolved));
+      } else if let Some(start) = stack
+        .iter()
+        .position(|namepath| namepath.module() == path && namepath.last().lexeme() == name)
+      {
+        let mut circle = stack[start..]
+          .iter()
+          .map(ToString::to_string)
+          .collect::<Vec<String>>();
+        circle.push(stack[start].to_string());
+        return Err(dependency.recipe.last().error(CircularRecipeDependency {
+          recipe: recipe.name(),
+          circle,
+        }));
+      } else if let Some(unresolved) = self
+        .unresolved_recipes
+        .get_mut(&path)
+        .and_then(|recipes| recipes.remove(name))
+      {
         // resolve unresolved dependency
         dependencies.push(self.resolve_recipe(stack, unresolved)?);
       } else {
         // dependency is unknown
-        return Err(dependency.recipe.error(UnknownDependency {
-          recipe: recipe.name(),
-          unknown: name,
-        }));
+        return Err(Self::unknown_dependency(
+          &recipe,
+          dependency,
+          dependency.recipe.last(),
+        ));
       }
     }
 
     stack.pop();
 
-    let resolved = Rc::new(recipe.resolve(dependencies)?);
-    self.resolved_recipes.insert(Rc::clone(&resolved));
+    let resolved = Arc::new(recipe.resolve(dependencies)?);
+    self
+      .resolved_recipes
+      .entry(module)
+      .or_default()
+      .insert(Arc::clone(&resolved));
     Ok(resolved)
   }
 }
@@ -137,7 +284,7 @@ mod tests {
     line:   1,
     column: 3,
     width:  1,
-    kind:   CircularRecipeDependency{recipe: "b", circle: vec!["a", "b", "a"]},
+    kind:   CircularRecipeDependency{recipe: "b", circle: vec!["a".into(), "b".into(), "a".into()]},
   }
 
   analysis_error! {
@@ -147,7 +294,7 @@ mod tests {
     line:   0,
     column: 3,
     width:  1,
-    kind:   CircularRecipeDependency{recipe: "a", circle: vec!["a", "a"]},
+    kind:   CircularRecipeDependency{recipe: "a", circle: vec!["a".into(), "a".into()]},
   }
 
   analysis_error! {
@@ -157,7 +304,7 @@ mod tests {
     line:   0,
     column: 3,
     width:  1,
-    kind:   UnknownDependency{recipe: "a", unknown: "b"},
+    kind:   UnknownDependency{recipe: "a", unknown: "b".into()},
   }
 
   analysis_error! {
@@ -9,7 +9,7 @@ impl ColorDisplay for RecipeSignature<'_> {
   fn fmt(&self, f: &mut Formatter, color: Color) -> fmt::Result {
     write!(f, "{}", self.name)?;
     for parameter in &self.recipe.parameters {
-      write!(f, " {}", parameter.color_display(color))?;
+      write!(f, " {}", parameter.signature().color_display(color))?;
     }
     Ok(())
   }
@@ -0,0 +1,133 @@
+use super::*;
+
+/// Clap-style help for a recipe, as printed by `--usage`
+pub(crate) struct RecipeUsage<'a> {
+  pub(crate) recipe: &'a Recipe<'a>,
+}
+
+impl RecipeUsage<'_> {
+  fn placeholder(parameter: &Parameter) -> String {
+    let placeholder = if parameter.is_required() {
+      format!("<{}>", parameter.name)
+    } else {
+      format!("[{}]", parameter.name)
+    };
+
+    if parameter.kind.is_variadic() {
+      format!("{placeholder}...")
+    } else {
+      placeholder
+    }
+  }
+
+  fn option(parameter: &Parameter) -> String {
+    let option = match (parameter.short, &parameter.long) {
+      (Some(short), Some(long)) => format!("-{short}, --{long}"),
+      (Some(short), None) => format!("-{short}"),
+      (None, Some(long)) => format!("    --{long}"),
+      (None, None) => String::new(),
+    };
+
+    if parameter.is_flag() {
+      option
+    } else {
+      format!("{option} <{}>", parameter.name)
+    }
+  }
+
+  fn help(parameter: &Parameter) -> String {
+    parameter
+      .help
+      .iter()
+      .cloned()
+      .chain(
+        (!parameter.choices.is_empty())
+          .then(|| format!("[possible values: {}]", parameter.choices.join(", "))),
+      )
+      .chain(
+        parameter
+          .default
+          .iter()
+          .map(|default| format!("[default: {default}]")),
+      )
+      .collect::<Vec<String>>()
+      .join(" ")
+  }
+}
+
+impl ColorDisplay for RecipeUsage<'_> {
+  fn fmt(&self, f: &mut Formatter, color: Color) -> fmt::Result {
+    if let Some(doc) = self.recipe.doc() {
+      writeln!(f, "{}", color.doc().paint(doc))?;
+      writeln!(f)?;
+    }
+
+    let (options, arguments): (Vec<&Parameter>, Vec<&Parameter>) = self
+      .recipe
+      .parameters
+      .iter()
+      .partition(|parameter| parameter.is_option());
+
+    write!(
+      f,
+      "{} just {}",
+      color.message().paint("Usage:"),
+      self.recipe.namepath
+    )?;
+
+    if !options.is_empty() {
+      write!(f, " [OPTIONS]")?;
+    }
+
+    for parameter in &arguments {
+      write!(
+        f,
+        " {}",
+        color.parameter().paint(&Self::placeholder(parameter))
+      )?;
+    }
+
+    let arguments = arguments
+      .into_iter()
+      .map(|parameter| (Self::placeholder(parameter), Self::help(parameter)))
+      .collect::<Vec<(String, String)>>();
+
+    let options = options
+      .into_iter()
+      .map(|parameter| (Self::option(parameter), Self::help(parameter)))
+      .collect::<Vec<(String, String)>>();
+
+    let width = arguments
+      .iter()
+      .chain(&options)
+      .map(|(left, _help)| UnicodeWidthStr::width(left.as_str()))
+      .max()
+      .unwrap_or_default();
+
+    for (heading, rows) in [("Arguments:", arguments), ("Options:", options)] {
+      if rows.is_empty() {
+        continue;
+      }
+
+      writeln!(f)?;
+      writeln!(f)?;
+      write!(f, "{}", color.message().paint(heading))?;
+
+      for (left, help) in rows {
+        writeln!(f)?;
+        write!(f, "  {}", color.parameter().paint(&left))?;
+        if !help.is_empty() {
+          write!(
+            f,
+            "{:padding$}  {}",
+            "",
+            color.doc().paint(&help),
+            padding = width - UnicodeWidthStr::width(left.as_str()),
+          )?;
+        }
+      }
+    }
+
+    Ok(())
+  }
+}
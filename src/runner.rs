@@ -0,0 +1,43 @@
+use super::*;
+
+/// Runs the recipes it visits
+pub(crate) struct Runner;
+
+impl<'src> Visitor<'src> for Runner {
+  const CONCURRENT: bool = true;
+
+  fn confirm(
+    &self,
+    context: &ExecutionContext<'src, '_>,
+    recipe: &Recipe<'src>,
+  ) -> RunResult<'src> {
+    if !context.config.yes && !recipe.confirm()? {
+      return Err(Error::NotConfirmed {
+        recipe: recipe.name(),
+      });
+    }
+
+    Ok(())
+  }
+
+  fn skip(
+    &self,
+    _arguments: &[Vec<String>],
+    _context: &ExecutionContext<'src, '_>,
+    _recipe: &Recipe<'src>,
+    _reason: Reason<'src>,
+  ) -> RunResult<'src> {
+    Ok(())
+  }
+
+  fn visit(
+    &self,
+    context: &ExecutionContext<'src, '_>,
+    positional: &[String],
+    reason: Reason<'src>,
+    recipe: &Recipe<'src>,
+    scope: &Scope<'src, '_>,
+  ) -> RunResult<'src> {
+    recipe.run(context, scope, positional, reason.is_dependency())
+  }
+}
@@ -3,14 +3,20 @@ use super::*;
 #[derive(Debug)]
 pub(crate) struct Scope<'src: 'run, 'run> {
   parent: Option<&'run Self>,
-  bindings: Table<'src, Binding<'src, String>>,
+  bindings: Table<'src, Binding<'src, Value>>,
+  submodules: Submodules<'src>,
 }
 
+/// Bindings of submodule variables referred to by a module, keyed by the path
+/// of the submodule relative to that module
+pub(crate) type Submodules<'src> = BTreeMap<Vec<&'src str>, Table<'src, Binding<'src, Value>>>;
+
 impl<'src, 'run> Scope<'src, 'run> {
   pub(crate) fn child(&'run self) -> Self {
     Self {
       parent: Some(self),
       bindings: Table::new(),
+      submodules: BTreeMap::new(),
     }
   }
 
@@ -18,6 +24,7 @@ impl<'src, 'run> Scope<'src, 'run> {
     let mut root = Self {
       parent: None,
       bindings: Table::new(),
+      submodules: BTreeMap::new(),
     };
 
     for (key, value) in constants() {
@@ -44,23 +51,92 @@ impl<'src, 'run> Scope<'src, 'run> {
     root
   }
 
-  pub(crate) fn bind(&mut self, binding: Binding<'src>) {
+  pub(crate) fn bind(&mut self, binding: Binding<'src, Value>) {
     self.bindings.insert(binding);
   }
 
+  pub(crate) fn unbind(&mut self, name: &str) -> Option<Binding<'src, Value>> {
+    self.bindings.remove(name)
+  }
+
   pub(crate) fn bound(&self, name: &str) -> bool {
     self.bindings.contains_key(name)
   }
 
-  pub(crate) fn value(&self, name: &str) -> Option<&str> {
+  pub(crate) fn value(&self, name: &str) -> Option<&Value> {
     if let Some(binding) = self.bindings.get(name) {
-      Some(binding.value.as_ref())
+      Some(&binding.value)
     } else {
       self.parent?.value(name)
     }
   }
 
-  pub(crate) fn bindings(&self) -> impl Iterator<Item = &Binding<String>> {
+  /// The value of variable `name` in the submodule at `path`, if it has been
+  /// evaluated
+  pub(crate) fn submodule_value(&self, path: &[&'src str], name: &str) -> Option<&Value> {
+    self
+      .submodules
+      .get(path)?
+      .get(name)
+      .map(|binding| &binding.value)
+  }
+
+  /// The evaluated bindings of the submodule at `path`, along with those of its
+  /// own submodules
+  pub(crate) fn submodule(
+    &self,
+    path: &[&'src str],
+  ) -> (Table<'src, Binding<'src, Value>>, Submodules<'src>) {
+    let bindings = self.submodules.get(path).cloned().unwrap_or_default();
+
+    let submodules = self
+      .submodules
+      .iter()
+      .filter(|(subpath, _bindings)| subpath.len() > path.len() && subpath.starts_with(path))
+      .map(|(subpath, bindings)| (subpath[path.len()..].to_vec(), bindings.clone()))
+      .collect();
+
+    (bindings, submodules)
+  }
+
+  /// Record the evaluated `bindings` of the submodule at `path`, along with
+  /// those of its own submodules
+  pub(crate) fn bind_submodule(
+    &mut self,
+    path: &[&'src str],
+    bindings: Table<'src, Binding<'src, Value>>,
+    submodules: Submodules<'src>,
+  ) {
+    self.submodules.insert(path.to_vec(), bindings);
+
+    for (subpath, bindings) in submodules {
+      self
+        .submodules
+        .insert(path.iter().copied().chain(subpath).collect(), bindings);
+    }
+  }
+
+  /// Bind previously evaluated `bindings` and `submodules`, as returned by
+  /// `Scope::submodule`
+  pub(crate) fn extend(
+    &mut self,
+    bindings: Table<'src, Binding<'src, Value>>,
+    submodules: Submodules<'src>,
+  ) {
+    for (name, binding) in bindings {
+      if !self.bound(name) {
+        self.bind(binding);
+      }
+    }
+
+    self.submodules.extend(submodules);
+  }
+
+  pub(crate) fn into_bindings(self) -> (Table<'src, Binding<'src, Value>>, Submodules<'src>) {
+    (self.bindings, self.submodules)
+  }
+
+  pub(crate) fn bindings(&self) -> impl Iterator<Item = &Binding<'_, Value>> {
     self.bindings.values()
   }
 
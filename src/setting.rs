@@ -11,6 +11,8 @@ pub(crate) enum Setting<'src> {
   Export(bool),
   Fallback(bool),
   IgnoreComments(bool),
+  InheritSettings(bool),
+  Path(Vec<StringLiteral<'src>>),
   PositionalArguments(bool),
   Quiet(bool),
   ScriptInterpreter(Interpreter<'src>),
@@ -32,6 +34,7 @@ impl Display for Setting<'_> {
       | Self::Export(value)
       | Self::Fallback(value)
       | Self::IgnoreComments(value)
+      | Self::InheritSettings(value)
       | Self::PositionalArguments(value)
       | Self::Quiet(value)
       | Self::Unstable(value)
@@ -39,6 +42,16 @@ impl Display for Setting<'_> {
       Self::ScriptInterpreter(shell) | Self::Shell(shell) | Self::WindowsShell(shell) => {
         write!(f, "[{shell}]")
       }
+      Self::Path(directories) => {
+        write!(f, "[")?;
+        for (i, directory) in directories.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{directory}")?;
+        }
+        write!(f, "]")
+      }
       Self::DotenvFilename(value)
       | Self::DotenvPath(value)
       | Self::Tempdir(value)
@@ -5,7 +5,7 @@ pub(crate) const DEFAULT_SHELL_ARGS: &[&str] = &["-cu"];
 pub(crate) const WINDOWS_POWERSHELL_SHELL: &str = "powershell.exe";
 pub(crate) const WINDOWS_POWERSHELL_ARGS: &[&str] = &["-NoLogo", "-Command"];
 
-#[derive(Debug, PartialEq, Serialize, Default)]
+#[derive(Clone, Debug, PartialEq, Serialize, Default)]
 pub(crate) struct Settings<'src> {
   pub(crate) allow_duplicate_recipes: bool,
   pub(crate) allow_duplicate_variables: bool,
@@ -16,6 +16,8 @@ pub(crate) struct Settings<'src> {
   pub(crate) export: bool,
   pub(crate) fallback: bool,
   pub(crate) ignore_comments: bool,
+  pub(crate) inherit_settings: bool,
+  pub(crate) path: Vec<PathBuf>,
   pub(crate) positional_arguments: bool,
   pub(crate) quiet: bool,
   #[serde(skip)]
@@ -29,8 +31,8 @@ pub(crate) struct Settings<'src> {
 }
 
 impl<'src> Settings<'src> {
-  pub(crate) fn from_table(sets: Table<'src, Set<'src>>) -> Self {
-    let mut settings = Self::default();
+  pub(crate) fn from_table(inherited: Option<&Self>, sets: Table<'src, Set<'src>>) -> Self {
+    let mut settings = inherited.cloned().unwrap_or_default();
 
     for (_name, set) in sets {
       match set.value {
@@ -61,6 +63,15 @@ impl<'src> Settings<'src> {
         Setting::IgnoreComments(ignore_comments) => {
           settings.ignore_comments = ignore_comments;
         }
+        Setting::InheritSettings(inherit_settings) => {
+          settings.inherit_settings = inherit_settings;
+        }
+        Setting::Path(path) => {
+          settings.path = path
+            .into_iter()
+            .map(|directory| directory.cooked.into())
+            .collect();
+        }
         Setting::PositionalArguments(positional_arguments) => {
           settings.positional_arguments = positional_arguments;
         }
@@ -94,6 +105,14 @@ impl<'src> Settings<'src> {
     settings
   }
 
+  /// Whether any environment file settings are enabled
+  pub(crate) fn dotenv(&self) -> bool {
+    self.dotenv_load
+      || self.dotenv_filename.is_some()
+      || self.dotenv_path.is_some()
+      || self.dotenv_required
+  }
+
   pub(crate) fn shell_command(&self, config: &Config) -> Command {
     let (command, args) = self.shell(config);
 
@@ -9,6 +9,11 @@ fn backtick_re() -> &'static Regex {
 
 #[derive(PartialEq, Clone, Debug)]
 pub(crate) enum Subcommand {
+  ArgumentChoices {
+    path: ModulePath,
+  },
+  CacheClear,
+  CacheStatus,
   Changelog,
   Choose {
     overrides: BTreeMap<String, String>,
@@ -29,6 +34,9 @@ pub(crate) enum Subcommand {
     variable: Option<String>,
   },
   Format,
+  Graph {
+    path: ModulePath,
+  },
   Groups,
   Init,
   List {
@@ -46,6 +54,9 @@ pub(crate) enum Subcommand {
     path: ModulePath,
   },
   Summary,
+  Usage {
+    path: ModulePath,
+  },
   Variables,
 }
 
@@ -66,14 +77,18 @@ impl Subcommand {
 
     let search = Search::find(&config.search_config, &config.invocation_directory)?;
 
-    if let Edit = self {
-      return Self::edit(&search);
+    match self {
+      CacheClear => return Self::cache_clear(config, &search),
+      CacheStatus => return Self::cache_status(config, &search),
+      Edit => return Self::edit(&search),
+      _ => {}
     }
 
     let compilation = Self::compile(config, loader, &search)?;
     let justfile = &compilation.justfile;
 
     match self {
+      ArgumentChoices { path } => Self::argument_choices(justfile, path)?,
       Choose { overrides, chooser } => {
         Self::choose(config, justfile, &search, overrides, chooser.as_deref())?;
       }
@@ -82,19 +97,61 @@ impl Subcommand {
       }
       Dump => Self::dump(config, compilation)?,
       Format => Self::format(config, &search, compilation)?,
+      Graph { path } => Self::graph(config, justfile, path)?,
       Groups => Self::groups(config, justfile),
       List { path } => Self::list(config, justfile, path)?,
       Request { request } => Self::request(request)?,
       Run {
         arguments,
         overrides,
-      } => Self::run(config, loader, search, compilation, arguments, overrides)?,
+      } => {
+        if config.watch {
+          Self::watch(config, loader, &search, compilation, arguments, overrides)?;
+        } else {
+          Self::run(config, loader, search, compilation, arguments, overrides)?;
+        }
+      }
       Show { path } => Self::show(config, justfile, path)?,
       Summary => Self::summary(config, justfile),
+      Usage { path } => Self::usage(config, justfile, path)?,
       Variables => Self::variables(justfile),
-      Changelog | Completions { .. } | Edit | Init | Man => unreachable!(),
+      CacheClear | CacheStatus | Changelog | Completions { .. } | Edit | Init | Man => {
+        unreachable!()
+      }
+    }
+
+    Ok(())
+  }
+
+  fn graph<'src>(
+    config: &Config,
+    mut module: &Justfile<'src>,
+    path: &ModulePath,
+  ) -> RunResult<'src> {
+    let mut graph = None;
+
+    for (i, name) in path.path.iter().enumerate() {
+      if let Some(submodule) = module.modules.get(name) {
+        module = submodule;
+      } else if i + 1 == path.path.len() {
+        let recipe = module
+          .get_recipe(name)
+          .ok_or_else(|| Error::UnknownRecipe {
+            recipe: name.to_owned(),
+            suggestion: module.suggest_recipe(name),
+          })?;
+        graph = Some(Graph::recipe(recipe));
+      } else {
+        return Err(Error::UnknownSubmodule {
+          path: path.to_string(),
+        });
+      }
     }
 
+    let graph = graph.unwrap_or_else(|| Graph::module(module));
+
+    println!("{}", graph.render(config.graph_format));
+
     Ok(())
   }
 
@@ -162,6 +219,99 @@ impl Subcommand {
     }
   }
 
+  fn watch<'src>(
+    config: &Config,
+    loader: &'src Loader,
+    search: &Search,
+    compilation: Compilation<'src>,
+    arguments: &[String],
+    overrides: &BTreeMap<String, String>,
+  ) -> RunResult<'src> {
+    let color = config.color.stderr().banner();
+    let prefix = color.prefix();
+    let suffix = color.suffix();
+
+    let report = |error: &Error| {
+      if !config.verbosity.quiet() && error.print_message() {
+        eprintln!("{}", error.color_display(config.color.stderr()));
+      }
+    };
+
+    let mut watcher = Watcher::new(config, search)?;
+
+    let mut compilation = Some(compilation);
+
+    loop {
+      if let Some(compilation) = compilation.take() {
+        watcher.watch(compilation.srcs.keys().cloned().collect());
+
+        // `Some(true)` if the run was terminated because files changed, and
+        // `Some(false)` if it finished first
+        let outcome = Mutex::new(None);
+
+        // recipes run in their own process groups, so that terminating them
+        // also terminates any processes they started
+        InterruptHandler::isolate(|isolation| {
+          thread::scope(|scope| {
+            let watching = scope.spawn(|| {
+              while !watcher.changed() {}
+
+              let mut outcome = outcome.lock().unwrap_or_else(PoisonError::into_inner);
+
+              if outcome.is_none() {
+                isolation.terminate();
+                *outcome = Some(true);
+              }
+            });
+
+            let result = Self::run(
+              config,
+              loader,
+              search.clone(),
+              compilation,
+              arguments,
+              overrides,
+            );
+
+            let terminated = *outcome
+              .lock()
+              .unwrap_or_else(PoisonError::into_inner)
+              .get_or_insert(false);
+
+            if terminated {
+              // errors caused by terminating the run are not reported
+              if config.verbosity.loud() {
+                eprintln!("{prefix}===> Files changed, restarting{suffix}");
+              }
+            } else {
+              if let Err(error) = result {
+                report(&error);
+              }
+
+              if config.verbosity.loud() {
+                eprintln!("{prefix}===> Waiting for changes{suffix}");
+              }
+            }
+
+            if let Err(panic) = watching.join() {
+              std::panic::resume_unwind(panic);
+            }
+          });
+        });
+      } else {
+        while !watcher.changed() {}
+      }
+
+      compilation = match Self::compile(config, loader, search) {
+        Ok(compilation) => Some(compilation),
+        Err(error) => {
+          report(&error);
+          None
+        }
+      };
+    }
+  }
+
   fn compile<'src>(
     config: &Config,
     loader: &'src Loader,
@@ -180,6 +330,39 @@ impl Subcommand {
     Ok(compilation)
   }
 
+  fn cache_clear(config: &Config, search: &Search) -> RunResult<'static> {
+    let cache = Cache::new(&search.justfile)?;
+
+    cache.clear()?;
+
+    if config.verbosity.loud() {
+      eprintln!("Cleared cache directory `{}`", cache.directory().display());
+    }
+
+    Ok(())
+  }
+
+  fn cache_status(config: &Config, search: &Search) -> RunResult<'static> {
+    let cache = Cache::new(&search.justfile)?;
+
+    println!("Cache directory: {}", cache.directory().display());
+    println!("Cached recipes:");
+    for CacheEntry {
+      arguments,
+      hash,
+      recipe,
+    } in cache.entries()?
+    {
+      print!("{}{recipe}", config.list_prefix);
+      for argument in arguments {
+        print!(" {argument}");
+      }
+      println!(" {hash}");
+    }
+
+    Ok(())
+  }
+
   fn changelog() {
     write!(io::stdout(), "{}", include_str!("../CHANGELOG.md")).ok();
   }
@@ -572,7 +755,7 @@ impl Subcommand {
         } else {
           for group in submodule_groups {
             groups
-              .entry(Some(group.to_string()))
+              .entry(Some(group.clone()))
               .or_default()
               .push(submodule);
           }
@@ -755,6 +938,50 @@ impl Subcommand {
     }
   }
 
+  fn recipe_at<'src, 'run>(
+    mut module: &'run Justfile<'src>,
+    path: &ModulePath,
+  ) -> RunResult<'src, &'run Recipe<'src>> {
+    for name in &path.path[0..path.path.len() - 1] {
+      module = module
+        .modules
+        .get(name)
+        .ok_or_else(|| Error::UnknownSubmodule {
+          path: path.to_string(),
+        })?;
+    }
+
+    let name = path.path.last().unwrap();
+
+    module.get_recipe(name).ok_or_else(|| Error::UnknownRecipe {
+      recipe: name.to_owned(),
+      suggestion: module.suggest_recipe(name),
+    })
+  }
+
+  fn usage<'src>(config: &Config, module: &Justfile<'src>, path: &ModulePath) -> RunResult<'src> {
+    let recipe = Self::recipe_at(module, path)?;
+
+    println!(
+      "{}",
+      RecipeUsage { recipe }.color_display(config.color.stdout())
+    );
+
+    Ok(())
+  }
+
+  fn argument_choices<'src>(module: &Justfile<'src>, path: &ModulePath) -> RunResult<'src> {
+    let recipe = Self::recipe_at(module, path)?;
+
+    for parameter in &recipe.parameters {
+      if !parameter.is_option() {
+        println!("{}", parameter.choices.join(" "));
+      }
+    }
+
+    Ok(())
+  }
+
   fn variables(justfile: &Justfile) {
     for (i, (_, assignment)) in justfile
       .assignments
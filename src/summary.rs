@@ -19,9 +19,10 @@ use {
 
 mod full {
   pub(crate) use crate::{
-    assignment::Assignment, condition::Condition, conditional_operator::ConditionalOperator,
-    dependency::Dependency, expression::Expression, fragment::Fragment, justfile::Justfile,
-    line::Line, parameter::Parameter, parameter_kind::ParameterKind, recipe::Recipe, thunk::Thunk,
+    arithmetic_operator::ArithmeticOperator, assignment::Assignment, condition::Condition,
+    conditional_operator::ConditionalOperator, dependency::Dependency, expression::Expression,
+    fragment::Fragment, iteration::Iteration, justfile::Justfile, line::Line, parameter::Parameter,
+    parameter_kind::ParameterKind, recipe::Recipe, thunk::Thunk,
   };
 }
 
@@ -187,6 +188,11 @@ pub enum Expression {
     lhs: Box<Expression>,
     rhs: Box<Expression>,
   },
+  Arithmetic {
+    lhs: Box<Expression>,
+    operator: ArithmeticOperator,
+    rhs: Box<Expression>,
+  },
   Assert {
     condition: Condition,
     error: Box<Expression>,
@@ -198,6 +204,12 @@ pub enum Expression {
     name: String,
     arguments: Vec<Expression>,
   },
+  Comprehension {
+    element: Box<Expression>,
+    name: String,
+    iterable: Box<Expression>,
+    condition: Option<Condition>,
+  },
   Concatenation {
     lhs: Box<Expression>,
     rhs: Box<Expression>,
@@ -209,14 +221,32 @@ pub enum Expression {
     otherwise: Box<Expression>,
     operator: ConditionalOperator,
   },
+  Index {
+    lhs: Box<Expression>,
+    index: Box<Expression>,
+  },
+  Int {
+    contents: Box<Expression>,
+  },
   Join {
     lhs: Option<Box<Expression>>,
     rhs: Box<Expression>,
   },
+  List {
+    elements: Vec<Expression>,
+  },
+  Match {
+    subject: Box<Expression>,
+    arms: Vec<(String, Expression)>,
+    default: Box<Expression>,
+  },
   Or {
     lhs: Box<Expression>,
     rhs: Box<Expression>,
   },
+  Splat {
+    contents: Box<Expression>,
+  },
   String {
     text: String,
   },
@@ -233,6 +263,11 @@ impl Expression {
         lhs: Self::new(lhs).into(),
         rhs: Self::new(rhs).into(),
       },
+      Arithmetic { lhs, operator, rhs } => Self::Arithmetic {
+        lhs: Self::new(lhs).into(),
+        operator: ArithmeticOperator::new(*operator),
+        rhs: Self::new(rhs).into(),
+      },
       Assert {
         condition: full::Condition { lhs, rhs, operator },
         error,
@@ -252,7 +287,9 @@ impl Expression {
           name: name.lexeme().to_owned(),
           arguments: Vec::new(),
         },
-        full::Thunk::Unary { name, arg, .. } => Self::Call {
+        full::Thunk::Unary { name, arg, .. }
+        | full::Thunk::UnaryList { name, arg, .. }
+        | full::Thunk::UnaryToList { name, arg, .. } => Self::Call {
           name: name.lexeme().to_owned(),
           arguments: vec![Self::new(arg)],
         },
@@ -313,6 +350,21 @@ impl Expression {
           name: name.lexeme().to_owned(),
           arguments: vec![Self::new(a), Self::new(b), Self::new(c)],
         },
+        full::Thunk::User { name, args } => Self::Call {
+          name: name.lexeme().to_owned(),
+          arguments: args.iter().map(Self::new).collect(),
+        },
+      },
+      Comprehension {
+        condition,
+        element,
+        iterable,
+        name,
+      } => Self::Comprehension {
+        element: Self::new(element).into(),
+        name: name.lexeme().to_owned(),
+        iterable: Self::new(iterable).into(),
+        condition: condition.as_ref().map(Condition::new),
       },
       Concatenation { lhs, rhs } => Self::Concatenation {
         lhs: Self::new(lhs).into(),
@@ -329,15 +381,46 @@ impl Expression {
         rhs: Self::new(rhs).into(),
         then: Self::new(then).into(),
       },
-      Group { contents } => Self::new(contents),
+      FormatString { contents, .. } | Group { contents } => Self::new(contents),
+      Index { lhs, index } => Self::Index {
+        lhs: Self::new(lhs).into(),
+        index: Self::new(index).into(),
+      },
+      Int { contents } => Self::Int {
+        contents: Self::new(contents).into(),
+      },
+      Integer { token } => Self::String {
+        text: token.lexeme().to_owned(),
+      },
       Join { lhs, rhs } => Self::Join {
         lhs: lhs.as_ref().map(|lhs| Self::new(lhs).into()),
         rhs: Self::new(rhs).into(),
       },
+      List { elements } => Self::List {
+        elements: elements.iter().map(Self::new).collect(),
+      },
+      Match {
+        arms,
+        default,
+        subject,
+      } => Self::Match {
+        subject: Self::new(subject).into(),
+        arms: arms
+          .iter()
+          .map(|arm| (arm.pattern.cooked.clone(), Self::new(&arm.value)))
+          .collect(),
+        default: Self::new(default).into(),
+      },
       Or { lhs, rhs } => Self::Or {
         lhs: Self::new(lhs).into(),
         rhs: Self::new(rhs).into(),
       },
+      QualifiedVariable { path } => Self::Variable {
+        name: path.to_string(),
+      },
+      Splat { contents } => Self::Splat {
+        contents: Self::new(contents).into(),
+      },
       StringLiteral { string_literal } => Self::String {
         text: string_literal.cooked.clone(),
       },
@@ -348,6 +431,25 @@ impl Expression {
   }
 }
 
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
+pub enum ArithmeticOperator {
+  Addition,
+  Multiplication,
+  Remainder,
+  Subtraction,
+}
+
+impl ArithmeticOperator {
+  fn new(operator: full::ArithmeticOperator) -> Self {
+    match operator {
+      full::ArithmeticOperator::Addition => Self::Addition,
+      full::ArithmeticOperator::Multiplication => Self::Multiplication,
+      full::ArithmeticOperator::Remainder => Self::Remainder,
+      full::ArithmeticOperator::Subtraction => Self::Subtraction,
+    }
+  }
+}
+
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub struct Condition {
   lhs: Box<Expression>,
@@ -355,10 +457,24 @@ pub struct Condition {
   operator: ConditionalOperator,
 }
 
+impl Condition {
+  fn new(condition: &full::Condition) -> Self {
+    Self {
+      lhs: Expression::new(&condition.lhs).into(),
+      rhs: Expression::new(&condition.rhs).into(),
+      operator: ConditionalOperator::new(condition.operator),
+    }
+  }
+}
+
 #[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
 pub enum ConditionalOperator {
   Equality,
+  Greater,
+  GreaterEqual,
   Inequality,
+  Less,
+  LessEqual,
   RegexMatch,
   RegexMismatch,
 }
@@ -367,7 +483,11 @@ impl ConditionalOperator {
   fn new(operator: full::ConditionalOperator) -> Self {
     match operator {
       full::ConditionalOperator::Equality => Self::Equality,
+      full::ConditionalOperator::Greater => Self::Greater,
+      full::ConditionalOperator::GreaterEqual => Self::GreaterEqual,
       full::ConditionalOperator::Inequality => Self::Inequality,
+      full::ConditionalOperator::Less => Self::Less,
+      full::ConditionalOperator::LessEqual => Self::LessEqual,
       full::ConditionalOperator::RegexMatch => Self::RegexMatch,
       full::ConditionalOperator::RegexMismatch => Self::RegexMismatch,
     }
@@ -378,13 +498,32 @@ impl ConditionalOperator {
 pub struct Dependency {
   pub recipe: String,
   pub arguments: Vec<Expression>,
+  pub iteration: Option<Iteration>,
 }
 
 impl Dependency {
   fn new(dependency: &full::Dependency) -> Self {
     Self {
-      recipe: dependency.recipe.name().to_owned(),
+      recipe: dependency.path.to_string(),
       arguments: dependency.arguments.iter().map(Expression::new).collect(),
+      iteration: dependency.iteration.as_ref().map(Iteration::new),
+    }
+  }
+}
+
+#[derive(Eq, PartialEq, Hash, Ord, PartialOrd, Debug, Clone)]
+pub struct Iteration {
+  pub name: String,
+  pub iterable: Expression,
+  pub condition: Option<Condition>,
+}
+
+impl Iteration {
+  fn new(iteration: &full::Iteration) -> Self {
+    Self {
+      name: iteration.name.lexeme().to_owned(),
+      iterable: Expression::new(&iteration.iterable),
+      condition: iteration.condition.as_ref().map(Condition::new),
     }
   }
 }
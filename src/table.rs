@@ -1,6 +1,6 @@
 use {super::*, std::collections::btree_map};
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(transparent)]
 pub(crate) struct Table<'key, V: Keyed<'key>> {
   map: BTreeMap<&'key str, V>,
@@ -29,7 +29,7 @@ impl<'key, V: Keyed<'key>> Table<'key, V> {
     self.map.is_empty()
   }
 
-  pub(crate) fn values(&self) -> btree_map::Values<&'key str, V> {
+  pub(crate) fn values(&self) -> btree_map::Values<'_, &'key str, V> {
     self.map.values()
   }
 
@@ -37,14 +37,18 @@ impl<'key, V: Keyed<'key>> Table<'key, V> {
     self.map.contains_key(key)
   }
 
-  pub(crate) fn keys(&self) -> btree_map::Keys<&'key str, V> {
+  pub(crate) fn keys(&self) -> btree_map::Keys<'_, &'key str, V> {
     self.map.keys()
   }
 
-  pub(crate) fn iter(&self) -> btree_map::Iter<&'key str, V> {
+  pub(crate) fn iter(&self) -> btree_map::Iter<'_, &'key str, V> {
     self.map.iter()
   }
 
+  pub(crate) fn iter_mut(&mut self) -> btree_map::IterMut<'_, &'key str, V> {
+    self.map.iter_mut()
+  }
+
   pub(crate) fn pop(&mut self) -> Option<V> {
     let key = self.map.keys().next().copied()?;
     self.map.remove(key)
@@ -1,6 +1,6 @@
 use {super::*, pretty_assertions::assert_eq};
 
-pub(crate) fn compile(src: &str) -> Justfile {
+pub(crate) fn compile(src: &str) -> Justfile<'_> {
   Compiler::test_compile(src).expect("expected successful compilation")
 }
 
@@ -63,8 +63,8 @@ pub(crate) fn analysis_error(
     .expect("Parsing failed in analysis test...");
 
   let root = PathBuf::from("justfile");
-  let mut asts: HashMap<PathBuf, Ast> = HashMap::new();
-  asts.insert(root.clone(), ast);
+  let mut asts: HashMap<(Vec<&str>, PathBuf), Ast> = HashMap::new();
+  asts.insert((Vec::new(), root.clone()), ast);
 
   let mut paths: HashMap<PathBuf, PathBuf> = HashMap::new();
   paths.insert("justfile".into(), "justfile".into());
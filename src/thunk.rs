@@ -14,6 +14,18 @@ pub(crate) enum Thunk<'src> {
     function: fn(function::Context, &str) -> FunctionResult,
     arg: Box<Expression<'src>>,
   },
+  UnaryList {
+    name: Name<'src>,
+    #[derive_where(skip(Debug, EqHashOrd))]
+    function: fn(function::Context, &[String]) -> FunctionResult,
+    arg: Box<Expression<'src>>,
+  },
+  UnaryToList {
+    name: Name<'src>,
+    #[derive_where(skip(Debug, EqHashOrd))]
+    function: fn(function::Context, &str) -> ListResult,
+    arg: Box<Expression<'src>>,
+  },
   UnaryOpt {
     name: Name<'src>,
     #[derive_where(skip(Debug, EqHashOrd))]
@@ -44,6 +56,10 @@ pub(crate) enum Thunk<'src> {
     function: fn(function::Context, &str, &str, &str) -> FunctionResult,
     args: [Box<Expression<'src>>; 3],
   },
+  User {
+    name: Name<'src>,
+    args: Vec<Expression<'src>>,
+  },
 }
 
 impl<'src> Thunk<'src> {
@@ -51,11 +67,14 @@ impl<'src> Thunk<'src> {
     match self {
       Self::Nullary { name, .. }
       | Self::Unary { name, .. }
+      | Self::UnaryList { name, .. }
+      | Self::UnaryToList { name, .. }
       | Self::UnaryOpt { name, .. }
       | Self::UnaryPlus { name, .. }
       | Self::Binary { name, .. }
       | Self::BinaryPlus { name, .. }
-      | Self::Ternary { name, .. } => *name,
+      | Self::Ternary { name, .. }
+      | Self::User { name, .. } => *name,
     }
   }
 
@@ -63,74 +82,86 @@ impl<'src> Thunk<'src> {
     name: Name<'src>,
     mut arguments: Vec<Expression<'src>>,
   ) -> CompileResult<'src, Thunk<'src>> {
-    function::get(name.lexeme()).map_or(
-      Err(name.error(CompileErrorKind::UnknownFunction {
-        function: name.lexeme(),
-      })),
-      |function| match (function, arguments.len()) {
-        (Function::Nullary(function), 0) => Ok(Thunk::Nullary { function, name }),
-        (Function::Unary(function), 1) => Ok(Thunk::Unary {
+    let Some(function) = function::get(name.lexeme()) else {
+      return Ok(Thunk::User {
+        name,
+        args: arguments,
+      });
+    };
+
+    match (function, arguments.len()) {
+      (Function::Nullary(function), 0) => Ok(Thunk::Nullary { function, name }),
+      (Function::Unary(function), 1) => Ok(Thunk::Unary {
+        function,
+        arg: arguments.pop().unwrap().into(),
+        name,
+      }),
+      (Function::UnaryList(function), 1) => Ok(Thunk::UnaryList {
+        function,
+        arg: arguments.pop().unwrap().into(),
+        name,
+      }),
+      (Function::UnaryToList(function), 1) => Ok(Thunk::UnaryToList {
+        function,
+        arg: arguments.pop().unwrap().into(),
+        name,
+      }),
+      (Function::UnaryOpt(function), 1..=2) => {
+        let a = arguments.remove(0).into();
+        let b = match arguments.pop() {
+          Some(value) => Some(value).into(),
+          None => None.into(),
+        };
+        Ok(Thunk::UnaryOpt {
           function,
-          arg: arguments.pop().unwrap().into(),
+          args: (a, b),
           name,
-        }),
-        (Function::UnaryOpt(function), 1..=2) => {
-          let a = arguments.remove(0).into();
-          let b = match arguments.pop() {
-            Some(value) => Some(value).into(),
-            None => None.into(),
-          };
-          Ok(Thunk::UnaryOpt {
-            function,
-            args: (a, b),
-            name,
-          })
-        }
-        (Function::UnaryPlus(function), 1..=usize::MAX) => {
-          let rest = arguments.drain(1..).collect();
-          let a = Box::new(arguments.pop().unwrap());
-          Ok(Thunk::UnaryPlus {
-            function,
-            args: (a, rest),
-            name,
-          })
-        }
-        (Function::Binary(function), 2) => {
-          let b = arguments.pop().unwrap().into();
-          let a = arguments.pop().unwrap().into();
-          Ok(Thunk::Binary {
-            function,
-            args: [a, b],
-            name,
-          })
-        }
-        (Function::BinaryPlus(function), 2..=usize::MAX) => {
-          let rest = arguments.drain(2..).collect();
-          let b = arguments.pop().unwrap().into();
-          let a = arguments.pop().unwrap().into();
-          Ok(Thunk::BinaryPlus {
-            function,
-            args: ([a, b], rest),
-            name,
-          })
-        }
-        (Function::Ternary(function), 3) => {
-          let c = arguments.pop().unwrap().into();
-          let b = arguments.pop().unwrap().into();
-          let a = arguments.pop().unwrap().into();
-          Ok(Thunk::Ternary {
-            function,
-            args: [a, b, c],
-            name,
-          })
-        }
-        (function, _) => Err(name.error(CompileErrorKind::FunctionArgumentCountMismatch {
-          function: name.lexeme(),
-          found: arguments.len(),
-          expected: function.argc(),
-        })),
-      },
-    )
+        })
+      }
+      (Function::UnaryPlus(function), 1..=usize::MAX) => {
+        let rest = arguments.drain(1..).collect();
+        let a = Box::new(arguments.pop().unwrap());
+        Ok(Thunk::UnaryPlus {
+          function,
+          args: (a, rest),
+          name,
+        })
+      }
+      (Function::Binary(function), 2) => {
+        let b = arguments.pop().unwrap().into();
+        let a = arguments.pop().unwrap().into();
+        Ok(Thunk::Binary {
+          function,
+          args: [a, b],
+          name,
+        })
+      }
+      (Function::BinaryPlus(function), 2..=usize::MAX) => {
+        let rest = arguments.drain(2..).collect();
+        let b = arguments.pop().unwrap().into();
+        let a = arguments.pop().unwrap().into();
+        Ok(Thunk::BinaryPlus {
+          function,
+          args: ([a, b], rest),
+          name,
+        })
+      }
+      (Function::Ternary(function), 3) => {
+        let c = arguments.pop().unwrap().into();
+        let b = arguments.pop().unwrap().into();
+        let a = arguments.pop().unwrap().into();
+        Ok(Thunk::Ternary {
+          function,
+          args: [a, b, c],
+          name,
+        })
+      }
+      (function, _) => Err(name.error(CompileErrorKind::FunctionArgumentCountMismatch {
+        function: name.lexeme(),
+        found: arguments.len(),
+        expected: function.argc(),
+      })),
+    }
   }
 }
 
@@ -139,7 +170,9 @@ impl Display for Thunk<'_> {
     use Thunk::*;
     match self {
       Nullary { name, .. } => write!(f, "{}()", name.lexeme()),
-      Unary { name, arg, .. } => write!(f, "{}({arg})", name.lexeme()),
+      Unary { name, arg, .. } | UnaryList { name, arg, .. } | UnaryToList { name, arg, .. } => {
+        write!(f, "{}({arg})", name.lexeme())
+      }
       UnaryOpt {
         name, args: (a, b), ..
       } => {
@@ -179,6 +212,16 @@ impl Display for Thunk<'_> {
         args: [a, b, c],
         ..
       } => write!(f, "{}({a}, {b}, {c})", name.lexeme()),
+      User { name, args } => {
+        write!(f, "{}(", name.lexeme())?;
+        for (i, arg) in args.iter().enumerate() {
+          if i > 0 {
+            write!(f, ", ")?;
+          }
+          write!(f, "{arg}")?;
+        }
+        write!(f, ")")
+      }
     }
   }
 }
@@ -193,7 +236,9 @@ impl Serialize for Thunk<'_> {
     seq.serialize_element(&self.name())?;
     match self {
       Self::Nullary { .. } => {}
-      Self::Unary { arg, .. } => seq.serialize_element(&arg)?,
+      Self::Unary { arg, .. } | Self::UnaryList { arg, .. } | Self::UnaryToList { arg, .. } => {
+        seq.serialize_element(&arg)?;
+      }
       Self::UnaryOpt {
         args: (a, opt_b), ..
       } => {
@@ -225,6 +270,11 @@ impl Serialize for Thunk<'_> {
           seq.serialize_element(arg)?;
         }
       }
+      Self::User { args, .. } => {
+        for arg in args {
+          seq.serialize_element(arg)?;
+        }
+      }
     }
     seq.end()
   }
@@ -15,6 +15,7 @@ pub(crate) enum TokenKind {
   BracketR,
   ByteOrderMark,
   Colon,
+  ColonColon,
   ColonEquals,
   Comma,
   Comment,
@@ -24,13 +25,23 @@ pub(crate) enum TokenKind {
   Eol,
   Equals,
   EqualsEquals,
+  EqualsGreater,
   EqualsTilde,
+  FormatStringEnd,
+  FormatStringStart,
+  Greater,
+  GreaterEquals,
   Identifier,
   Indent,
+  Integer,
   InterpolationEnd,
   InterpolationStart,
+  Less,
+  LessEquals,
+  Minus,
   ParenL,
   ParenR,
+  Percent,
   Plus,
   QuestionMark,
   Slash,
@@ -60,6 +71,7 @@ impl Display for TokenKind {
         BracketR => "']'",
         ByteOrderMark => "byte order mark",
         Colon => "':'",
+        ColonColon => "'::'",
         ColonEquals => "':='",
         Comma => "','",
         Comment => "comment",
@@ -69,13 +81,23 @@ impl Display for TokenKind {
         Eol => "end of line",
         Equals => "'='",
         EqualsEquals => "'=='",
+        EqualsGreater => "'=>'",
         EqualsTilde => "'=~'",
+        FormatStringEnd => "format string end",
+        FormatStringStart => "format string",
+        Greater => "'>'",
+        GreaterEquals => "'>='",
         Identifier => "identifier",
         Indent => "indent",
+        Integer => "integer",
         InterpolationEnd => "'}}'",
         InterpolationStart => "'{{'",
+        Less => "'<'",
+        LessEquals => "'<='",
+        Minus => "'-'",
         ParenL => "'('",
         ParenR => "')'",
+        Percent => "'%'",
         Plus => "'+'",
         QuestionMark => "?",
         Slash => "'/'",
@@ -12,6 +12,12 @@ macro_rules! tree {
     $crate::tree::Tree::atom(stringify!($atom))
   };
 
+  // must precede `$atom:literal`, which would otherwise try to parse `-` as
+  // the start of a negative literal
+  { - } => {
+    $crate::tree::Tree::atom("-")
+  };
+
   { $atom:literal } => {
     $crate::tree::Tree::atom(format!("\"{}\"", $atom))
   };
@@ -24,6 +30,10 @@ macro_rules! tree {
     $crate::tree::Tree::atom("?")
   };
 
+  { _ } => {
+    $crate::tree::Tree::atom("_")
+  };
+
   { + } => {
     $crate::tree::Tree::atom("+")
   };
@@ -32,6 +42,10 @@ macro_rules! tree {
     $crate::tree::Tree::atom("*")
   };
 
+  { % } => {
+    $crate::tree::Tree::atom("%")
+  };
+
   { && } => {
     $crate::tree::Tree::atom("&&")
   };
@@ -43,6 +57,22 @@ macro_rules! tree {
   { != } => {
     $crate::tree::Tree::atom("!=")
   };
+
+  { < } => {
+    $crate::tree::Tree::atom("<")
+  };
+
+  { <= } => {
+    $crate::tree::Tree::atom("<=")
+  };
+
+  { > } => {
+    $crate::tree::Tree::atom(">")
+  };
+
+  { >= } => {
+    $crate::tree::Tree::atom(">=")
+  };
 }
 
 /// A `Tree` is either…
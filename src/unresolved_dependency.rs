@@ -2,8 +2,9 @@ use super::*;
 
 #[derive(PartialEq, Debug, Clone)]
 pub(crate) struct UnresolvedDependency<'src> {
-  pub(crate) recipe: Name<'src>,
+  pub(crate) recipe: Namepath<'src>,
   pub(crate) arguments: Vec<Expression<'src>>,
+  pub(crate) iteration: Option<Iteration<'src>>,
 }
 
 impl Display for UnresolvedDependency<'_> {
@@ -17,6 +18,10 @@ impl Display for UnresolvedDependency<'_> {
         write!(f, " {argument}")?;
       }
 
+      if let Some(iteration) = &self.iteration {
+        write!(f, " {iteration}")?;
+      }
+
       write!(f, ")")
     }
   }
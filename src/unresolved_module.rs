@@ -0,0 +1,151 @@
+use {super::*, CompileErrorKind::*};
+
+/// A module whose items have been analyzed, but whose recipe dependencies
+/// have not yet been resolved. Dependencies may refer to recipes in other
+/// modules, so recipes are resolved once the whole module tree is available.
+pub(crate) struct UnresolvedModule<'src> {
+  pub(crate) aliases: Table<'src, Alias<'src, Name<'src>>>,
+  pub(crate) assignments: Table<'src, Assignment<'src>>,
+  pub(crate) doc: Option<String>,
+  pub(crate) functions: Table<'src, UserFunction<'src>>,
+  pub(crate) groups: Vec<String>,
+  pub(crate) loaded: Vec<PathBuf>,
+  pub(crate) modules: Table<'src, UnresolvedModule<'src>>,
+  pub(crate) name: Option<Name<'src>>,
+  pub(crate) path: PathBuf,
+  pub(crate) recipes: Table<'src, UnresolvedRecipe<'src>>,
+  pub(crate) settings: Settings<'src>,
+  pub(crate) source: PathBuf,
+  pub(crate) unexports: HashSet<String>,
+  pub(crate) unstable_features: BTreeSet<UnstableFeature>,
+  pub(crate) warnings: Vec<Warning>,
+  pub(crate) working_directory: PathBuf,
+}
+
+impl<'src> UnresolvedModule<'src> {
+  pub(crate) fn resolve(
+    self,
+    recipes: &mut BTreeMap<Vec<&'src str>, Table<'src, Arc<Recipe<'src>>>>,
+    path: &mut Vec<&'src str>,
+  ) -> CompileResult<'src, Justfile<'src>> {
+    let mut modules = Table::new();
+    for (_name, module) in self.modules {
+      path.push(module.name());
+      modules.insert(module.resolve(recipes, path)?);
+      path.pop();
+    }
+
+    let recipes = recipes.remove(path.as_slice()).unwrap_or_default();
+
+    let mut unresolved_aliases = self.aliases;
+    let mut aliases = Table::new();
+    while let Some(alias) = unresolved_aliases.pop() {
+      aliases.insert(Self::resolve_alias(&recipes, alias)?);
+    }
+
+    let mut unstable_features = self.unstable_features;
+
+    for recipe in recipes.values() {
+      if recipe.attributes.contains(AttributeDiscriminant::Script) {
+        unstable_features.insert(UnstableFeature::ScriptAttribute);
+        break;
+      }
+    }
+
+    if self.settings.script_interpreter.is_some() {
+      unstable_features.insert(UnstableFeature::ScriptInterpreterSetting);
+    }
+
+    Ok(Justfile {
+      aliases,
+      assignments: self.assignments,
+      default: recipes
+        .values()
+        .filter(|recipe| recipe.name.path == self.path)
+        .fold(None, |accumulator, next| match accumulator {
+          None => Some(Arc::clone(next)),
+          Some(previous) => Some(if previous.line_number() < next.line_number() {
+            previous
+          } else {
+            Arc::clone(next)
+          }),
+        }),
+      doc: self.doc,
+      dotenv: OnceLock::new(),
+      functions: self.functions,
+      groups: self.groups,
+      loaded: self.loaded,
+      modules,
+      name: self.name,
+      recipes,
+      settings: self.settings,
+      source: self.source,
+      unexports: self.unexports,
+      unstable_features,
+      warnings: self.warnings,
+      working_directory: self.working_directory,
+    })
+  }
+
+  /// Check that `path`, a reference to a variable in a submodule, refers to
+  /// a variable defined in one of `modules`
+  pub(crate) fn resolve_variable(
+    modules: &Table<'src, UnresolvedModule<'src>>,
+    path: &Namepath<'src>,
+  ) -> CompileResult<'src> {
+    let (name, names) = path.split_last();
+
+    let mut modules = modules;
+    let mut assignments = None;
+
+    for (i, module) in names.iter().enumerate() {
+      let Some(submodule) = modules.get(module.lexeme()) else {
+        return Err(
+          module.error(UnknownVariableModule {
+            module: names[..=i]
+              .iter()
+              .map(|name| name.lexeme())
+              .collect::<Vec<&str>>()
+              .join("::"),
+            variable: path.to_string(),
+          }),
+        );
+      };
+
+      modules = &submodule.modules;
+      assignments = Some(&submodule.assignments);
+    }
+
+    if !assignments.is_some_and(|assignments| assignments.contains_key(name.lexeme())) {
+      return Err(name.error(UndefinedQualifiedVariable {
+        variable: path.to_string(),
+      }));
+    }
+
+    Ok(())
+  }
+
+  fn name(&self) -> &'src str {
+    self.name.map(|name| name.lexeme()).unwrap_or_default()
+  }
+
+  fn resolve_alias(
+    recipes: &Table<'src, Arc<Recipe<'src>>>,
+    alias: Alias<'src, Name<'src>>,
+  ) -> CompileResult<'src, Alias<'src>> {
+    // Make sure the target recipe exists
+    match recipes.get(alias.target.lexeme()) {
+      Some(target) => Ok(alias.resolve(Arc::clone(target))),
+      None => Err(alias.name.token.error(UnknownAliasTarget {
+        alias: alias.name.lexeme(),
+        target: alias.target.lexeme(),
+      })),
+    }
+  }
+}
+
+impl<'src> Keyed<'src> for UnresolvedModule<'src> {
+  fn key(&self) -> &'src str {
+    self.name()
+  }
+}
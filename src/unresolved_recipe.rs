@@ -5,7 +5,7 @@ pub(crate) type UnresolvedRecipe<'src> = Recipe<'src, UnresolvedDependency<'src>
 impl<'src> UnresolvedRecipe<'src> {
   pub(crate) fn resolve(
     self,
-    resolved: Vec<Rc<Recipe<'src>>>,
+    resolved: Vec<Arc<Recipe<'src>>>,
   ) -> CompileResult<'src, Recipe<'src>> {
     assert_eq!(
       self.dependencies.len(),
@@ -16,21 +16,32 @@ impl<'src> UnresolvedRecipe<'src> {
     );
 
     for (unresolved, resolved) in self.dependencies.iter().zip(&resolved) {
-      assert_eq!(unresolved.recipe.lexeme(), resolved.name.lexeme());
-      if !resolved
-        .argument_range()
-        .contains(&unresolved.arguments.len())
-      {
-        return Err(
-          unresolved
-            .recipe
-            .error(CompileErrorKind::DependencyArgumentCountMismatch {
-              dependency: unresolved.recipe.lexeme(),
-              found: unresolved.arguments.len(),
-              min: resolved.min_arguments(),
-              max: resolved.max_arguments(),
-            }),
-        );
+      assert_eq!(unresolved.recipe.last().lexeme(), resolved.name.lexeme());
+
+      let splats = unresolved
+        .arguments
+        .iter()
+        .filter(|argument| matches!(argument, Expression::Splat { .. }))
+        .count();
+
+      let found = unresolved.arguments.len() - splats;
+
+      // splatted arguments are checked when they are evaluated
+      let valid = if splats > 0 {
+        found <= resolved.max_arguments()
+      } else {
+        resolved.argument_range().contains(&found)
+      };
+
+      if !valid {
+        return Err(unresolved.recipe.last().error(
+          CompileErrorKind::DependencyArgumentCountMismatch {
+            dependency: unresolved.recipe.last().lexeme(),
+            found,
+            min: resolved.min_arguments(),
+            max: resolved.max_arguments(),
+          },
+        ));
       }
     }
 
@@ -39,8 +50,10 @@ impl<'src> UnresolvedRecipe<'src> {
       .into_iter()
       .zip(resolved)
       .map(|(unresolved, resolved)| Dependency {
-        recipe: resolved,
         arguments: unresolved.arguments,
+        iteration: unresolved.iteration,
+        path: unresolved.recipe,
+        recipe: resolved,
       })
       .collect();
 
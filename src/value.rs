@@ -0,0 +1,96 @@
+use super::*;
+
+/// The result of evaluating an expression. Lists are displayed as their
+/// elements joined with spaces, so that they can be used anywhere a string is
+/// expected.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+  List(Vec<String>),
+  String(String),
+}
+
+impl Value {
+  pub(crate) fn is_empty(&self) -> bool {
+    match self {
+      Self::List(list) => list.is_empty(),
+      Self::String(string) => string.is_empty(),
+    }
+  }
+
+  pub(crate) fn into_list(self) -> Result<Vec<String>, String> {
+    match self {
+      Self::List(list) => Ok(list),
+      Self::String(string) => Err(string),
+    }
+  }
+
+  pub(crate) fn into_string(self) -> String {
+    match self {
+      Self::List(list) => list.join(" "),
+      Self::String(string) => string,
+    }
+  }
+
+  /// Format as a literal, with strings quoted and lists bracketed. List
+  /// elements are escaped, so that the literal can be parsed back.
+  pub(crate) fn literal(&self) -> String {
+    match self {
+      Self::List(list) => format!(
+        "[{}]",
+        list
+          .iter()
+          .map(|element| Self::escape(element))
+          .collect::<Vec<String>>()
+          .join(", "),
+      ),
+      Self::String(string) => format!("\"{string}\""),
+    }
+  }
+
+  /// Format `string` as a double-quoted string literal
+  fn escape(string: &str) -> String {
+    let mut escaped = String::from('"');
+
+    for c in string.chars() {
+      match c {
+        '"' => escaped.push_str("\\\""),
+        '\\' => escaped.push_str("\\\\"),
+        '\n' => escaped.push_str("\\n"),
+        '\r' => escaped.push_str("\\r"),
+        '\t' => escaped.push_str("\\t"),
+        _ => escaped.push(c),
+      }
+    }
+
+    escaped.push('"');
+
+    escaped
+  }
+}
+
+impl Default for Value {
+  fn default() -> Self {
+    Self::String(String::new())
+  }
+}
+
+impl Display for Value {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    match self {
+      Self::List(list) => write!(f, "{}", list.join(" ")),
+      Self::String(string) => write!(f, "{string}"),
+    }
+  }
+}
+
+impl From<String> for Value {
+  fn from(string: String) -> Self {
+    Self::String(string)
+  }
+}
+
+impl From<&str> for Value {
+  fn from(string: &str) -> Self {
+    Self::String(string.into())
+  }
+}
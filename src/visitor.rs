@@ -0,0 +1,35 @@
+use super::*;
+
+/// Receives each recipe reached by `Justfile::run_recipe`, which traverses
+/// recipes, their dependencies, and their subsequents in the order that they
+/// run. Running recipes and planning them with `--plan` share the traversal,
+/// so that the plan matches what would run.
+pub(crate) trait Visitor<'src>: Sync {
+  /// Whether dependencies of `[parallel]` recipes are visited concurrently
+  const CONCURRENT: bool;
+
+  /// Called before the parameters and dependencies of a recipe which has not
+  /// already run are evaluated
+  fn confirm(&self, context: &ExecutionContext<'src, '_>, recipe: &Recipe<'src>)
+    -> RunResult<'src>;
+
+  /// Called for a recipe which already ran with the same arguments
+  fn skip(
+    &self,
+    arguments: &[Vec<String>],
+    context: &ExecutionContext<'src, '_>,
+    recipe: &Recipe<'src>,
+    reason: Reason<'src>,
+  ) -> RunResult<'src>;
+
+  /// Called for a recipe after its prior dependencies, and before its
+  /// subsequents
+  fn visit(
+    &self,
+    context: &ExecutionContext<'src, '_>,
+    positional: &[String],
+    reason: Reason<'src>,
+    recipe: &Recipe<'src>,
+    scope: &Scope<'src, '_>,
+  ) -> RunResult<'src>;
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+/// A pre-order traversal of an expression and all of its subexpressions
+pub(crate) struct Walk<'expression, 'src> {
+  enter_comprehensions: bool,
+  stack: Vec<&'expression Expression<'src>>,
+}
+
+impl<'expression, 'src> Walk<'expression, 'src> {
+  pub(crate) fn new(root: &'expression Expression<'src>) -> Self {
+    Self {
+      enter_comprehensions: true,
+      stack: vec![root],
+    }
+  }
+
+  /// Like `new`, but do not descend into the element and condition of
+  /// comprehensions, which are evaluated in a scope with an additional
+  /// binding.
+  pub(crate) fn shallow(root: &'expression Expression<'src>) -> Self {
+    Self {
+      enter_comprehensions: false,
+      stack: vec![root],
+    }
+  }
+}
+
+impl<'expression, 'src> Iterator for Walk<'expression, 'src> {
+  type Item = &'expression Expression<'src>;
+
+  fn next(&mut self) -> Option<Self::Item> {
+    let expression = self.stack.pop()?;
+
+    match expression {
+      Expression::And { lhs, rhs } | Expression::Or { lhs, rhs } => {
+        self.stack.push(lhs);
+        self.stack.push(rhs);
+      }
+      Expression::Assert {
+        condition: Condition {
+          lhs,
+          rhs,
+          operator: _,
+        },
+        error,
+      } => {
+        self.stack.push(error);
+        self.stack.push(rhs);
+        self.stack.push(lhs);
+      }
+      Expression::Backtick { .. }
+      | Expression::Integer { .. }
+      | Expression::QualifiedVariable { .. }
+      | Expression::StringLiteral { .. }
+      | Expression::Variable { .. } => {}
+      Expression::Call { thunk } => match thunk {
+        Thunk::Nullary { .. } => {}
+        Thunk::Unary { arg, .. }
+        | Thunk::UnaryList { arg, .. }
+        | Thunk::UnaryToList { arg, .. } => {
+          self.stack.push(arg);
+        }
+        Thunk::UnaryOpt {
+          args: (a, opt_b), ..
+        } => {
+          self.stack.push(a);
+          if let Some(b) = opt_b.as_ref() {
+            self.stack.push(b);
+          }
+        }
+        Thunk::UnaryPlus {
+          args: (a, rest), ..
+        } => {
+          let first: &[&Expression] = &[a];
+          for arg in first.iter().copied().chain(rest).rev() {
+            self.stack.push(arg);
+          }
+        }
+        Thunk::Binary { args, .. } => {
+          for arg in args.iter().rev() {
+            self.stack.push(arg);
+          }
+        }
+        Thunk::BinaryPlus {
+          args: ([a, b], rest),
+          ..
+        } => {
+          let first: &[&Expression] = &[a, b];
+          for arg in first.iter().copied().chain(rest).rev() {
+            self.stack.push(arg);
+          }
+        }
+        Thunk::Ternary { args, .. } => {
+          for arg in args.iter().rev() {
+            self.stack.push(arg);
+          }
+        }
+        Thunk::User { args, .. } => {
+          for arg in args.iter().rev() {
+            self.stack.push(arg);
+          }
+        }
+      },
+      Expression::Comprehension {
+        condition,
+        element,
+        iterable,
+        ..
+      } => {
+        if self.enter_comprehensions {
+          if let Some(Condition { lhs, rhs, .. }) = condition {
+            self.stack.push(rhs);
+            self.stack.push(lhs);
+          }
+          self.stack.push(element);
+        }
+        self.stack.push(iterable);
+      }
+      Expression::Arithmetic { lhs, rhs, .. } | Expression::Concatenation { lhs, rhs } => {
+        self.stack.push(rhs);
+        self.stack.push(lhs);
+      }
+      Expression::Conditional {
+        condition: Condition {
+          lhs,
+          rhs,
+          operator: _,
+        },
+        then,
+        otherwise,
+      } => {
+        self.stack.push(otherwise);
+        self.stack.push(then);
+        self.stack.push(rhs);
+        self.stack.push(lhs);
+      }
+      Expression::FormatString { contents, .. }
+      | Expression::Group { contents }
+      | Expression::Int { contents }
+      | Expression::Splat { contents } => {
+        self.stack.push(contents);
+      }
+      Expression::Index { lhs, index } => {
+        self.stack.push(index);
+        self.stack.push(lhs);
+      }
+      Expression::Join { lhs, rhs } => {
+        self.stack.push(rhs);
+        if let Some(lhs) = lhs {
+          self.stack.push(lhs);
+        }
+      }
+      Expression::Match {
+        arms,
+        default,
+        subject,
+      } => {
+        self.stack.push(default);
+        for arm in arms.iter().rev() {
+          self.stack.push(&arm.value);
+        }
+        self.stack.push(subject);
+      }
+      Expression::List { elements } => {
+        for element in elements.iter().rev() {
+          self.stack.push(element);
+        }
+      }
+    }
+
+    Some(expression)
+  }
+}
@@ -0,0 +1,195 @@
+use {
+  super::*,
+  notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _},
+  std::{
+    path::Component,
+    sync::mpsc::{self, Receiver},
+  },
+};
+
+/// Watches justfile sources and files matching `--watch-glob` patterns for
+/// changes, using filesystem events
+pub(crate) struct Watcher {
+  directories: BTreeSet<PathBuf>,
+  events: Receiver<notify::Result<Event>>,
+  globs: Vec<glob::Pattern>,
+  paths: BTreeSet<PathBuf>,
+  watcher: RecommendedWatcher,
+}
+
+impl Watcher {
+  const DEBOUNCE: Duration = Duration::from_millis(100);
+  const POLL: Duration = Duration::from_millis(250);
+
+  const MATCH_OPTIONS: glob::MatchOptions = glob::MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+  };
+
+  pub(crate) fn new(config: &Config, search: &Search) -> RunResult<'static, Self> {
+    let directory = Self::canonicalize(&search.working_directory);
+
+    let escaped = glob::Pattern::escape(&directory.to_string_lossy());
+
+    let (sender, events) = mpsc::channel();
+
+    let mut watcher = Self {
+      directories: BTreeSet::new(),
+      events,
+      globs: Vec::new(),
+      paths: BTreeSet::new(),
+      watcher: notify::recommended_watcher(sender)
+        .map_err(|notify_error| Error::Watch { notify_error })?,
+    };
+
+    for pattern in &config.watch_globs {
+      let error = |pattern_error| Error::WatchPattern {
+        pattern: pattern.clone(),
+        pattern_error,
+      };
+
+      glob::Pattern::new(pattern).map_err(error)?;
+
+      watcher.globs.push(
+        glob::Pattern::new(&if Path::new(pattern).is_absolute() {
+          pattern.clone()
+        } else {
+          format!("{escaped}/{pattern}")
+        })
+        .map_err(error)?,
+      );
+
+      // watch the directory named by the components of the pattern before the
+      // first wildcard, recursively if the rest of the pattern has more than
+      // one component
+      let components = Path::new(pattern).components().collect::<Vec<Component>>();
+
+      let literal = components
+        .iter()
+        .take(components.len().saturating_sub(1))
+        .take_while(|component| {
+          !component
+            .as_os_str()
+            .to_string_lossy()
+            .contains(['*', '?', '['])
+        })
+        .count();
+
+      let mode = if components.len() - literal > 1 {
+        RecursiveMode::Recursive
+      } else {
+        RecursiveMode::NonRecursive
+      };
+
+      watcher.watch_directory(
+        &directory.join(components[..literal].iter().collect::<PathBuf>()),
+        mode,
+      );
+    }
+
+    watcher.watch(vec![search.justfile.clone()]);
+
+    Ok(watcher)
+  }
+
+  fn canonicalize(path: &Path) -> PathBuf {
+    if let Ok(path) = path.canonicalize() {
+      return path;
+    }
+
+    // paths which don't exist yet are canonicalized via their parent
+    match (path.parent(), path.file_name()) {
+      (Some(parent), Some(name)) => Self::canonicalize(parent).join(name),
+      _ => path.into(),
+    }
+  }
+
+  /// Watch the closest existing ancestor of `directory`. Directories which
+  /// cannot be watched are skipped, since there is nothing to observe.
+  fn watch_directory(&mut self, directory: &Path, mode: RecursiveMode) {
+    let Some(directory) = directory.ancestors().find(|ancestor| ancestor.is_dir()) else {
+      return;
+    };
+
+    if mode == RecursiveMode::NonRecursive && self.directories.contains(directory) {
+      return;
+    }
+
+    if self.watcher.watch(directory, mode).is_ok() {
+      self.directories.insert(directory.into());
+    }
+  }
+
+  /// Watch `paths`, in addition to files matching globs, discarding changes
+  /// seen so far
+  pub(crate) fn watch(&mut self, paths: Vec<PathBuf>) {
+    self.paths = paths.iter().map(|path| Self::canonicalize(path)).collect();
+
+    // editors often replace files instead of writing them, so watch the
+    // parent directories of paths instead of the paths themselves
+    for path in self.paths.clone() {
+      if let Some(parent) = path.parent() {
+        self.watch_directory(parent, RecursiveMode::NonRecursive);
+      }
+    }
+
+    while self.events.try_recv().is_ok() {}
+  }
+
+  fn is_change(&self, event: &notify::Result<Event>) -> bool {
+    let Ok(event) = event else {
+      return false;
+    };
+
+    if event.need_rescan() {
+      return true;
+    }
+
+    // reading a file is not a change
+    if matches!(event.kind, EventKind::Access(_)) {
+      return false;
+    }
+
+    event.paths.iter().any(|path| {
+      self.paths.contains(path)
+        || self
+          .globs
+          .iter()
+          .any(|glob| glob.matches_path_with(path, Self::MATCH_OPTIONS))
+    })
+  }
+
+  /// Wait up to one polling interval for changes, returning `true` once
+  /// changes have been detected and no further changes have been made for
+  /// the debounce period
+  pub(crate) fn changed(&mut self) -> bool {
+    let deadline = Instant::now() + Self::POLL;
+
+    loop {
+      let Ok(event) = self
+        .events
+        .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+      else {
+        return false;
+      };
+
+      if self.is_change(&event) {
+        break;
+      }
+    }
+
+    let mut deadline = Instant::now() + Self::DEBOUNCE;
+
+    while let Ok(event) = self
+      .events
+      .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+    {
+      if self.is_change(&event) {
+        deadline = Instant::now() + Self::DEBOUNCE;
+      }
+    }
+
+    true
+  }
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+/// Search for executable `name` the way `Command` would. Names containing a
+/// path separator are looked up directly, otherwise the directories in `path`
+/// are searched in order. Relative names and directories are resolved against
+/// `working_directory`.
+pub(crate) fn which(name: &str, path: Option<&OsStr>, working_directory: &Path) -> Option<PathBuf> {
+  if name.is_empty() {
+    return None;
+  }
+
+  let name = Path::new(name);
+
+  let directories = if name.components().count() > 1 {
+    vec![working_directory.to_owned()]
+  } else {
+    path
+      .into_iter()
+      .flat_map(env::split_paths)
+      .map(|directory| working_directory.join(directory))
+      .collect()
+  };
+
+  for directory in directories {
+    let candidate = directory.join(name);
+
+    if Platform::is_executable(&candidate) {
+      return Some(candidate);
+    }
+
+    if cfg!(windows) && name.extension().is_none() {
+      let extensions = env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into());
+
+      for extension in extensions
+        .split(';')
+        .filter(|extension| !extension.is_empty())
+      {
+        let candidate = candidate.with_extension(extension.trim_start_matches('.'));
+
+        if Platform::is_executable(&candidate) {
+          return Some(candidate);
+        }
+      }
+    }
+  }
+
+  None
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+  use {super::*, temptree::temptree};
+
+  #[test]
+  fn search() {
+    let tmp = temptree! {
+      a: {
+        foo: "",
+      },
+      b: {
+        foo: "",
+        bar: "",
+      },
+    };
+
+    for file in ["a/foo", "b/foo"] {
+      Platform::set_execute_permission(&tmp.path().join(file)).unwrap();
+    }
+
+    let path = env::join_paths(["a", "b"]).unwrap();
+
+    assert_eq!(
+      which("foo", Some(&path), tmp.path()),
+      Some(tmp.path().join("a/foo")),
+    );
+
+    assert_eq!(which("bar", Some(&path), tmp.path()), None);
+
+    assert_eq!(which("baz", Some(&path), tmp.path()), None);
+
+    assert_eq!(which("foo", None, tmp.path()), None);
+
+    assert_eq!(
+      which("b/foo", None, tmp.path()),
+      Some(tmp.path().join("b/foo")),
+    );
+
+    assert_eq!(which("", Some(&path), tmp.path()), None);
+  }
+}
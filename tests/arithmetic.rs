@@ -0,0 +1,412 @@
+use super::*;
+
+#[test]
+fn operators() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(7 + 2)}} {{int(7 - 2)}} {{int(7 * 2)}} {{int(7 % 2)}}
+      ",
+    )
+    .stdout("9 5 14 1\n")
+    .run();
+}
+
+#[test]
+fn multiplication_binds_tighter_than_addition() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(1 + 2 * 3)}} {{int((1 + 2) * 3)}} {{int(2 * 5 % 3)}}
+      ",
+    )
+    .stdout("7 9 1\n")
+    .run();
+}
+
+#[test]
+fn operators_are_left_associative() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(10 - 3 - 2)}}
+      ",
+    )
+    .stdout("5\n")
+    .run();
+}
+
+#[test]
+fn operands_may_be_any_value() {
+  Test::new()
+    .justfile(
+      "
+        base := '8080'
+        shards := `echo 3`
+
+        foo offset='1':
+          @echo {{int(base + shards * offset)}} {{int(len(['a', 'b']) + 1)}}
+      ",
+    )
+    .args(["foo", "2"])
+    .stdout("8086 3\n")
+    .run();
+}
+
+#[test]
+fn function_arguments_are_not_numeric() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(trim(' 1' + '2 ') + 1)}}
+      ",
+    )
+    .stdout("13\n")
+    .run();
+}
+
+#[test]
+fn plus_outside_int_concatenates() {
+  Test::new()
+    .justfile(
+      "
+        port := 80 + 80
+
+        foo:
+          @echo {{port}} {{int(80 + 80)}}
+      ",
+    )
+    .stdout("8080 160\n")
+    .run();
+}
+
+#[test]
+fn minus_outside_int_is_an_error() {
+  Test::new()
+    .justfile(
+      "
+        a := '1'
+        b := a - 1
+      ",
+    )
+    .stderr(
+      "
+        error: Expected '&&', '||', '[', comment, end of file, end of line, '(', '+', or '/', but found '-'
+         ——▶ justfile:2:8
+          │
+        2 │ b := a - 1
+          │        ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn percent_outside_int_is_an_error() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          echo {{ 10 % 3 }}
+      ",
+    )
+    .stderr(
+      "
+        error: Expected '&&', '||', '[', '}}', '+', or '/', but found '%'
+         ——▶ justfile:2:14
+          │
+        2 │   echo {{ 10 % 3 }}
+          │              ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn comparison_outside_condition_is_an_error() {
+  Test::new()
+    .justfile(
+      "
+        a := '1'
+        b := (a < 2)
+      ",
+    )
+    .stderr(
+      "
+        error: Expected '&&', '||', '[', '(', ')', '+', or '/', but found '<'
+         ——▶ justfile:2:9
+          │
+        2 │ b := (a < 2)
+          │         ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn results_are_normalized() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int('007')}} {{int('+5')}} {{int(0 - 5)}}
+      ",
+    )
+    .stdout("7 5 -5\n")
+    .run();
+}
+
+#[test]
+fn integer_literal_default() {
+  Test::new()
+    .justfile(
+      "
+        foo count=3:
+          @echo {{int(count * 2)}}
+      ",
+    )
+    .stdout("6\n")
+    .run();
+}
+
+#[test]
+fn non_integer_operand() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int('3.5' + 1)}}
+      ",
+    )
+    .stderr("error: Expected integer but found `3.5`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn overflow() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(9223372036854775807 + 1)}}
+      ",
+    )
+    .stderr("error: Integer overflow evaluating `9223372036854775807 + 1`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn multiplication_overflow() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(4294967296 * 4294967296)}}
+      ",
+    )
+    .stderr("error: Integer overflow evaluating `4294967296 * 4294967296`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn literal_out_of_range() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(9223372036854775808)}}
+      ",
+    )
+    .stderr("error: Expected integer but found `9223372036854775808`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn remainder_by_zero() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{int(5 % (1 - 1))}}
+      ",
+    )
+    .stderr("error: Remainder by zero evaluating `5 % 0`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn int_cannot_be_redefined() {
+  Test::new()
+    .justfile("fn int(x) := x")
+    .stderr(
+      "
+        error: Function `int` redefines a built-in function
+         ——▶ justfile:1:4
+          │
+        1 │ fn int(x) := x
+          │    ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn int_may_be_used_as_variable_name() {
+  Test::new()
+    .justfile(
+      "
+        int := '1'
+
+        foo:
+          @echo {{int}} {{int(int + 1)}}
+      ",
+    )
+    .stdout("1 2\n")
+    .run();
+}
+
+#[test]
+fn comparisons() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ if '10' > '9' { 'a' } else { 'b' } }}
+          @echo {{ if 3 >= 3 { 'a' } else { 'b' } }}
+          @echo {{ if '-1' < '0' { 'a' } else { 'b' } }}
+          @echo {{ if 4 <= 3 { 'a' } else { 'b' } }}
+      ",
+    )
+    .stdout("a\na\na\nb\n")
+    .run();
+}
+
+#[test]
+fn comparison_with_arithmetic() {
+  Test::new()
+    .justfile(
+      "
+        shards := '3'
+
+        foo:
+          @echo {{ if int(shards * 2) > 5 { 'many' } else { 'few' } }}
+      ",
+    )
+    .stdout("many\n")
+    .run();
+}
+
+#[test]
+fn comparison_with_non_integer() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{ if 'a' < 'b' { 'a' } else { 'b' } }}
+      ",
+    )
+    .stderr("error: Expected integer but found `a`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn comparison_in_comprehension() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{[x for x in ['1', '5', '10'] if x >= 5]}}
+      ",
+    )
+    .stdout("5 10\n")
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        a := int(b+c*(d - 1)%2)
+        b := '1'
+        c := '2'
+        d := '3'
+
+        foo:
+          echo {{ if a>=2 { 'x' } else { 'y' } }}
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        a := int(b + c * (d - 1) % 2)
+        b := '1'
+        c := '2'
+        d := '3'
+
+        foo:
+            echo {{ if a >= 2 { 'x' } else { 'y' } }}
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn integer_literal_dump() {
+  Test::new()
+    .justfile(
+      "
+        x := 3
+        y := [0, '1'][0]
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        x := 3
+        y := [0, '1'][0]
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn integer_literal_evaluate() {
+  Test::new()
+    .justfile(
+      "
+        x := 3
+      ",
+    )
+    .args(["--evaluate", "x"])
+    .stdout("3")
+    .run();
+}
+
+#[test]
+fn evaluate() {
+  Test::new()
+    .justfile(
+      "
+        port := int(base + 1)
+        base := '8080'
+      ",
+    )
+    .args(["--evaluate", "port"])
+    .stdout("8081")
+    .run();
+}
@@ -0,0 +1,313 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  [cache]
+  build:
+    @echo build
+";
+
+fn cached(tempdir: TempDir) -> Test {
+  let cache = tempdir.path().join("cache");
+  Test::with_tempdir(tempdir).env("XDG_CACHE_HOME", cache.to_str().unwrap())
+}
+
+#[test]
+fn cached_recipe_is_skipped_when_unchanged() {
+  let output = cached(tempdir()).justfile(JUSTFILE).stdout("build\n").run();
+
+  cached(output.tempdir).justfile(JUSTFILE).run();
+}
+
+#[test]
+fn unchanged_recipe_is_reported_when_verbose() {
+  let output = cached(tempdir()).justfile(JUSTFILE).stdout("build\n").run();
+
+  cached(output.tempdir)
+    .justfile(JUSTFILE)
+    .arg("--verbose")
+    .stderr("===> Recipe `build` is up to date\n")
+    .run();
+}
+
+#[test]
+fn cached_recipe_runs_when_body_changes() {
+  let output = cached(tempdir())
+    .justfile(
+      "
+      x := 'a'
+
+      [cache]
+      build:
+        @echo {{x}}
+      ",
+    )
+    .stdout("a\n")
+    .run();
+
+  cached(output.tempdir)
+    .justfile(
+      "
+      x := 'a'
+
+      [cache]
+      build:
+        @echo {{x}}
+      ",
+    )
+    .args(["x=b"])
+    .stdout("b\n")
+    .run();
+}
+
+#[test]
+fn cached_recipe_runs_when_arguments_change() {
+  let justfile = "
+    [cache]
+    build mode:
+      @echo {{mode}}
+  ";
+
+  let output = cached(tempdir())
+    .justfile(justfile)
+    .args(["build", "debug"])
+    .stdout("debug\n")
+    .run();
+
+  let output = cached(output.tempdir)
+    .justfile(justfile)
+    .args(["build", "debug"])
+    .run();
+
+  cached(output.tempdir)
+    .justfile(justfile)
+    .args(["build", "release"])
+    .stdout("release\n")
+    .run();
+}
+
+#[test]
+fn each_set_of_arguments_is_cached_separately() {
+  let justfile = "
+    [cache]
+    build mode:
+      @echo {{mode}}
+  ";
+
+  let output = cached(tempdir())
+    .justfile(justfile)
+    .args(["build", "debug"])
+    .stdout("debug\n")
+    .run();
+
+  let output = cached(output.tempdir)
+    .justfile(justfile)
+    .args(["build", "release"])
+    .stdout("release\n")
+    .run();
+
+  cached(output.tempdir)
+    .justfile(justfile)
+    .args(["build", "debug"])
+    .run();
+}
+
+#[test]
+fn cached_recipe_runs_when_justfile_changes() {
+  let output = cached(tempdir()).justfile(JUSTFILE).stdout("build\n").run();
+
+  cached(output.tempdir)
+    .justfile(
+      "
+      set quiet
+
+      [cache]
+      build:
+        @echo build
+      ",
+    )
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn cached_recipe_runs_when_environment_changes() {
+  let justfile = "
+    export FOO := env('FOO')
+
+    [cache]
+    build:
+      @echo $FOO
+  ";
+
+  let output = cached(tempdir())
+    .justfile(justfile)
+    .env("FOO", "a")
+    .stdout("a\n")
+    .run();
+
+  cached(output.tempdir)
+    .justfile(justfile)
+    .env("FOO", "b")
+    .stdout("b\n")
+    .run();
+}
+
+#[test]
+fn cached_recipe_runs_when_sources_change() {
+  let justfile = "
+    [cache, sources('*.c')]
+    build:
+      @echo build
+  ";
+
+  let output = cached(tempdir())
+    .justfile(justfile)
+    .write("main.c", "a")
+    .stdout("build\n")
+    .run();
+
+  let output = cached(output.tempdir).justfile(justfile).run();
+
+  cached(output.tempdir)
+    .justfile(justfile)
+    .write("main.c", "b")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn failed_runs_are_not_cached() {
+  let justfile = "
+    [cache]
+    build:
+      @echo build
+      @test -e ok
+  ";
+
+  let output = cached(tempdir())
+    .justfile(justfile)
+    .stdout("build\n")
+    .stderr("error: Recipe `build` failed on line 4 with exit code 1\n")
+    .status(EXIT_FAILURE)
+    .run();
+
+  cached(output.tempdir)
+    .justfile(justfile)
+    .write("ok", "")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn force_overrides_cache() {
+  let output = cached(tempdir()).justfile(JUSTFILE).stdout("build\n").run();
+
+  cached(output.tempdir)
+    .justfile(JUSTFILE)
+    .arg("--force")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn dry_run_is_not_cached() {
+  let output = cached(tempdir())
+    .justfile(JUSTFILE)
+    .arg("--dry-run")
+    .stderr("echo build\n")
+    .run();
+
+  cached(output.tempdir)
+    .justfile(JUSTFILE)
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn cached_script_recipe() {
+  let justfile = "
+    [cache]
+    build:
+      #!/usr/bin/env bash
+      echo build
+  ";
+
+  let output = cached(tempdir()).justfile(justfile).stdout("build\n").run();
+
+  cached(output.tempdir).justfile(justfile).run();
+}
+
+#[test]
+fn cache_status() {
+  let output = cached(tempdir()).justfile(JUSTFILE).stdout("build\n").run();
+
+  cached(output.tempdir)
+    .justfile(JUSTFILE)
+    .arg("--cache-status")
+    .stdout_regex(
+      "Cache directory: .*/cache/just/[0-9a-f]{64}\nCached recipes:\n    build [0-9a-f]{64}\n",
+    )
+    .run();
+}
+
+#[test]
+fn cache_status_includes_arguments() {
+  let output = cached(tempdir())
+    .justfile(
+      "
+      [cache]
+      build mode:
+        @echo {{mode}}
+      ",
+    )
+    .args(["build", "debug"])
+    .stdout("debug\n")
+    .run();
+
+  cached(output.tempdir)
+    .justfile(
+      "
+      [cache]
+      build mode:
+        @echo {{mode}}
+      ",
+    )
+    .arg("--cache-status")
+    .stdout_regex("Cache directory: .*\nCached recipes:\n    build debug [0-9a-f]{64}\n")
+    .run();
+}
+
+#[test]
+fn cache_status_empty() {
+  cached(tempdir())
+    .justfile(JUSTFILE)
+    .arg("--cache-status")
+    .stdout_regex("Cache directory: .*\nCached recipes:\n")
+    .run();
+}
+
+#[test]
+fn cache_clear() {
+  let output = cached(tempdir()).justfile(JUSTFILE).stdout("build\n").run();
+
+  let output = cached(output.tempdir)
+    .justfile(JUSTFILE)
+    .arg("--cache-clear")
+    .stderr_regex("Cleared cache directory `.*`\n")
+    .run();
+
+  cached(output.tempdir)
+    .justfile(JUSTFILE)
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn cache_clear_takes_no_arguments() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--cache-clear", "build"])
+    .stderr("error: `--cache-clear` used with unexpected argument: `build`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -53,6 +53,13 @@ test_complete_recipes_from_subdirs() {
 }
 test_complete_recipes_from_subdirs
 
+test_complete_argument_choices() {
+  COMP_WORDS=(just deploy s)
+  COMP_CWORD=2 _just just
+  reply_equals 'declare -a COMPREPLY=([0]="staging")'
+}
+test_complete_argument_choices
+
 # --- Conclusion ---
 if [ $exit_code = 0 ]; then
   echo "All tests passed."
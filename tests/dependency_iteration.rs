@@ -0,0 +1,270 @@
+use super::*;
+
+#[test]
+fn dependency_is_invoked_once_per_element() {
+  Test::new()
+    .justfile(
+      "
+        regions := ['us', 'eu', 'ap']
+
+        deploy-all: (deploy r for r in regions)
+
+        deploy region:
+          @echo {{region}}
+      ",
+    )
+    .stdout("us\neu\nap\n")
+    .run();
+}
+
+#[test]
+fn other_arguments_are_passed_to_each_invocation() {
+  Test::new()
+    .justfile(
+      "
+        deploy-all version: (deploy version r + '-1' for r in ['us', 'eu'])
+
+        deploy version region:
+          @echo {{version}} {{region}}
+      ",
+    )
+    .args(["deploy-all", "v2"])
+    .stdout("v2 us-1\nv2 eu-1\n")
+    .run();
+}
+
+#[test]
+fn elements_may_be_filtered() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in ['a', 'b', 'c'] if x != 'b')
+
+        bar x:
+          @echo {{x}}
+      ",
+    )
+    .stdout("a\nc\n")
+    .run();
+}
+
+#[test]
+fn empty_list() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in [])
+          @echo foo
+
+        bar x:
+          @echo {{x}}
+      ",
+    )
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn duplicate_invocations_are_skipped() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar 'b') (bar x for x in ['a', 'b', 'a'])
+
+        bar x:
+          @echo {{x}}
+      ",
+    )
+    .stdout("b\na\n")
+    .run();
+}
+
+#[test]
+fn iterable_may_be_a_variadic_parameter() {
+  Test::new()
+    .justfile(
+      "
+        foo +regions: (bar r for r in regions)
+
+        bar region:
+          @echo {{region}}
+      ",
+    )
+    .args(["foo", "us", "eu"])
+    .stdout("us\neu\n")
+    .run();
+}
+
+#[test]
+fn iteration_variable_shadows_parameters() {
+  Test::new()
+    .justfile(
+      "
+        foo r='outer': (bar r for r in ['inner']) (bar r)
+
+        bar x:
+          @echo {{x}}
+      ",
+    )
+    .stdout("inner\nouter\n")
+    .run();
+}
+
+#[test]
+fn subsequent_dependencies() {
+  Test::new()
+    .justfile(
+      "
+        foo: && (bar x for x in ['a', 'b'])
+          @echo foo
+
+        bar x:
+          @echo {{x}}
+      ",
+    )
+    .stdout("foo\na\nb\n")
+    .run();
+}
+
+#[test]
+fn dry_run() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in ['a', 'b'])
+
+        bar x:
+          echo {{x}}
+      ",
+    )
+    .arg("--dry-run")
+    .stderr("echo a\necho b\n")
+    .run();
+}
+
+#[test]
+fn plan() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in ['a', 'b', 'a'])
+
+        bar x:
+      ",
+    )
+    .arg("--plan")
+    .stdout(
+      r#"
+        1. bar x="a"  # dependency of foo
+        2. bar x="b"  # dependency of foo
+        -  bar x="a"  # dependency of foo, skipped, already run
+        3. foo
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn iterating_over_string_is_an_error() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in 'a')
+
+        bar x:
+      ",
+    )
+    .stderr("error: Expected list but found string `a`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn iteration_variable_is_local_to_dependency() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in ['a']) (bar x)
+
+        bar x:
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `x` not defined
+         ——▶ justfile:1:34
+          │
+        1 │ foo: (bar x for x in ['a']) (bar x)
+          │                                  ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn undefined_variable_in_iterable() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in xs)
+
+        bar x:
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `xs` not defined
+         ——▶ justfile:1:22
+          │
+        1 │ foo: (bar x for x in xs)
+          │                      ^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn argument_count_is_checked() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x x for x in ['a'])
+
+        bar x:
+      ",
+    )
+    .stderr(
+      "
+        error: Dependency `bar` got 2 arguments but takes 1 argument
+         ——▶ justfile:1:7
+          │
+        1 │ foo: (bar x x for x in ['a'])
+          │       ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar x for x in ['a','b'] if x!='b')
+
+        bar x:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        foo: (bar x for x in ['a', 'b'] if x != 'b')
+
+        bar x:
+      ",
+    )
+    .run();
+}
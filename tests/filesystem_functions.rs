@@ -0,0 +1,161 @@
+use super::*;
+
+#[test]
+fn glob() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{glob('proto/**/*.proto')}}
+      ",
+    )
+    .write("proto/b.proto", "")
+    .write("proto/a.proto", "")
+    .write("proto/v1/c.proto", "")
+    .write("proto/README.md", "")
+    .stdout("proto/a.proto proto/b.proto proto/v1/c.proto\n")
+    .run();
+}
+
+#[test]
+fn glob_returns_list() {
+  Test::new()
+    .justfile(
+      "
+        protos := glob('*.proto')
+
+        foo:
+          @echo {{len(protos)}} {{protos[1]}} {{[file_stem(p) for p in protos]}}
+      ",
+    )
+    .write("a.proto", "")
+    .write("b.proto", "")
+    .stdout("2 b.proto a b\n")
+    .run();
+}
+
+#[test]
+fn glob_with_no_matches_is_empty() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo '[{{glob('*.proto')}}]'
+      ",
+    )
+    .stdout("[]\n")
+    .run();
+}
+
+#[test]
+fn glob_is_relative_to_justfile_directory() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{glob('src/*.rs')}}
+      ",
+    )
+    .write("src/main.rs", "")
+    .write("sub/src/lib.rs", "")
+    .current_dir("sub")
+    .stdout("src/main.rs\n")
+    .run();
+}
+
+#[test]
+fn glob_in_dependency_iteration() {
+  Test::new()
+    .justfile(
+      "
+        all: (compile p for p in glob('*.proto'))
+
+        compile proto:
+          @echo protoc {{proto}}
+      ",
+    )
+    .write("a.proto", "")
+    .write("b.proto", "")
+    .stdout("protoc a.proto\nprotoc b.proto\n")
+    .run();
+}
+
+#[test]
+fn glob_invalid_pattern() {
+  Test::new()
+    .justfile("foo := glob('[a')")
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `glob` failed: Invalid glob pattern `[a`: Pattern syntax error near position 0: invalid range pattern
+         ——▶ justfile:1:8
+          │
+        1 │ foo := glob('[a')
+          │        ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn read_dir() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{read_dir('proto')}} {{len(read_directory('proto'))}}
+      ",
+    )
+    .write("proto/b.proto", "")
+    .write("proto/a.proto", "")
+    .write("proto/v1/c.proto", "")
+    .stdout("proto/a.proto proto/b.proto proto/v1 3\n")
+    .run();
+}
+
+#[test]
+fn read_dir_not_found() {
+  Test::new()
+    .justfile("foo := read_dir('proto')")
+    .args(["--evaluate", "foo"])
+    .stderr_regex(
+      r"error: Call to function `read_dir` failed: I/O error reading directory `proto`: .*",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn is_file_and_is_dir() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{is_file('a/b')}} {{is_file('a')}} {{is_file('c')}}
+          @echo {{is_dir('a/b')}} {{is_dir('a')}} {{is_directory('c')}}
+      ",
+    )
+    .write("a/b", "")
+    .stdout("true false false\nfalse true false\n")
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        foo := glob( '*.rs' )
+        bar := read_dir( 'src' )
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        foo := glob('*.rs')
+        bar := read_dir('src')
+      ",
+    )
+    .run();
+}
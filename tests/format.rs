@@ -1155,3 +1155,32 @@ fn if_else() {
     )
     .run();
 }
+
+#[test]
+fn match_expression() {
+  Test::new()
+    .justfile(
+      "
+        x := match os() {
+          'linux' => 'a',
+          1 => 'b',
+          _ => 'c',
+        }
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        x := match os() { 'linux' => 'a', 1 => 'b', _ => 'c' }
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn formatted_match_expression_passes_check() {
+  Test::new()
+    .justfile("x := match os() { 'linux' => 'a', _ => 'c' }\n")
+    .args(["--unstable", "--fmt", "--check"])
+    .run();
+}
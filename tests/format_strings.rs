@@ -0,0 +1,242 @@
+use super::*;
+
+#[test]
+fn interpolation() {
+  Test::new()
+    .justfile(
+      "
+        name := 'just'
+        version := '1.0'
+        archive := f'{{name}}-{{version}}.tar.gz'
+
+        foo:
+          @echo {{archive}}
+      ",
+    )
+    .stdout("just-1.0.tar.gz\n")
+    .run();
+}
+
+#[test]
+fn interpolations_may_contain_expressions() {
+  Test::new()
+    .justfile(
+      "
+        name := 'just'
+
+        foo:
+          @echo {{f'{{uppercase(name)}}-{{name + '!'}}-{{int(1 + 2)}}'}}
+      ",
+    )
+    .stdout("JUST-just!-3\n")
+    .run();
+}
+
+#[test]
+fn double_quoted_format_strings_process_escapes() {
+  Test::new()
+    .justfile(
+      r#"
+        x := 'a'
+        y := f"\"{{x}}\"\t"
+
+        foo:
+          @echo '{{y}}'
+      "#,
+    )
+    .stdout("\"a\"\t\n")
+    .run();
+}
+
+#[test]
+fn single_quoted_format_strings_are_raw() {
+  Test::new()
+    .justfile(
+      r"
+        x := 'a'
+        y := f'\t{{x}}'
+
+        foo:
+          @echo '{{y}}'
+      ",
+    )
+    .stdout("\\ta\n")
+    .run();
+}
+
+#[test]
+fn escaped_interpolation() {
+  Test::new()
+    .justfile(
+      "
+        x := 'a'
+
+        foo:
+          @echo '{{f'{{{{x}}-{{x}}'}}'
+      ",
+    )
+    .stdout("{{x}}-a\n")
+    .run();
+}
+
+#[test]
+fn lists_are_joined_in_each_interpolation() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b']
+
+        foo:
+          @echo '{{f'{{xs}}-{{xs}}'}}'
+      ",
+    )
+    .stdout("a b-a b\n")
+    .run();
+}
+
+#[test]
+fn nested() {
+  Test::new()
+    .justfile(
+      r#"
+        x := 'a'
+
+        foo:
+          @echo '{{f'[{{f"({{x}})"}}]'}}'
+      "#,
+    )
+    .stdout("[(a)]\n")
+    .run();
+}
+
+#[test]
+fn empty() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo '[{{f''}}]'
+      ",
+    )
+    .stdout("[]\n")
+    .run();
+}
+
+#[test]
+fn dependency_arguments() {
+  Test::new()
+    .justfile(
+      "
+        foo: (bar f'v{{1}}')
+
+        bar x:
+          @echo {{x}}
+      ",
+    )
+    .stdout("v1\n")
+    .run();
+}
+
+#[test]
+fn undefined_variable() {
+  Test::new()
+    .justfile("x := f'a{{y}}'")
+    .stderr(
+      "
+        error: Variable `y` not defined
+         ——▶ justfile:1:11
+          │
+        1 │ x := f'a{{y}}'
+          │           ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unterminated() {
+  Test::new()
+    .justfile("x := f'a{{y}}")
+    .stderr(
+      "
+        error: Unterminated string
+         ——▶ justfile:1:6
+          │
+        1 │ x := f'a{{y}}
+          │      ^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unterminated_interpolation() {
+  Test::new()
+    .justfile("x := f'a{{y")
+    .stderr(
+      "
+        error: Unterminated interpolation
+         ——▶ justfile:1:9
+          │
+        1 │ x := f'a{{y
+          │         ^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn indented_format_strings_are_not_supported() {
+  Test::new()
+    .justfile("x := f'''a'''")
+    .stderr(
+      "
+        error: Indented format strings are not supported
+         ——▶ justfile:1:6
+          │
+        1 │ x := f'''a'''
+          │      ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn f_is_still_a_valid_name() {
+  Test::new()
+    .justfile(
+      "
+        f := 'a'
+
+        foo:
+          @echo {{f}} {{f + 'b'}}
+      ",
+    )
+    .stdout("a ab\n")
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      r#"
+        x := f'a{{ y }}{{{{b}}'
+        y := f"\t{{'c' + z}}"
+        z := 'd'
+      "#,
+    )
+    .arg("--dump")
+    .stdout(
+      r#"
+        x := f'a{{ y }}{{{{b}}'
+        y := f"\t{{ 'c' + z }}"
+        z := 'd'
+      "#,
+    )
+    .run();
+}
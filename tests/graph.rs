@@ -0,0 +1,261 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  build: (compile 'x86') && notify
+
+  compile target:
+
+  notify:
+
+  lint:
+";
+
+#[test]
+fn dot() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--graph")
+    .stdout(
+      r#"
+      digraph justfile {
+        "build"
+        "compile"
+        "notify"
+        "lint"
+        "build" -> "compile" [label="'x86'"]
+        "build" -> "notify" [style=dashed]
+      }
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn mermaid() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--graph", "--graph-format", "mermaid"])
+    .stdout(
+      r#"
+      flowchart TD
+        n0["build"]
+        n1["compile"]
+        n2["notify"]
+        n3["lint"]
+        n0 -->|"'x86'"| n1
+        n0 -.-> n2
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn mermaid_recipe() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--graph", "--graph-format", "mermaid", "build"])
+    .stdout(
+      r#"
+      flowchart TD
+        n0["build"]
+        n1["compile"]
+        n2["notify"]
+        n0 -->|"'x86'"| n1
+        n0 -.-> n2
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn graph_format_env_var() {
+  Test::new()
+    .justfile("foo:")
+    .arg("--graph")
+    .env("JUST_GRAPH_FORMAT", "mermaid")
+    .stdout(
+      r#"
+      flowchart TD
+        n0["foo"]
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn recipe() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--graph", "compile"])
+    .stdout(
+      r#"
+      digraph justfile {
+        "compile"
+      }
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn recipe_includes_transitive_dependencies() {
+  Test::new()
+    .justfile(
+      "
+      a: b
+      b: c
+      c:
+      d:
+      ",
+    )
+    .args(["--graph", "a"])
+    .stdout(
+      r#"
+      digraph justfile {
+        "a"
+        "b"
+        "c"
+        "a" -> "b"
+        "b" -> "c"
+      }
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn labels_are_escaped() {
+  Test::new()
+    .justfile(
+      r#"
+      a: (b "x")
+      b y:
+      "#,
+    )
+    .args(["--graph", "a"])
+    .stdout(
+      r#"
+      digraph justfile {
+        "a"
+        "b"
+        "a" -> "b" [label="\"x\""]
+      }
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn mermaid_labels_are_escaped() {
+  Test::new()
+    .justfile(
+      r#"
+      a: (b "x")
+      b y:
+      "#,
+    )
+    .args(["--graph-format", "mermaid", "--graph", "a"])
+    .stdout(
+      r##"
+      flowchart TD
+        n0["a"]
+        n1["b"]
+        n0 -->|"#quot;x#quot;"| n1
+      "##,
+    )
+    .run();
+}
+
+#[test]
+fn modules() {
+  Test::new()
+    .write("foo.just", "bar: baz\nbaz:")
+    .justfile(
+      "
+      mod foo
+
+      qux:
+      ",
+    )
+    .arg("--graph")
+    .stdout(
+      r#"
+      digraph justfile {
+        "qux"
+        "foo::bar"
+        "foo::baz"
+        "foo::bar" -> "foo::baz"
+      }
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn module_path() {
+  Test::new()
+    .write("foo.just", "bar: baz\nbaz:")
+    .justfile(
+      "
+      mod foo
+
+      qux:
+      ",
+    )
+    .args(["--graph", "foo"])
+    .stdout(
+      r#"
+      digraph justfile {
+        "foo::bar"
+        "foo::baz"
+        "foo::bar" -> "foo::baz"
+      }
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn recipe_in_module() {
+  Test::new()
+    .write("foo.just", "bar: baz\nbaz:")
+    .justfile(
+      "
+      mod foo
+      ",
+    )
+    .args(["--graph", "foo::baz"])
+    .stdout(
+      r#"
+      digraph justfile {
+        "foo::baz"
+      }
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--graph", "biuld"])
+    .stderr(
+      "
+      error: Justfile does not contain recipe `biuld`
+      Did you mean `build`?
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_submodule() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["--graph", "foo", "bar"])
+    .stderr("error: Justfile does not contain submodule `foo bar`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
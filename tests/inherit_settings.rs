@@ -0,0 +1,140 @@
+use super::*;
+
+#[test]
+fn submodules_do_not_inherit_settings_by_default() {
+  Test::new()
+    .write("foo.just", "bar:\n echo BAR")
+    .justfile(
+      "
+        set quiet
+
+        mod foo
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("BAR\n")
+    .stderr("echo BAR\n")
+    .run();
+}
+
+#[test]
+fn submodules_inherit_settings() {
+  Test::new()
+    .write("foo.just", "x := 'X'\n\nbar:\n echo $x")
+    .justfile(
+      "
+        set inherit-settings
+        set export
+        set quiet
+
+        mod foo
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("X\n")
+    .run();
+}
+
+#[test]
+fn submodules_inherit_shell() {
+  Test::new()
+    .write("foo.just", "bar:\n echo BAR")
+    .justfile(
+      "
+        set inherit-settings
+        set shell := ['echo', 'SHELL']
+
+        mod foo
+      ",
+    )
+    .args(["foo", "bar"])
+    .shell(false)
+    .stdout("SHELL echo BAR\n")
+    .stderr("echo BAR\n")
+    .run();
+}
+
+#[test]
+fn submodules_can_override_inherited_settings() {
+  Test::new()
+    .write("foo.just", "set quiet := false\n\nbar:\n echo BAR")
+    .justfile(
+      "
+        set inherit-settings
+        set quiet
+
+        mod foo
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("BAR\n")
+    .stderr("echo BAR\n")
+    .run();
+}
+
+#[test]
+fn settings_are_inherited_transitively() {
+  Test::new()
+    .write("foo.just", "mod baz")
+    .write("baz.just", "bob:\n echo BOB")
+    .justfile(
+      "
+        set inherit-settings
+        set quiet
+
+        mod foo
+      ",
+    )
+    .args(["foo", "baz", "bob"])
+    .stdout("BOB\n")
+    .run();
+}
+
+#[test]
+fn submodules_can_stop_inheritance() {
+  Test::new()
+    .write("foo.just", "set inherit-settings := false\n\nmod baz")
+    .write("baz.just", "bob:\n echo BOB")
+    .justfile(
+      "
+        set inherit-settings
+        set quiet
+
+        mod foo
+      ",
+    )
+    .args(["foo", "baz", "bob"])
+    .stdout("BOB\n")
+    .stderr("echo BOB\n")
+    .run();
+}
+
+#[test]
+fn submodules_can_enable_inheritance_for_their_own_submodules() {
+  Test::new()
+    .write("foo.just", "set inherit-settings\nset quiet\n\nmod baz")
+    .write("baz.just", "bob:\n echo BOB")
+    .justfile("mod foo")
+    .args(["foo", "baz", "bob"])
+    .stdout("BOB\n")
+    .run();
+}
+
+#[test]
+fn inherited_settings_do_not_affect_parent() {
+  Test::new()
+    .write("foo.just", "set quiet")
+    .justfile(
+      "
+        set inherit-settings
+
+        mod foo
+
+        bar:
+          echo BAR
+      ",
+    )
+    .stdout("BAR\n")
+    .stderr("echo BAR\n")
+    .run();
+}
@@ -21,9 +21,18 @@ struct Assignment<'a> {
 #[serde(deny_unknown_fields)]
 struct Dependency<'a> {
   arguments: Vec<Value>,
+  iteration: Option<Value>,
   recipe: &'a str,
 }
 
+#[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Function<'a> {
+  body: Value,
+  name: &'a str,
+  parameters: Vec<&'a str>,
+}
+
 #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 struct Interpreter<'a> {
@@ -38,6 +47,7 @@ struct Module<'a> {
   assignments: BTreeMap<&'a str, Assignment<'a>>,
   doc: Option<&'a str>,
   first: Option<&'a str>,
+  functions: BTreeMap<&'a str, Function<'a>>,
   groups: Vec<&'a str>,
   modules: BTreeMap<&'a str, Module<'a>>,
   recipes: BTreeMap<&'a str, Recipe<'a>>,
@@ -50,10 +60,18 @@ struct Module<'a> {
 #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
 #[serde(deny_unknown_fields)]
 struct Parameter<'a> {
+  choices: Vec<&'a str>,
   default: Option<&'a str>,
   export: bool,
+  help: Option<&'a str>,
   kind: &'a str,
+  long: Option<&'a str>,
   name: &'a str,
+  pattern: Option<&'a str>,
+  short: Option<char>,
+  #[serde(rename = "type")]
+  type_: Option<&'a str>,
+  value: Option<&'a str>,
 }
 
 #[derive(Debug, Default, Deserialize, PartialEq, Serialize)]
@@ -84,6 +102,8 @@ struct Settings<'a> {
   export: bool,
   fallback: bool,
   ignore_comments: bool,
+  inherit_settings: bool,
+  path: Vec<&'a str>,
   positional_arguments: bool,
   quiet: bool,
   shell: Option<Interpreter<'a>>,
@@ -353,6 +373,217 @@ fn dependency_argument() {
                 json!(["call", "replace", "a", "b", "c"]),
               ]
               .into(),
+              ..default()
+            }]
+            .into(),
+            priors: 1,
+            ..default()
+          },
+        ),
+      ]
+      .into(),
+      ..default()
+    },
+  );
+}
+
+#[test]
+fn dependency_iteration() {
+  case(
+    "
+      foo x:
+      bar: (foo r for r in ['a', 'b'] if r != 'b')
+    ",
+    Module {
+      first: Some("foo"),
+      recipes: [
+        (
+          "foo",
+          Recipe {
+            name: "foo",
+            namepath: "foo",
+            parameters: [Parameter {
+              kind: "singular",
+              name: "x",
+              ..default()
+            }]
+            .into(),
+            ..default()
+          },
+        ),
+        (
+          "bar",
+          Recipe {
+            name: "bar",
+            namepath: "bar",
+            dependencies: [Dependency {
+              recipe: "foo",
+              arguments: [json!(["variable", "r"])].into(),
+              iteration: Some(json!({
+                "condition": ["!=", ["variable", "r"], "b"],
+                "iterable": ["list", "a", "b"],
+                "name": "r",
+              })),
+            }]
+            .into(),
+            priors: 1,
+            ..default()
+          },
+        ),
+      ]
+      .into(),
+      ..default()
+    },
+  );
+}
+
+#[test]
+fn dependency_arithmetic() {
+  case(
+    "
+      foo x:
+      bar: (foo int((1 + 2) * 3 % 4 - 5)) (foo if '1' < '2' { 'a' } else { 'b' })
+    ",
+    Module {
+      first: Some("foo"),
+      recipes: [
+        (
+          "foo",
+          Recipe {
+            name: "foo",
+            namepath: "foo",
+            parameters: [Parameter {
+              kind: "singular",
+              name: "x",
+              ..default()
+            }]
+            .into(),
+            ..default()
+          },
+        ),
+        (
+          "bar",
+          Recipe {
+            name: "bar",
+            namepath: "bar",
+            dependencies: [
+              Dependency {
+                recipe: "foo",
+                arguments: [json!([
+                  "int",
+                  ["-", ["%", ["*", ["+", "1", "2"], "3"], "4"], "5"]
+                ])]
+                .into(),
+                ..default()
+              },
+              Dependency {
+                recipe: "foo",
+                arguments: [json!(["if", ["<", "1", "2"], "a", "b"])].into(),
+                ..default()
+              },
+            ]
+            .into(),
+            priors: 2,
+            ..default()
+          },
+        ),
+      ]
+      .into(),
+      ..default()
+    },
+  );
+}
+
+#[test]
+fn match_expression() {
+  case(
+    "
+      foo x:
+      bar: (foo match 'a' { 'a' => 'b', _ => 'c' })
+    ",
+    Module {
+      first: Some("foo"),
+      recipes: [
+        (
+          "foo",
+          Recipe {
+            name: "foo",
+            namepath: "foo",
+            parameters: [Parameter {
+              kind: "singular",
+              name: "x",
+              ..default()
+            }]
+            .into(),
+            ..default()
+          },
+        ),
+        (
+          "bar",
+          Recipe {
+            name: "bar",
+            namepath: "bar",
+            dependencies: [Dependency {
+              recipe: "foo",
+              arguments: [json!(["match", "a", [["a", "b"]], "c"])].into(),
+              ..default()
+            }]
+            .into(),
+            priors: 1,
+            ..default()
+          },
+        ),
+      ]
+      .into(),
+      ..default()
+    },
+  );
+}
+
+#[test]
+fn format_string() {
+  case(
+    "
+      foo x:
+      bar y: (foo f'{{y}}-1')
+    ",
+    Module {
+      first: Some("foo"),
+      recipes: [
+        (
+          "foo",
+          Recipe {
+            name: "foo",
+            namepath: "foo",
+            parameters: [Parameter {
+              kind: "singular",
+              name: "x",
+              ..default()
+            }]
+            .into(),
+            ..default()
+          },
+        ),
+        (
+          "bar",
+          Recipe {
+            name: "bar",
+            namepath: "bar",
+            parameters: [Parameter {
+              kind: "singular",
+              name: "y",
+              ..default()
+            }]
+            .into(),
+            dependencies: [Dependency {
+              recipe: "foo",
+              arguments: [json!([
+                "concatenate",
+                ["concatenate", "", ["variable", "y"]],
+                "-1"
+              ])]
+              .into(),
+              ..default()
             }]
             .into(),
             priors: 1,
@@ -459,6 +690,31 @@ fn doc_comment() {
   );
 }
 
+#[test]
+fn function() {
+  case(
+    "
+      fn tag(name, version) := name + ':' + version
+    ",
+    Module {
+      functions: [(
+        "tag",
+        Function {
+          body: json!([
+            "concatenate",
+            ["variable", "name"],
+            ["concatenate", ":", ["variable", "version"]],
+          ]),
+          name: "tag",
+          parameters: vec!["name", "version"],
+        },
+      )]
+      .into(),
+      ..default()
+    },
+  );
+}
+
 #[test]
 fn empty_justfile() {
   case("", Module::default());
@@ -565,6 +821,161 @@ fn parameters() {
   );
 }
 
+#[test]
+fn options() {
+  case(
+    "
+      [arg('target', long, short='t', help='Target triple')]
+      [arg('release', long='release', value='true')]
+      build target release='false':
+    ",
+    Module {
+      first: Some("build"),
+      recipes: [(
+        "build",
+        Recipe {
+          attributes: [
+            json!({
+              "arg": {
+                "choices": null,
+                "help": null,
+                "long": "release",
+                "name": "release",
+                "pattern": null,
+                "short": null,
+                "value": "true",
+                "value_type": null,
+              },
+            }),
+            json!({
+              "arg": {
+                "choices": null,
+                "help": "Target triple",
+                "long": "target",
+                "name": "target",
+                "pattern": null,
+                "short": "t",
+                "value": null,
+                "value_type": null,
+              },
+            }),
+          ]
+          .into(),
+          name: "build",
+          namepath: "build",
+          parameters: [
+            Parameter {
+              help: Some("Target triple"),
+              kind: "singular",
+              long: Some("target"),
+              name: "target",
+              short: Some('t'),
+              ..default()
+            },
+            Parameter {
+              default: Some("false"),
+              kind: "singular",
+              long: Some("release"),
+              name: "release",
+              value: Some("true"),
+              ..default()
+            },
+          ]
+          .into(),
+          ..default()
+        },
+      )]
+      .into(),
+      ..default()
+    },
+  );
+}
+
+#[test]
+fn argument_constraints() {
+  case(
+    r"
+      [arg('env', choices='dev prod')]
+      [arg('replicas', type='integer')]
+      [arg('version', pattern='[0-9]+')]
+      deploy env replicas version:
+    ",
+    Module {
+      first: Some("deploy"),
+      recipes: [(
+        "deploy",
+        Recipe {
+          attributes: [
+            json!({
+              "arg": {
+                "choices": null,
+                "help": null,
+                "long": null,
+                "name": "replicas",
+                "pattern": null,
+                "short": null,
+                "value": null,
+                "value_type": "integer",
+              },
+            }),
+            json!({
+              "arg": {
+                "choices": null,
+                "help": null,
+                "long": null,
+                "name": "version",
+                "pattern": "[0-9]+",
+                "short": null,
+                "value": null,
+                "value_type": null,
+              },
+            }),
+            json!({
+              "arg": {
+                "choices": "dev prod",
+                "help": null,
+                "long": null,
+                "name": "env",
+                "pattern": null,
+                "short": null,
+                "value": null,
+                "value_type": null,
+              },
+            }),
+          ]
+          .into(),
+          name: "deploy",
+          namepath: "deploy",
+          parameters: [
+            Parameter {
+              choices: ["dev", "prod"].into(),
+              kind: "singular",
+              name: "env",
+              ..default()
+            },
+            Parameter {
+              kind: "singular",
+              name: "replicas",
+              type_: Some("integer"),
+              ..default()
+            },
+            Parameter {
+              kind: "singular",
+              name: "version",
+              pattern: Some("[0-9]+"),
+              ..default()
+            },
+          ]
+          .into(),
+          ..default()
+        },
+      )]
+      .into(),
+      ..default()
+    },
+  );
+}
+
 #[test]
 fn priors() {
   case(
@@ -672,6 +1083,7 @@ fn settings() {
       set export
       set fallback
       set ignore-comments
+      set path := ['bin', 'node_modules/.bin']
       set positional-arguments
       set quiet
       set shell := ['a', 'b', 'c']
@@ -699,6 +1111,7 @@ fn settings() {
         export: true,
         fallback: true,
         ignore_comments: true,
+        path: ["bin", "node_modules/.bin"].into(),
         positional_arguments: true,
         quiet: true,
         shell: Some(Interpreter {
@@ -814,6 +1227,51 @@ fn module() {
   );
 }
 
+#[test]
+fn inherited_module_settings() {
+  case_with_submodule(
+    "
+      set inherit-settings
+      set quiet
+
+      mod foo
+    ",
+    Some(("foo.just", "set export\n\nbar:")),
+    Module {
+      modules: [(
+        "foo",
+        Module {
+          first: Some("bar"),
+          source: "foo.just".into(),
+          recipes: [(
+            "bar",
+            Recipe {
+              name: "bar",
+              namepath: "foo::bar",
+              ..default()
+            },
+          )]
+          .into(),
+          settings: Settings {
+            export: true,
+            inherit_settings: true,
+            quiet: true,
+            ..default()
+          },
+          ..default()
+        },
+      )]
+      .into(),
+      settings: Settings {
+        inherit_settings: true,
+        quiet: true,
+        ..default()
+      },
+      ..default()
+    },
+  );
+}
+
 #[test]
 fn module_group() {
   case_with_submodule(
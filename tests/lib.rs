@@ -40,6 +40,7 @@ mod alias_style;
 mod allow_duplicate_recipes;
 mod allow_duplicate_variables;
 mod allow_missing;
+mod arithmetic;
 mod assert_stdout;
 mod assert_success;
 mod assertions;
@@ -47,6 +48,8 @@ mod assignment;
 mod attributes;
 mod backticks;
 mod byte_order_mark;
+#[cfg(target_os = "linux")]
+mod cache;
 mod changelog;
 mod choose;
 mod command;
@@ -56,6 +59,7 @@ mod confirm;
 mod constants;
 mod datetime;
 mod delimiters;
+mod dependency_iteration;
 mod directories;
 mod dotenv;
 mod edit;
@@ -66,13 +70,17 @@ mod examples;
 mod explain;
 mod export;
 mod fallback;
+mod filesystem_functions;
 mod format;
+mod format_strings;
 mod functions;
 #[cfg(unix)]
 mod global;
+mod graph;
 mod groups;
 mod ignore_comments;
 mod imports;
+mod inherit_settings;
 mod init;
 #[cfg(unix)]
 mod interrupts;
@@ -80,9 +88,14 @@ mod invocation_directory;
 mod json;
 mod line_prefixes;
 mod list;
+mod lists;
 mod logical_operators;
 mod man;
+mod match_expression;
 mod misc;
+mod module_dependencies;
+mod module_parameters;
+mod module_variables;
 mod modules;
 mod multibyte_char;
 mod newline_escape;
@@ -90,9 +103,13 @@ mod no_aliases;
 mod no_cd;
 mod no_dependencies;
 mod no_exit_message;
+mod options;
 mod os_attributes;
+mod parallel;
+mod parameter_types;
 mod parameters;
 mod parser;
+mod plan;
 mod positional_arguments;
 mod private;
 mod quiet;
@@ -101,6 +118,7 @@ mod readme;
 mod recursion_limit;
 mod regexes;
 mod request;
+mod retry;
 mod run;
 mod script;
 mod search;
@@ -112,13 +130,22 @@ mod shell_expansion;
 mod show;
 mod slash_operator;
 mod string;
+mod structured_data;
 mod subsequents;
 mod summary;
 mod tempdir;
+#[cfg(unix)]
+mod timeout;
 mod timestamps;
 mod undefined_variables;
 mod unexport;
 mod unstable;
+mod up_to_date;
+mod usage;
+mod user_functions;
+#[cfg(unix)]
+mod watch;
+mod which_function;
 #[cfg(windows)]
 mod windows;
 #[cfg(target_family = "windows")]
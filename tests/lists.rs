@@ -0,0 +1,506 @@
+use super::*;
+
+#[test]
+fn interpolation_joins_elements_with_spaces() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b', 'c']
+
+        foo:
+          @echo {{xs}}
+      ",
+    )
+    .stdout("a b c\n")
+    .run();
+}
+
+#[test]
+fn empty_list() {
+  Test::new()
+    .justfile(
+      "
+        xs := []
+
+        foo:
+          @echo '[{{xs}}]' {{len(xs)}}
+      ",
+    )
+    .stdout("[] 0\n")
+    .run();
+}
+
+#[test]
+fn trailing_comma_and_newlines() {
+  Test::new()
+    .justfile(
+      "
+        xs := [
+          'a',
+          'b',
+        ]
+
+        foo:
+          @echo {{xs}}
+      ",
+    )
+    .stdout("a b\n")
+    .run();
+}
+
+#[test]
+fn evaluate_shows_list_literals() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b c']
+        y := 'd'
+      ",
+    )
+    .arg("--evaluate")
+    .stdout(
+      r#"
+        xs := ["a", "b c"]
+        y  := "d"
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn evaluate_escapes_list_elements() {
+  let justfile = r#"
+    xs := ["it's", 'a\b', "c\"d", "e
+    f"]
+  "#;
+
+  let expected = r#"xs := ["it's", "a\\b", "c\"d", "e\nf"]"#;
+
+  Test::new()
+    .justfile(justfile)
+    .arg("--evaluate")
+    .stdout(format!("{expected}\n"))
+    .run();
+
+  Test::new()
+    .justfile(expected)
+    .arg("--evaluate")
+    .stdout(format!("{expected}\n"))
+    .run();
+}
+
+#[test]
+fn evaluate_single_list_variable() {
+  Test::new()
+    .justfile("xs := ['a', 'b']")
+    .args(["--evaluate", "xs"])
+    .stdout("a b")
+    .run();
+}
+
+#[test]
+fn index() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b', 'c']
+        i := '2'
+
+        foo:
+          @echo {{xs[0]}} {{xs[1]}} {{xs[i]}}
+      ",
+    )
+    .stdout("a b c\n")
+    .run();
+}
+
+#[test]
+fn index_out_of_range() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b']
+
+        foo:
+          @echo {{xs[2]}}
+      ",
+    )
+    .stderr("error: List index 2 is out of range for list of length 2\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn index_must_be_non_negative_integer() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b']
+
+        foo:
+          @echo {{xs['x']}}
+      ",
+    )
+    .stderr("error: List index `x` is not a non-negative integer\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn strings_cannot_be_indexed() {
+  Test::new()
+    .justfile(
+      "
+        x := 'abc'
+
+        foo:
+          @echo {{x[0]}}
+      ",
+    )
+    .stderr("error: Expected list but found string `abc`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn len() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{len(['a', 'b', 'c'])}}
+      ",
+    )
+    .stdout("3\n")
+    .run();
+}
+
+#[test]
+fn len_of_string_is_an_error() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{len('abc')}}
+      ",
+    )
+    .stderr(
+      "
+        error: Call to function `len` failed: Expected list but found string `abc`
+         ——▶ justfile:2:11
+          │
+        2 │   @echo {{len('abc')}}
+          │           ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn comprehension_maps_elements() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b']
+
+        foo:
+          @echo {{[uppercase(x) + '!' for x in xs]}}
+      ",
+    )
+    .stdout("A! B!\n")
+    .run();
+}
+
+#[test]
+fn comprehension_filters_elements() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a.rs', 'b.txt', 'c.rs']
+
+        foo:
+          @echo {{[x for x in xs if x =~ '\\.rs$']}}
+      ",
+    )
+    .stdout("a.rs c.rs\n")
+    .run();
+}
+
+#[test]
+fn comprehension_variable_shadows_variables() {
+  Test::new()
+    .justfile(
+      "
+        x := 'outer'
+        ys := [x + '!' for x in ['a', 'b']]
+
+        foo:
+          @echo {{ys}} {{x}}
+      ",
+    )
+    .stdout("a! b! outer\n")
+    .run();
+}
+
+#[test]
+fn comprehension_variable_is_not_defined_outside_comprehension() {
+  Test::new()
+    .justfile(
+      "
+        ys := [x for x in ['a']] + x
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `x` not defined
+         ——▶ justfile:1:28
+          │
+        1 │ ys := [x for x in ['a']] + x
+          │                            ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn comprehension_over_string_is_an_error() {
+  Test::new()
+    .justfile(
+      "
+        ys := [x for x in 'abc']
+
+        foo:
+      ",
+    )
+    .stderr("error: Expected list but found string `abc`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn concatenating_lists() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a'] + ['b', 'c']
+
+        foo:
+          @echo {{len(xs)}}: {{xs}}
+      ",
+    )
+    .stdout("3: a b c\n")
+    .run();
+}
+
+#[test]
+fn concatenating_variadic_parameters() {
+  Test::new()
+    .justfile(
+      "
+        foo *xs:
+          @echo {{len(xs + xs)}}: {{xs + xs}}
+          @echo {{xs + '' + xs}}
+      ",
+    )
+    .args(["foo", "a", "b"])
+    .stdout("4: a b a b\na ba b\n")
+    .run();
+}
+
+#[test]
+fn splat_into_list() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b']
+        ys := ['z', *xs, 'c']
+
+        foo:
+          @echo {{len(ys)}}: {{ys}}
+      ",
+    )
+    .stdout("4: z a b c\n")
+    .run();
+}
+
+#[test]
+fn variadic_parameters_are_lists() {
+  Test::new()
+    .justfile(
+      "
+        foo +args:
+          @echo {{len(args)}} {{args[1]}} {{args}}
+      ",
+    )
+    .args(["foo", "a b", "c"])
+    .stdout("2 c a b c\n")
+    .run();
+}
+
+#[test]
+fn star_parameters_are_empty_lists() {
+  Test::new()
+    .justfile(
+      "
+        foo *args:
+          @echo '[{{args}}]' {{len(args)}}
+      ",
+    )
+    .stdout("[] 0\n")
+    .run();
+}
+
+#[test]
+fn variadic_parameter_default_is_list() {
+  Test::new()
+    .justfile(
+      "
+        foo *args='a b':
+          @echo {{len(args)}} {{args}}
+      ",
+    )
+    .stdout("1 a b\n")
+    .run();
+}
+
+#[test]
+fn variadic_parameter_elements_can_be_quoted() {
+  Test::new()
+    .justfile(
+      "
+        foo *files:
+          @for f in {{[quote(f) for f in files]}}; do echo \"[$f]\"; done
+      ",
+    )
+    .args(["foo", "a b", "c"])
+    .stdout("[a b]\n[c]\n")
+    .run();
+}
+
+#[test]
+fn exported_lists_are_joined() {
+  Test::new()
+    .justfile(
+      "
+        export XS := ['a', 'b']
+
+        foo:
+          @echo $XS
+      ",
+    )
+    .stdout("a b\n")
+    .run();
+}
+
+#[test]
+fn splat_into_dependency_arguments() {
+  Test::new()
+    .justfile(
+      "
+        set positional-arguments
+
+        files := ['a b', 'c']
+
+        foo: (bar 'x' *files)
+
+        bar first *rest:
+          @echo $# {{first}} \"$2\"
+      ",
+    )
+    .stdout("3 x a b\n")
+    .run();
+}
+
+#[test]
+fn splatted_arguments_are_checked() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a', 'b', 'c']
+
+        foo: (bar *xs)
+
+        bar x y:
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `bar` got 3 arguments but only takes 2
+        usage:
+            just bar x y
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn too_many_arguments_with_splat() {
+  Test::new()
+    .justfile(
+      "
+        xs := []
+
+        foo: (bar 'a' 'b' *xs)
+
+        bar x:
+      ",
+    )
+    .stderr(
+      "
+        error: Dependency `bar` got 2 arguments but takes 1 argument
+         ——▶ justfile:3:7
+          │
+        3 │ foo: (bar 'a' 'b' *xs)
+          │       ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn user_functions_may_return_lists() {
+  Test::new()
+    .justfile(
+      "
+        fn pair(a, b) := [a, b]
+
+        foo:
+          @echo {{len(pair('x', 'y'))}}
+      ",
+    )
+    .stdout("2\n")
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        xs := ['a',*ys]
+        ys := [y for y in ['b'] if y != 'c']
+
+        foo: (bar *xs)
+          echo {{xs[0]}}
+
+        bar *args:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        xs := ['a', *ys]
+        ys := [y for y in ['b'] if y != 'c']
+
+        foo: (bar *xs)
+            echo {{ xs[0] }}
+
+        bar *args:
+      ",
+    )
+    .run();
+}
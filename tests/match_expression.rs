@@ -0,0 +1,225 @@
+use super::*;
+
+#[test]
+fn first_matching_arm_is_used() {
+  Test::new()
+    .justfile(
+      "
+        foo x:
+          @echo {{ match x { 'linux' => 'tar.gz', 'windows' => 'zip', _ => 'other' } }}
+      ",
+    )
+    .args(["foo", "windows"])
+    .stdout("zip\n")
+    .run();
+}
+
+#[test]
+fn default_arm_is_used_if_no_arm_matches() {
+  Test::new()
+    .justfile(
+      "
+        foo x:
+          @echo {{ match x { 'linux' => 'tar.gz', _ => 'other' } }}
+      ",
+    )
+    .args(["foo", "plan9"])
+    .stdout("other\n")
+    .run();
+}
+
+#[test]
+fn arms_may_span_lines() {
+  Test::new()
+    .justfile(
+      "
+        target := match os() + '-' + arch() {
+          'linux-x86_64' => 'x86_64-unknown-linux-musl',
+          'macos-aarch64' => 'aarch64-apple-darwin',
+          _ => 'unknown',
+        }
+
+        foo:
+          @echo {{ if target != '' { 'ok' } else { 'empty' } }}
+      ",
+    )
+    .stdout("ok\n")
+    .run();
+}
+
+#[test]
+fn integer_patterns() {
+  Test::new()
+    .justfile(
+      "
+        foo n:
+          @echo {{ match n { 1 => 'one', 2 => 'two', _ => 'many' } }}
+      ",
+    )
+    .args(["foo", "2"])
+    .stdout("two\n")
+    .run();
+}
+
+#[test]
+fn only_selected_arm_is_evaluated() {
+  Test::new()
+    .justfile(
+      "
+        x := match 'a' { 'a' => 'ok', 'b' => `exit 1`, _ => `exit 1` }
+
+        foo:
+          @echo {{x}}
+      ",
+    )
+    .stdout("ok\n")
+    .run();
+}
+
+#[test]
+fn arms_may_be_lists() {
+  Test::new()
+    .justfile(
+      "
+        flags := match 'release' { 'release' => ['--release', '--locked'], _ => [] }
+
+        foo:
+          @echo {{len(flags)}} {{flags}}
+      ",
+    )
+    .stdout("2 --release --locked\n")
+    .run();
+}
+
+#[test]
+fn missing_default_arm() {
+  Test::new()
+    .justfile(
+      "
+        x := match os() { 'linux' => 'a' }
+      ",
+    )
+    .stderr(
+      "
+        error: `match` expression has no default arm `_`
+         ——▶ justfile:1:6
+          │
+        1 │ x := match os() { 'linux' => 'a' }
+          │      ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn duplicate_arm() {
+  Test::new()
+    .justfile(
+      "
+        x := match os() { 'linux' => 'a', \"linux\" => 'b', _ => 'c' }
+      ",
+    )
+    .stderr(
+      r#"
+        error: `match` expression has duplicate arm `linux`
+         ——▶ justfile:1:35
+          │
+        1 │ x := match os() { 'linux' => 'a', "linux" => 'b', _ => 'c' }
+          │                                   ^^^^^^^
+      "#,
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn default_arm_must_be_last() {
+  Test::new()
+    .justfile(
+      "
+        x := match os() { _ => 'a', 'linux' => 'b' }
+      ",
+    )
+    .stderr(
+      "
+        error: Expected '}', but found string
+         ——▶ justfile:1:29
+          │
+        1 │ x := match os() { _ => 'a', 'linux' => 'b' }
+          │                             ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn undefined_variable_in_arm() {
+  Test::new()
+    .justfile(
+      "
+        x := match os() { 'linux' => y, _ => 'c' }
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `y` not defined
+         ——▶ justfile:1:30
+          │
+        1 │ x := match os() { 'linux' => y, _ => 'c' }
+          │                              ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn match_may_be_used_as_dependency_argument() {
+  Test::new()
+    .justfile(
+      "
+        foo mode: (bar match mode { 'fast' => '-O1', _ => '-O3' })
+
+        bar flag:
+          @echo {{flag}}
+      ",
+    )
+    .args(["foo", "fast"])
+    .stdout("-O1\n")
+    .run();
+}
+
+#[test]
+fn match_may_be_used_as_name() {
+  Test::new()
+    .justfile(
+      "
+        match := 'm'
+        x := match
+        y := match + 'n'
+
+        foo match:
+          @echo {{x}} {{y}} {{match}}
+      ",
+    )
+    .args(["foo", "o"])
+    .stdout("m mn o\n")
+    .run();
+}
+
+#[test]
+fn match_variable_may_be_match_subject() {
+  Test::new()
+    .justfile(
+      "
+        match := 'a'
+
+        foo:
+          @echo {{ match match { 'a' => 'b', _ => 'c' } }}
+      ",
+    )
+    .stdout("b\n")
+    .run();
+}
@@ -1667,13 +1667,13 @@ test! {
 test! {
   name:     unknown_start_of_token,
   justfile: "
-assembly_source_files = %(wildcard src/arch/$(arch)/*.s)
+assembly_source_files = ^(wildcard src/arch/$(arch)/*.s)
 ",
   stderr:   r"
     error: Unknown start of token:
      ——▶ justfile:1:25
       │
-    1 │ assembly_source_files = %(wildcard src/arch/$(arch)/*.s)
+    1 │ assembly_source_files = ^(wildcard src/arch/$(arch)/*.s)
       │                         ^
   ",
    status:   EXIT_FAILURE,
@@ -0,0 +1,243 @@
+use super::*;
+
+#[test]
+fn recipes_can_depend_on_recipes_in_submodules() {
+  Test::new()
+    .write("foo.just", "bar:\n @echo BAR")
+    .justfile(
+      "
+        mod foo
+
+        baz: foo::bar
+          @echo BAZ
+      ",
+    )
+    .stdout("BAR\nBAZ\n")
+    .run();
+}
+
+#[test]
+fn recipes_can_depend_on_recipes_in_nested_submodules() {
+  Test::new()
+    .write("foo.just", "mod bar")
+    .write("bar.just", "baz:\n @echo BAZ")
+    .justfile(
+      "
+        mod foo
+
+        qux: foo::bar::baz
+          @echo QUX
+      ",
+    )
+    .stdout("BAZ\nQUX\n")
+    .run();
+}
+
+#[test]
+fn recipes_can_depend_on_recipes_in_parent_module() {
+  Test::new()
+    .write("foo.just", "bar: super::baz\n @echo BAR")
+    .justfile(
+      "
+        mod foo
+
+        baz:
+          @echo BAZ
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("BAZ\nBAR\n")
+    .run();
+}
+
+#[test]
+fn recipes_can_depend_on_recipes_in_sibling_modules() {
+  Test::new()
+    .write("foo.just", "a: super::bar::b\n @echo A")
+    .write("bar.just", "b:\n @echo B")
+    .justfile(
+      "
+        mod foo
+        mod bar
+      ",
+    )
+    .args(["foo::a"])
+    .stdout("B\nA\n")
+    .run();
+}
+
+#[test]
+fn submodule_dependencies_can_take_arguments() {
+  Test::new()
+    .write("foo.just", "bar x:\n @echo {{x}}")
+    .justfile(
+      "
+        mod foo
+
+        baz: (foo::bar 'hello')
+      ",
+    )
+    .stdout("hello\n")
+    .run();
+}
+
+#[test]
+fn submodule_dependencies_use_submodule_variables_and_settings() {
+  Test::new()
+    .write("foo.just", "set quiet\n\nx := 'FOO'\n\nbar:\n echo {{x}}")
+    .justfile(
+      "
+        x := 'ROOT'
+
+        mod foo
+
+        baz: foo::bar
+          echo {{x}}
+      ",
+    )
+    .stdout("FOO\nROOT\n")
+    .stderr("echo ROOT\n")
+    .run();
+}
+
+#[test]
+fn submodule_dependencies_run_in_submodule_working_directory() {
+  Test::new()
+    .write("foo/mod.just", "bar:\n @cat data.txt")
+    .write("foo/data.txt", "FOO")
+    .justfile(
+      "
+        mod foo
+
+        baz: foo::bar
+      ",
+    )
+    .stdout("FOO")
+    .run();
+}
+
+#[test]
+fn submodule_dependencies_are_deduplicated() {
+  Test::new()
+    .write("foo.just", "a:\n @echo A")
+    .write("bar.just", "b: super::foo::a\n @echo B")
+    .justfile(
+      "
+        mod foo
+        mod bar
+
+        c: foo::a bar::b
+          @echo C
+      ",
+    )
+    .stdout("A\nB\nC\n")
+    .run();
+}
+
+#[test]
+fn submodule_dependencies_are_dumped_with_path() {
+  Test::new()
+    .write("foo.just", "bar:")
+    .justfile(
+      "
+        mod foo
+
+        baz: foo::bar (foo::bar)
+      ",
+    )
+    .arg("--dump")
+    .stdout("mod foo\n\nbaz: foo::bar foo::bar\n")
+    .run();
+}
+
+#[test]
+fn dependency_on_unknown_module() {
+  Test::new()
+    .justfile("bar: foo::baz")
+    .stderr(
+      "
+        error: Recipe `bar` has unknown dependency `foo::baz`
+         ——▶ justfile:1:6
+          │
+        1 │ bar: foo::baz
+          │      ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dependency_on_unknown_recipe_in_submodule() {
+  Test::new()
+    .write("foo.just", "")
+    .justfile(
+      "
+        mod foo
+
+        bar: foo::baz
+      ",
+    )
+    .stderr(
+      "
+        error: Recipe `bar` has unknown dependency `foo::baz`
+         ——▶ justfile:3:11
+          │
+        3 │ bar: foo::baz
+          │           ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn super_dependency_in_root_module() {
+  Test::new()
+    .justfile("foo:\nbar: super::foo")
+    .stderr(
+      "
+        error: Recipe `bar` has unknown dependency `super::foo`
+         ——▶ justfile:2:6
+          │
+        2 │ bar: super::foo
+          │      ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn circular_dependency_across_modules() {
+  Test::new()
+    .write("foo.just", "bar: super::baz")
+    .justfile(
+      "
+        mod foo
+
+        baz: foo::bar
+      ",
+    )
+    .stderr_regex(
+      "error: Recipe `bar` has circular dependency `baz -> foo::bar -> baz`\n.*foo.just:1:13\n.*",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dependency_path_separator_may_not_contain_whitespace() {
+  Test::new()
+    .write("foo.just", "bar:")
+    .justfile(
+      "
+        mod foo
+
+        baz: foo :: bar
+      ",
+    )
+    .stderr_regex("error: Expected .*\n(.*\n)*")
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,239 @@
+use super::*;
+
+#[test]
+fn arguments_are_bound_to_parameters() {
+  Test::new()
+    .write("foo.just", "param name\n\nbar:\n @echo {{name}}")
+    .justfile(
+      "
+        mod foo (name='FOO')
+      ",
+    )
+    .args(["foo", "bar"])
+    .stdout("FOO\n")
+    .run();
+}
+
+#[test]
+fn same_file_can_be_loaded_under_different_names() {
+  Test::new()
+    .write(
+      "service.just",
+      "param name\nparam port\n\nrun:\n @echo {{name}}:{{port}}",
+    )
+    .justfile(
+      "
+        mod api 'service.just' (name='api', port='8080')
+        mod web 'service.just' (name='web', port='80')
+
+        run: api::run web::run
+      ",
+    )
+    .stdout("api:8080\nweb:80\n")
+    .run();
+}
+
+#[test]
+fn parameter_defaults_are_used_when_argument_is_omitted() {
+  Test::new()
+    .write(
+      "service.just",
+      "param port := '80'\n\nrun:\n @echo {{port}}",
+    )
+    .justfile(
+      "
+        mod api 'service.just' (port='8080')
+        mod web 'service.just'
+
+        run: api::run web::run
+      ",
+    )
+    .stdout("8080\n80\n")
+    .run();
+}
+
+#[test]
+fn parameters_can_be_used_in_assignments() {
+  Test::new()
+    .write(
+      "service.just",
+      "param name\nparam port := '80'\nurl := 'http://' + name + ':' + port\n\nrun:\n @echo {{url}}",
+    )
+    .justfile(
+      "
+        mod api 'service.just' (name='api')
+      ",
+    )
+    .args(["api", "run"])
+    .stdout("http://api:80\n")
+    .run();
+}
+
+#[test]
+fn parameters_can_be_referred_to_with_qualified_paths() {
+  Test::new()
+    .write("service.just", "param port")
+    .justfile(
+      "
+        mod api 'service.just' (port='8080')
+
+        run:
+          @echo {{api::port}}
+      ",
+    )
+    .stdout("8080\n")
+    .run();
+}
+
+#[test]
+fn parameters_are_evaluated_as_variables() {
+  Test::new()
+    .write("service.just", "param name\nparam port := '80'")
+    .justfile(
+      "
+        mod api 'service.just' (name='api')
+      ",
+    )
+    .args(["--evaluate", "api"])
+    .stdout(
+      "
+        name := \"api\"
+        port := \"80\"
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn module_arguments_are_dumped() {
+  Test::new()
+    .write("service.just", "param name")
+    .justfile(
+      "
+        mod api 'service.just' (name='api')
+      ",
+    )
+    .arg("--dump")
+    .stdout("mod api 'service.just' (name='api')\n")
+    .run();
+}
+
+#[test]
+fn missing_argument() {
+  Test::new()
+    .write("service.just", "param name")
+    .justfile("mod api 'service.just'")
+    .stderr(
+      "
+        error: Module `api` is missing argument for parameter `name`
+         ——▶ justfile:1:5
+          │
+        1 │ mod api 'service.just'
+          │     ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_argument() {
+  Test::new()
+    .write("service.just", "param name")
+    .justfile("mod api 'service.just' (name='api', port='80')")
+    .stderr(
+      "
+        error: Module `api` has no parameter `port`
+         ——▶ justfile:1:37
+          │
+        1 │ mod api 'service.just' (name='api', port='80')
+          │                                     ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn duplicate_argument() {
+  Test::new()
+    .write("service.just", "param name")
+    .justfile("mod api 'service.just' (name='api', name='web')")
+    .stderr(
+      "
+        error: Module `api` has duplicate argument `name`
+         ——▶ justfile:1:37
+          │
+        1 │ mod api 'service.just' (name='api', name='web')
+          │                                     ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn root_parameters_must_have_defaults() {
+  Test::new()
+    .justfile("param name")
+    .stderr(
+      "
+        error: Module parameter `name` of root justfile must have a default value
+         ——▶ justfile:1:7
+          │
+        1 │ param name
+          │       ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn root_parameter_defaults_can_be_overridden() {
+  Test::new()
+    .justfile(
+      "
+        param name := 'foo'
+
+        bar:
+          @echo {{name}}
+      ",
+    )
+    .args(["name=baz", "bar"])
+    .stdout("baz\n")
+    .run();
+}
+
+#[test]
+fn parameter_conflicts_with_variable() {
+  Test::new()
+    .write("service.just", "param name\nname := 'foo'")
+    .justfile("mod api 'service.just' (name='api')")
+    .stderr(
+      "
+        error: Variable `name` has multiple definitions
+         ——▶ service.just:2:1
+          │
+        2 │ name := 'foo'
+          │ ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn param_is_still_a_valid_variable_and_recipe_name() {
+  Test::new()
+    .justfile(
+      "
+        param := 'foo'
+
+        param:
+          @echo {{param}}
+      ",
+    )
+    .stdout("foo\n")
+    .run();
+}
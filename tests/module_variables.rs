@@ -0,0 +1,320 @@
+use super::*;
+
+#[test]
+fn submodule_variables_can_be_used_in_assignments() {
+  Test::new()
+    .write("foo.just", "version := '1.2.3'")
+    .justfile(
+      "
+        mod foo
+
+        tag := 'v' + foo::version
+
+        bar:
+          @echo {{tag}}
+      ",
+    )
+    .stdout("v1.2.3\n")
+    .run();
+}
+
+#[test]
+fn submodule_variables_can_be_used_in_recipes() {
+  Test::new()
+    .write("foo.just", "version := '1.2.3'")
+    .justfile(
+      "
+        mod foo
+
+        bar x=foo::version:
+          @echo {{x}} {{foo::version}}
+      ",
+    )
+    .stdout("1.2.3 1.2.3\n")
+    .run();
+}
+
+#[test]
+fn nested_submodule_variables_can_be_used() {
+  Test::new()
+    .write("foo.just", "mod bar")
+    .write("bar.just", "baz := 'BAZ'")
+    .justfile(
+      "
+        mod foo
+
+        qux:
+          @echo {{foo::bar::baz}}
+      ",
+    )
+    .stdout("BAZ\n")
+    .run();
+}
+
+#[test]
+fn submodule_variables_may_depend_on_other_submodule_variables() {
+  Test::new()
+    .write("foo.just", "a := b + '!'\nb := 'B'\n\nbar:\n @echo {{a}}")
+    .justfile(
+      "
+        mod foo
+
+        x := foo::a
+
+        bar: foo::bar
+          @echo {{x}}
+      ",
+    )
+    .stdout("B!\nB!\n")
+    .run();
+}
+
+#[test]
+fn submodule_variables_are_not_shadowed_by_parent_variables() {
+  Test::new()
+    .write("foo.just", "x := 'child'\nz := x\n\nshow:\n @echo {{z}}")
+    .justfile(
+      "
+        mod foo
+
+        x := 'parent'
+        y := foo::z
+
+        bar: foo::show
+          @echo {{y}}
+      ",
+    )
+    .stdout("child\nchild\n")
+    .run();
+}
+
+#[test]
+fn nested_submodule_variables_are_not_shadowed_by_parent_variables() {
+  Test::new()
+    .write("foo.just", "mod bar\n\nx := 'foo'")
+    .write("bar.just", "a := x\nx := 'bar'")
+    .justfile(
+      "
+        mod foo
+
+        x := 'root'
+        y := foo::bar::a
+
+        baz:
+          @echo {{y}}
+      ",
+    )
+    .stdout("bar\n")
+    .run();
+}
+
+#[test]
+fn submodule_variables_are_evaluated_once() {
+  Test::new()
+    .write(
+      "foo.just",
+      "x := `echo evaluated >&2; echo X`\n\nbar:\n @echo {{x}}",
+    )
+    .justfile(
+      "
+        mod foo
+
+        y := foo::x
+
+        baz: foo::bar
+          @echo {{y}} {{foo::x}}
+      ",
+    )
+    .stdout("X\nX X\n")
+    .stderr("evaluated\n")
+    .run();
+}
+
+#[test]
+fn submodule_backticks_see_parent_exports() {
+  Test::new()
+    .write("foo.just", "x := `echo $Y`")
+    .justfile(
+      "
+        mod foo
+
+        export Y := 'hello'
+
+        z := foo::x
+
+        bar:
+          @echo {{z}}
+      ",
+    )
+    .stdout("hello\n")
+    .run();
+}
+
+#[test]
+fn submodule_variables_cannot_be_used_in_functions() {
+  Test::new()
+    .write("foo.just", "x := 'X'")
+    .justfile(
+      "
+        mod foo
+
+        fn f() := foo::x
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `foo::x` not defined
+         ——▶ justfile:3:16
+          │
+        3 │ fn f() := foo::x
+          │                ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_module() {
+  Test::new()
+    .justfile("x := foo::bar")
+    .stderr(
+      "
+        error: Variable `foo::bar` refers to unknown module `foo`
+         ——▶ justfile:1:6
+          │
+        1 │ x := foo::bar
+          │      ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_nested_module() {
+  Test::new()
+    .write("foo.just", "")
+    .justfile(
+      "
+        mod foo
+
+        bar:
+          echo {{foo::baz::x}}
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `foo::baz::x` refers to unknown module `foo::baz`
+         ——▶ justfile:4:15
+          │
+        4 │   echo {{foo::baz::x}}
+          │               ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_variable() {
+  Test::new()
+    .write("foo.just", "x := 'X'")
+    .justfile(
+      "
+        mod foo
+
+        y := foo::z
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `foo::z` not defined
+         ——▶ justfile:3:11
+          │
+        3 │ y := foo::z
+          │           ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn qualified_variables_are_dumped() {
+  Test::new()
+    .write("foo.just", "x := 'X'")
+    .justfile(
+      "
+        mod foo
+
+        y := foo::x
+      ",
+    )
+    .arg("--dump")
+    .stdout("mod foo\n\ny := foo::x\n")
+    .run();
+}
+
+#[test]
+fn evaluate_submodule_variable() {
+  Test::new()
+    .write("foo.just", "x := 'X'\ny := 'Y'")
+    .justfile("mod foo")
+    .args(["--evaluate", "foo::y"])
+    .stdout("Y")
+    .run();
+}
+
+#[test]
+fn evaluate_submodule() {
+  Test::new()
+    .write("foo.just", "x := 'X'\nyy := 'Y'\n_z := 'Z'")
+    .justfile("mod foo")
+    .args(["--evaluate", "foo"])
+    .stdout("x  := \"X\"\nyy := \"Y\"\n")
+    .run();
+}
+
+#[test]
+fn evaluate_nested_submodule_variable() {
+  Test::new()
+    .write("foo.just", "mod bar")
+    .write("bar.just", "x := 'X'")
+    .justfile("mod foo")
+    .args(["--evaluate", "foo::bar::x"])
+    .stdout("X")
+    .run();
+}
+
+#[test]
+fn evaluate_unknown_submodule_variable() {
+  Test::new()
+    .write("foo.just", "version := '1'")
+    .justfile("mod foo")
+    .args(["--evaluate", "foo::versio"])
+    .stderr(
+      "
+        error: Justfile does not contain variable `foo::versio`.
+        Did you mean `version`?
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn evaluate_root_variable_takes_precedence_over_module() {
+  Test::new()
+    .write("foo.just", "x := 'X'")
+    .justfile(
+      "
+        mod foo
+
+        foo := 'FOO'
+      ",
+    )
+    .args(["--evaluate", "foo"])
+    .stdout("FOO")
+    .run();
+}
@@ -477,7 +477,7 @@ fn root_dotenv_is_available_to_submodules() {
 }
 
 #[test]
-fn dotenv_settings_in_submodule_are_ignored() {
+fn disabling_dotenv_load_in_submodule_does_not_unload_root_dotenv() {
   Test::new()
     .justfile(
       "
@@ -496,6 +496,178 @@ fn dotenv_settings_in_submodule_are_ignored() {
     .run();
 }
 
+#[test]
+fn submodule_dotenv_is_loaded_relative_to_submodule() {
+  Test::new()
+    .justfile("mod foo")
+    .write("foo/mod.just", "set dotenv-load\nfoo:\n @echo $DOTENV_KEY")
+    .write("foo/.env", "DOTENV_KEY=foo")
+    .args(["foo", "foo"])
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn submodule_dotenv_is_not_available_to_root() {
+  Test::new()
+    .justfile(
+      "
+        mod foo
+
+        bar:
+          @echo ${DOTENV_KEY:-unset}
+      ",
+    )
+    .write("foo/mod.just", "set dotenv-load\nfoo:\n @echo $DOTENV_KEY")
+    .write("foo/.env", "DOTENV_KEY=foo")
+    .args(["bar", "foo::foo"])
+    .stdout("unset\nfoo\n")
+    .run();
+}
+
+#[test]
+fn submodule_dotenv_takes_precedence_over_root_dotenv() {
+  Test::new()
+    .justfile(
+      "
+        set dotenv-load
+
+        mod foo
+
+        bar:
+          @echo $A $B
+      ",
+    )
+    .write(".env", "A=root-a\nB=root-b")
+    .write("foo/mod.just", "set dotenv-load\nfoo:\n @echo $A $B")
+    .write("foo/.env", "B=foo-b")
+    .args(["bar", "foo::foo"])
+    .stdout("root-a root-b\nroot-a foo-b\n")
+    .run();
+}
+
+#[test]
+fn environment_takes_precedence_over_submodule_dotenv() {
+  Test::new()
+    .justfile("mod foo")
+    .write("foo/mod.just", "set dotenv-load\nfoo:\n @echo $DOTENV_KEY")
+    .write("foo/.env", "DOTENV_KEY=foo")
+    .env("DOTENV_KEY", "environment")
+    .args(["foo", "foo"])
+    .stdout("environment\n")
+    .run();
+}
+
+#[test]
+fn submodule_dotenv_is_used_in_submodule_assignments() {
+  Test::new()
+    .justfile(
+      "
+        mod foo
+
+        x := foo::y
+
+        bar:
+          @echo {{x}}
+      ",
+    )
+    .write("foo/mod.just", "set dotenv-load\ny := env('DOTENV_KEY')")
+    .write("foo/.env", "DOTENV_KEY=foo")
+    .arg("bar")
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn submodule_dotenv_is_loaded_once() {
+  Test::new()
+    .justfile(
+      "
+        mod foo
+
+        x := foo::a
+        y := foo::b
+
+        bar: foo::baz
+          @echo {{x}} {{y}}
+      ",
+    )
+    .write(
+      "foo/mod.just",
+      "
+set dotenv-load
+
+a := `echo DOTENV_KEY=changed > .env` + env('DOTENV_KEY')
+b := env('DOTENV_KEY')
+
+baz:
+  @echo $DOTENV_KEY
+",
+    )
+    .write("foo/.env", "DOTENV_KEY=original")
+    .arg("bar")
+    .stdout("original\noriginal original\n")
+    .run();
+}
+
+#[test]
+fn inherited_dotenv_settings_load_submodule_dotenv() {
+  Test::new()
+    .justfile(
+      "
+        set inherit-settings
+        set dotenv-load
+
+        mod foo
+      ",
+    )
+    .write(".env", "A=root-a\nB=root-b")
+    .write("foo/mod.just", "foo:\n @echo $A $B")
+    .write("foo/.env", "B=foo-b")
+    .args(["foo", "foo"])
+    .stdout("root-a foo-b\n")
+    .run();
+}
+
+#[test]
+fn submodule_dotenv_path_is_relative_to_submodule() {
+  Test::new()
+    .justfile("mod foo")
+    .write(
+      "foo/mod.just",
+      "set dotenv-path := 'config/.env'\nfoo:\n @echo $DOTENV_KEY",
+    )
+    .write("foo/config/.env", "DOTENV_KEY=foo")
+    .args(["foo", "foo"])
+    .stdout("foo\n")
+    .run();
+}
+
+#[test]
+fn submodule_dotenv_required() {
+  Test::new()
+    .justfile("mod foo")
+    .write("foo/mod.just", "set dotenv-required\nfoo:")
+    .args(["foo", "foo"])
+    .stderr("error: Dotenv file not found\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn submodule_dotenv_is_not_loaded_with_no_dotenv() {
+  Test::new()
+    .justfile("mod foo")
+    .write(
+      "foo/mod.just",
+      "set dotenv-load\nfoo:\n @echo ${DOTENV_KEY:-unset}",
+    )
+    .write("foo/.env", "DOTENV_KEY=foo")
+    .args(["--no-dotenv", "foo", "foo"])
+    .stdout("unset\n")
+    .run();
+}
+
 #[test]
 fn modules_may_specify_path() {
   Test::new()
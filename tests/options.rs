@@ -0,0 +1,492 @@
+use super::*;
+
+const JUSTFILE: &str = "
+  [arg('target', long, short='t')]
+  [arg('release', long, value='true')]
+  build target release='false':
+    @echo {{target}} {{release}}
+";
+
+#[test]
+fn long_option() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "--target", "x86"])
+    .stdout("x86 false\n")
+    .run();
+}
+
+#[test]
+fn long_option_with_equals() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "--target=x86"])
+    .stdout("x86 false\n")
+    .run();
+}
+
+#[test]
+fn short_option() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "-t", "arm"])
+    .stdout("arm false\n")
+    .run();
+}
+
+#[test]
+fn flag() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "--release", "-t", "arm"])
+    .stdout("arm true\n")
+    .run();
+}
+
+#[test]
+fn flag_without_default_is_empty() {
+  Test::new()
+    .justfile(
+      "
+      [arg('verbose', short='v', value='-v')]
+      build verbose:
+        @echo 'verbose: {{verbose}}'
+      ",
+    )
+    .arg("build")
+    .stdout("verbose: \n")
+    .run();
+}
+
+#[test]
+fn options_and_positional_arguments() {
+  Test::new()
+    .justfile(
+      "
+      [arg('mode', long)]
+      build target mode='debug' *flags='':
+        @echo {{target}} {{mode}} {{flags}}
+      ",
+    )
+    .args(["build", "x86", "a", "--mode", "release", "b"])
+    .stdout("x86 release a b\n")
+    .run();
+}
+
+#[test]
+fn arguments_after_options_start_next_recipe() {
+  Test::new()
+    .justfile(
+      "
+      [arg('target', long)]
+      build target='x86':
+        @echo build {{target}}
+
+      test:
+        @echo test
+      ",
+    )
+    .args(["build", "--target", "arm", "test"])
+    .stdout("build arm\ntest\n")
+    .run();
+}
+
+#[test]
+fn dependencies_pass_options_positionally() {
+  Test::new()
+    .justfile(
+      "
+      default: (build 'arm' 'true')
+
+      [arg('target', long)]
+      [arg('release', long, value='true')]
+      build target release='false':
+        @echo {{target}} {{release}}
+      ",
+    )
+    .stdout("arm true\n")
+    .run();
+}
+
+#[test]
+fn missing_option() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("build")
+    .stderr("error: Recipe `build` requires option `--target`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn missing_option_value() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "--target"])
+    .stderr("error: Option `--target` of recipe `build` requires a value\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn flag_with_value() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "-t", "x86", "--release=yes"])
+    .stderr("error: Flag `--release` of recipe `build` does not take a value\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn duplicate_option() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "-t", "x86", "--target", "arm"])
+    .stderr("error: Option `--target` passed to recipe `build` more than once\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_option_suggestion() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "--tagret", "x86"])
+    .stderr(
+      "
+      error: Recipe `build` does not have option `--tagret`
+      Did you mean `--target`?
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_option() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .args(["build", "--foo"])
+    .stderr("error: Recipe `build` does not have option `--foo`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dashes_are_positional_for_recipes_without_options() {
+  Test::new()
+    .justfile(
+      "
+      foo bar:
+        @echo {{bar}}
+      ",
+    )
+    .args(["foo", "--bar"])
+    .stdout("--bar\n")
+    .run();
+}
+
+#[test]
+fn undeclared_short_options_are_positional() {
+  Test::new()
+    .justfile(
+      "
+      [arg('target', long, short='t')]
+      build target offset:
+        @echo {{target}} {{offset}}
+      ",
+    )
+    .args(["build", "-t", "x86", "-1"])
+    .stdout("x86 -1\n")
+    .run();
+}
+
+#[test]
+fn positional_argument_count_excludes_options() {
+  Test::new()
+    .justfile(
+      "
+      [arg('target', long)]
+      build target mode:
+        @echo {{target}} {{mode}}
+      ",
+    )
+    .args(["build", "--target", "x86"])
+    .stderr(
+      "
+      error: Recipe `build` got 0 arguments but takes 1
+      usage:
+          just build --target target mode
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn options_are_listed() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .arg("--list")
+    .stdout(
+      "
+      Available recipes:
+          build --target target --release
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn short_only_options_are_listed() {
+  Test::new()
+    .justfile(
+      "
+      [arg('target', short='t')]
+      build target='x86':
+      ",
+    )
+    .arg("--list")
+    .stdout(
+      "
+      Available recipes:
+          build -t target='x86'
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn options_with_positional_arguments() {
+  Test::new()
+    .justfile(
+      "
+      [arg('target', long)]
+      [positional-arguments]
+      build target mode:
+        @echo $1 $2
+      ",
+    )
+    .args(["build", "release", "--target", "x86"])
+    .stdout("x86 release\n")
+    .run();
+}
+
+#[test]
+fn arg_attribute_is_dumped() {
+  Test::new()
+    .justfile(
+      "
+      [arg('target', long, short='t')]
+      build target:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+      [arg('target', long='target', short='t')]
+      build target:
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn undefined_parameter() {
+  Test::new()
+    .justfile(
+      "
+      [arg('foo', long)]
+      build target:
+      ",
+    )
+    .stderr(
+      "
+      error: Recipe `build` has `arg` attribute for undefined parameter `foo`
+       ——▶ justfile:2:1
+        │
+      2 │ build target:
+        │ ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn duplicate_options() {
+  Test::new()
+    .justfile(
+      "
+      [arg('a', short='x')]
+      [arg('b', short='x')]
+      build a b:
+      ",
+    )
+    .stderr(
+      "
+      error: Recipe `build` has duplicate option `-x`
+       ——▶ justfile:3:1
+        │
+      3 │ build a b:
+        │ ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn variadic_option() {
+  Test::new()
+    .justfile(
+      "
+      [arg('a', long)]
+      build +a:
+      ",
+    )
+    .stderr(
+      "
+      error: Variadic parameter `a` cannot be an option
+       ——▶ justfile:2:1
+        │
+      2 │ build +a:
+        │ ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_short_option() {
+  Test::new()
+    .justfile(
+      "
+      [arg('a', short='ab')]
+      build a:
+      ",
+    )
+    .stderr(
+      "
+      error: Short option `ab` must be a single character other than `-` or whitespace
+       ——▶ justfile:1:2
+        │
+      1 │ [arg('a', short='ab')]
+        │  ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_long_option() {
+  Test::new()
+    .justfile(
+      "
+      [arg('a', long='--a')]
+      build a:
+      ",
+    )
+    .stderr(
+      "
+      error: Long option `--a` may not be empty, start with `-`, or contain `=` or whitespace
+       ——▶ justfile:1:2
+        │
+      1 │ [arg('a', long='--a')]
+        │  ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_keyword() {
+  Test::new()
+    .justfile(
+      "
+      [arg('a', foo='bar')]
+      build a:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `arg` has unknown keyword `foo`
+       ——▶ justfile:1:11
+        │
+      1 │ [arg('a', foo='bar')]
+        │           ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn keywords_are_only_accepted_by_arg() {
+  Test::new()
+    .justfile(
+      "
+      [group('a', long)]
+      build:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `group` has unknown keyword `long`
+       ——▶ justfile:1:13
+        │
+      1 │ [group('a', long)]
+        │             ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn keyword_missing_value() {
+  Test::new()
+    .justfile(
+      "
+      [arg('a', short)]
+      build a:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `arg` keyword `short` requires a value
+       ——▶ justfile:1:11
+        │
+      1 │ [arg('a', short)]
+        │           ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn value_without_option() {
+  Test::new()
+    .justfile(
+      "
+      [arg('a', value='b')]
+      build a:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `arg` for parameter `a` has `value` but no `long` or `short` option
+       ——▶ justfile:1:2
+        │
+      1 │ [arg('a', value='b')]
+        │  ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
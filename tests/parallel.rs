@@ -0,0 +1,219 @@
+use {
+  super::*,
+  std::time::{Duration, Instant},
+};
+
+#[test]
+fn dependencies_run_in_parallel() {
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: a b
+
+      a:
+        @touch a; for i in $(seq 100); do [ -e b ] && exit 0; sleep 0.1; done; exit 1
+
+      b:
+        @touch b; for i in $(seq 100); do [ -e a ] && exit 0; sleep 0.1; done; exit 1
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn dependencies_without_attribute_run_in_order() {
+  Test::new()
+    .justfile(
+      "
+      foo: a b c
+
+      a:
+        @sleep 0.2; echo a
+
+      b:
+        @sleep 0.1; echo b
+
+      c:
+        @echo c
+      ",
+    )
+    .stdout("a\nb\nc\n")
+    .run();
+}
+
+#[test]
+fn single_job_runs_dependencies_in_order() {
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: a b c
+
+      a:
+        @sleep 0.2; echo a
+
+      b:
+        @sleep 0.1; echo b
+
+      c:
+        @echo c
+      ",
+    )
+    .args(["--jobs", "1"])
+    .stdout("a\nb\nc\n")
+    .run();
+}
+
+#[test]
+fn jobs_must_be_positive() {
+  Test::new()
+    .args(["--jobs", "0"])
+    .stderr_regex("error: invalid value '0' for '--jobs <JOBS>'.*")
+    .status(2)
+    .run();
+}
+
+#[test]
+fn shared_dependencies_run_once() {
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: a b (c 'x') (c 'x')
+
+      a: setup
+      b: setup
+
+      c x: setup
+        @echo c {{x}}
+
+      setup:
+        @echo setup
+      ",
+    )
+    .stdout("setup\nc x\n")
+    .run();
+}
+
+#[test]
+fn failure_cancels_remaining_dependencies() {
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: a b
+        @echo foo
+
+      a:
+        @exit 1
+
+      b:
+        @echo b
+      ",
+    )
+    .args(["--jobs", "1"])
+    .stderr("error: Recipe `a` failed on line 6 with exit code 1\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn failure_terminates_running_dependencies() {
+  let start = Instant::now();
+
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: a b
+
+      a:
+        @sleep 1; exit 1
+
+      b:
+        @sleep 20; echo b
+      ",
+    )
+    .stderr("error: Recipe `a` failed on line 5 with exit code 1\n")
+    .status(EXIT_FAILURE)
+    .run();
+
+  assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+#[cfg(unix)]
+fn nested_failure_terminates_outer_dependencies() {
+  let start = Instant::now();
+
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: a b
+
+      [parallel]
+      a: c d
+
+      b:
+        @sleep 20; echo b
+
+      c:
+        @sleep 20; echo c
+
+      d:
+        @sleep 1; exit 1
+      ",
+    )
+    .stderr("error: Recipe `d` failed on line 14 with exit code 1\n")
+    .status(EXIT_FAILURE)
+    .run();
+
+  assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn subsequents_run_in_parallel() {
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: && a b
+
+      a:
+        @touch a; for i in $(seq 100); do [ -e b ] && exit 0; sleep 0.1; done; exit 1
+
+      b:
+        @touch b; for i in $(seq 100); do [ -e a ] && exit 0; sleep 0.1; done; exit 1
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn attribute_is_dumped() {
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      foo: a b
+
+      a:
+      b:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+      [parallel]
+      foo: a b
+
+      a:
+
+      b:
+      ",
+    )
+    .run();
+}
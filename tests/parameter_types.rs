@@ -0,0 +1,488 @@
+use super::*;
+
+#[test]
+fn choices() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', choices='dev staging prod')]
+      deploy env:
+        @echo {{env}}
+      ",
+    )
+    .args(["deploy", "staging"])
+    .stdout("staging\n")
+    .run();
+}
+
+#[test]
+fn invalid_choice() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', choices='dev staging prod')]
+      deploy env:
+        @echo {{env}}
+      ",
+    )
+    .args(["deploy", "qa"])
+    .stderr(
+      "error: Argument `qa` for parameter `env` of recipe `deploy` is not one of `dev`, `staging`, `prod`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn integer() {
+  Test::new()
+    .justfile(
+      "
+      [arg('count', type='integer')]
+      scale count:
+        @echo {{count}}
+      ",
+    )
+    .args(["scale", "-3"])
+    .stdout("-3\n")
+    .run();
+}
+
+#[test]
+fn invalid_integer() {
+  Test::new()
+    .justfile(
+      "
+      [arg('count', type='integer')]
+      scale count:
+      ",
+    )
+    .args(["scale", "three"])
+    .stderr("error: Argument `three` for parameter `count` of recipe `scale` is not an integer\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn boolean() {
+  Test::new()
+    .justfile(
+      "
+      [arg('verbose', type='boolean')]
+      build verbose='false':
+        @echo {{verbose}}
+      ",
+    )
+    .args(["build", "true"])
+    .stdout("true\n")
+    .run();
+}
+
+#[test]
+fn invalid_boolean() {
+  Test::new()
+    .justfile(
+      "
+      [arg('verbose', type='boolean')]
+      build verbose='false':
+      ",
+    )
+    .args(["build", "yes"])
+    .stderr(
+      "error: Argument `yes` for parameter `verbose` of recipe `build` is not `true` or `false`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn path() {
+  Test::new()
+    .justfile(
+      "
+      [arg('file', type='path')]
+      show file:
+        @cat {{file}}
+      ",
+    )
+    .write("foo.txt", "bar")
+    .args(["show", "foo.txt"])
+    .stdout("bar")
+    .run();
+}
+
+#[test]
+fn path_is_relative_to_invocation_directory() {
+  Test::new()
+    .justfile(
+      "
+      [arg('file', type='path')]
+      show file:
+        @echo {{file}}
+      ",
+    )
+    .write("sub/foo.txt", "bar")
+    .current_dir("sub")
+    .args(["show", "foo.txt"])
+    .stdout("foo.txt\n")
+    .run();
+}
+
+#[test]
+fn missing_path() {
+  Test::new()
+    .justfile(
+      "
+      [arg('file', type='path')]
+      show file:
+      ",
+    )
+    .args(["show", "foo.txt"])
+    .stderr(
+      "error: Argument `foo.txt` for parameter `file` of recipe `show` is not an existing path\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn pattern() {
+  Test::new()
+    .justfile(
+      r"
+      [arg('version', pattern='\d+\.\d+\.\d+')]
+      release version:
+        @echo {{version}}
+      ",
+    )
+    .args(["release", "1.2.3"])
+    .stdout("1.2.3\n")
+    .run();
+}
+
+#[test]
+fn pattern_must_match_entire_argument() {
+  Test::new()
+    .justfile(
+      r"
+      [arg('version', pattern='\d+\.\d+\.\d+')]
+      release version:
+      ",
+    )
+    .args(["release", "v1.2.3"])
+    .stderr(
+      r"
+        error: Argument `v1.2.3` for parameter `version` of recipe `release` is not a match for `\d+\.\d+\.\d+`
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_pattern() {
+  Test::new()
+    .justfile(
+      "
+      [arg('version', pattern='(')]
+      release version:
+      ",
+    )
+    .stderr_regex("error: Attribute `arg` for parameter `version` has invalid pattern `\\(`: .*")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_type() {
+  Test::new()
+    .justfile(
+      "
+      [arg('count', type='float')]
+      scale count:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `arg` for parameter `count` has unknown type `float`, expected `boolean`, `integer`, or `path`
+       ——▶ justfile:1:2
+        │
+      1 │ [arg('count', type='float')]
+        │  ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn empty_choices() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', choices=' ')]
+      deploy env:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `arg` for parameter `env` has empty `choices`
+       ——▶ justfile:1:2
+        │
+      1 │ [arg('env', choices=' ')]
+        │  ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn every_variadic_argument_is_checked() {
+  Test::new()
+    .justfile(
+      "
+      [arg('ports', type='integer')]
+      open +ports:
+      ",
+    )
+    .args(["open", "80", "http"])
+    .stderr("error: Argument `http` for parameter `ports` of recipe `open` is not an integer\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn options_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', long, choices='dev prod')]
+      deploy env='dev':
+      ",
+    )
+    .args(["deploy", "--env", "qa"])
+    .stderr(
+      "error: Argument `qa` for parameter `env` of recipe `deploy` is not one of `dev`, `prod`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn arguments_are_checked_before_any_recipe_runs() {
+  Test::new()
+    .justfile(
+      "
+      first:
+        @echo first
+
+      [arg('count', type='integer')]
+      second count:
+      ",
+    )
+    .args(["first", "second", "x"])
+    .stderr("error: Argument `x` for parameter `count` of recipe `second` is not an integer\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn dependency_arguments_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      all: (deploy 'qa')
+
+      [arg('env', choices='dev prod')]
+      deploy env:
+      ",
+    )
+    .stderr(
+      "error: Argument `qa` for parameter `env` of recipe `deploy` is not one of `dev`, `prod`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn defaults_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', choices='dev prod')]
+      deploy env='qa':
+      ",
+    )
+    .stderr(
+      "
+      error: Default `qa` for parameter `env` is not one of `dev`, `prod`
+       ——▶ justfile:2:1
+        │
+      2 │ deploy env='qa':
+        │ ^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn flag_values_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      [arg('count', long, value='many', type='integer')]
+      repeat count='1':
+      ",
+    )
+    .stderr(
+      "
+      error: Flag value `many` for parameter `count` is not an integer
+       ——▶ justfile:2:1
+        │
+      2 │ repeat count='1':
+        │ ^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn evaluated_defaults_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      env := 'q' + 'a'
+
+      [arg('env', choices='dev prod')]
+      deploy env=env:
+        echo {{env}}
+      ",
+    )
+    .stderr(
+      "error: Default `qa` for parameter `env` of recipe `deploy` is not one of `dev`, `prod`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn backtick_defaults_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      [arg('count', pattern='[0-9]+')]
+      repeat count=`echo many`:
+        echo {{count}}
+      ",
+    )
+    .stderr(
+      "error: Default `many` for parameter `count` of recipe `repeat` is not a match for `[0-9]+`\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn variadic_defaults_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      [arg('counts', type='integer')]
+      sum *counts=(['1', 'x']):
+      ",
+    )
+    .stderr("error: Default `x` for parameter `counts` of recipe `sum` is not an integer\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn path_defaults_are_checked() {
+  Test::new()
+    .justfile(
+      "
+      [arg('file', type='path')]
+      show file='missing.txt':
+        @echo {{file}}
+      ",
+    )
+    .stderr(
+      "error: Default `missing.txt` for parameter `file` of recipe `show` is not an existing path\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn existing_path_defaults_are_accepted() {
+  Test::new()
+    .justfile(
+      "
+      [arg('file', type='path')]
+      show file='foo.txt':
+        @cat {{file}}
+      ",
+    )
+    .write("foo.txt", "bar")
+    .stdout("bar")
+    .run();
+}
+
+#[test]
+fn choices_are_shown_in_usage() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', choices='dev prod', help='Environment')]
+      deploy env:
+      ",
+    )
+    .args(["--usage", "deploy"])
+    .stdout(
+      "
+      Usage: just deploy <env>
+
+      Arguments:
+        <env>  Environment [possible values: dev, prod]
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn argument_choices() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', choices='dev prod')]
+      [arg('force', long, value='true', choices='true false')]
+      [arg('region', choices='us eu')]
+      deploy env tag region force='false':
+      ",
+    )
+    .args(["--argument-choices", "deploy"])
+    .stdout("dev prod\n\nus eu\n")
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+      [arg('env', choices='dev prod', pattern='[a-z]+', type='path')]
+      deploy env:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+      [arg('env', choices='dev prod', pattern='[a-z]+', type='path')]
+      deploy env:
+      ",
+    )
+    .run();
+}
@@ -0,0 +1,329 @@
+use super::*;
+
+#[test]
+fn dependencies_run_before_recipe() {
+  Test::new()
+    .justfile(
+      "
+      a: b c
+        echo a
+      b:
+        echo b
+      c:
+        echo c
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout(
+      "
+      1. b  # dependency of a
+      2. c  # dependency of a
+      3. a
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn subsequents_run_after_recipe() {
+  Test::new()
+    .justfile(
+      "
+      a: b && c
+      b:
+      c:
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout(
+      "
+      1. b  # dependency of a
+      2. a
+      3. c  # subsequent of a
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn argument_bindings() {
+  Test::new()
+    .justfile(
+      "
+      build target *flags:
+      ",
+    )
+    .args(["--plan", "build", "arm", "-O", "-g"])
+    .stdout(
+      r#"
+      1. build target="arm" flags="-O -g"
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn default_arguments_are_bound() {
+  Test::new()
+    .justfile(
+      "
+      build target=('x' + '86'):
+      ",
+    )
+    .args(["--plan", "build"])
+    .stdout("1. build target=\"x86\"\n")
+    .run();
+}
+
+#[test]
+fn dependency_arguments_are_evaluated() {
+  Test::new()
+    .justfile(
+      "
+      a x: (b x + '-suffix')
+      b y:
+      ",
+    )
+    .args(["--plan", "a", "foo"])
+    .stdout(
+      r#"
+      1. b y="foo-suffix"  # dependency of a
+      2. a x="foo"
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn recipes_that_already_ran_are_skipped() {
+  Test::new()
+    .justfile(
+      "
+      a: c
+      b: c
+      c:
+      ",
+    )
+    .args(["--plan", "a", "b"])
+    .stdout(
+      "
+      1. c  # dependency of a
+      2. a
+      -  c  # dependency of b, skipped, already run
+      3. b
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn recipes_with_different_arguments_are_not_skipped() {
+  Test::new()
+    .justfile(
+      "
+      a: (c '1') (c '2') (c '1')
+      c x:
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout(
+      r#"
+      1. c x="1"  # dependency of a
+      2. c x="2"  # dependency of a
+      -  c x="1"  # dependency of a, skipped, already run
+      3. a
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn parallel_dependencies() {
+  Test::new()
+    .justfile(
+      "
+      [parallel]
+      a: b c
+      b:
+      c:
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout(
+      "
+      1. b  # parallel dependency of a
+      2. c  # parallel dependency of a
+      3. a
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn no_deps() {
+  Test::new()
+    .justfile(
+      "
+      a: b && c
+      b:
+      c:
+      ",
+    )
+    .args(["--plan", "--no-deps", "a"])
+    .stdout("1. a\n")
+    .run();
+}
+
+#[test]
+fn default_recipe() {
+  Test::new()
+    .justfile(
+      "
+      a: b
+      b:
+      ",
+    )
+    .arg("--plan")
+    .stdout(
+      "
+      1. b  # dependency of a
+      2. a
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn modules() {
+  Test::new()
+    .write("foo.just", "bar: baz\nbaz:")
+    .justfile("mod foo")
+    .args(["--plan", "foo", "bar"])
+    .stdout(
+      "
+      1. foo::baz  # dependency of foo::bar
+      2. foo::bar
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn step_numbers_are_aligned() {
+  Test::new()
+    .justfile(
+      "
+      a: b c d e f g h i j
+      b:
+      c:
+      d:
+      e:
+      f:
+      g:
+      h:
+      i:
+      j:
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout(
+      "
+      1.  b  # dependency of a
+      2.  c  # dependency of a
+      3.  d  # dependency of a
+      4.  e  # dependency of a
+      5.  f  # dependency of a
+      6.  g  # dependency of a
+      7.  h  # dependency of a
+      8.  i  # dependency of a
+      9.  j  # dependency of a
+      10. a
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn backticks_are_not_run() {
+  Test::new()
+    .justfile(
+      "
+      x := `touch assignment`
+
+      a y=`touch default`: (b `touch argument`)
+        touch recipe
+      b z:
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout(
+      r#"
+      1. b z="`touch argument`"  # dependency of a
+      2. a y="`touch default`"
+      "#,
+    )
+    .run();
+}
+
+#[test]
+fn shell_commands_are_not_run() {
+  let tempdir = Test::new()
+    .justfile(
+      "
+      x := shell('touch assignment')
+
+      a y=shell('touch $1', 'default'): (b shell('touch argument'))
+      b z:
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout(
+      r#"
+      1. b z="shell('touch argument')"       # dependency of a
+      2. a y="shell('touch $1', 'default')"
+      "#,
+    )
+    .run()
+    .tempdir;
+
+  for path in ["assignment", "default", "argument"] {
+    assert!(!tempdir.path().join(path).exists());
+  }
+}
+
+#[test]
+fn recipes_are_not_run() {
+  let tempdir = Test::new()
+    .justfile(
+      "
+      a:
+        touch ran
+      ",
+    )
+    .args(["--plan", "a"])
+    .stdout("1. a\n")
+    .run()
+    .tempdir;
+
+  assert!(!tempdir.path().join("ran").exists());
+}
+
+#[test]
+fn conflicts_with_dry_run() {
+  Test::new()
+    .justfile("a:")
+    .args(["--plan", "--dry-run", "a"])
+    .stderr_regex("error: the argument '--plan' cannot be used with '--dry-run'.*")
+    .status(2)
+    .run();
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .justfile("a:")
+    .args(["--plan", "xyz"])
+    .stderr("error: Justfile does not contain recipe `xyz`\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,308 @@
+use {
+  super::*,
+  std::time::{Duration, Instant},
+};
+
+#[test]
+fn retries_until_success() {
+  Test::new()
+    .justfile(
+      "
+      [retry(3)]
+      flaky:
+        @echo attempt $JUST_ATTEMPT
+        @test $JUST_ATTEMPT -ge 3
+      ",
+    )
+    .stdout(
+      "
+      attempt 1
+      attempt 2
+      attempt 3
+      ",
+    )
+    .stderr(
+      "
+      ===> Recipe `flaky` failed on line 4 with exit code 1, retrying (attempt 2 of 4)
+      ===> Recipe `flaky` failed on line 4 with exit code 1, retrying (attempt 3 of 4)
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn fails_after_last_retry() {
+  Test::new()
+    .justfile(
+      "
+      [retry(2)]
+      broken:
+        @exit 7
+      ",
+    )
+    .stderr(
+      "
+      ===> Recipe `broken` failed on line 3 with exit code 7, retrying (attempt 2 of 3)
+      ===> Recipe `broken` failed on line 3 with exit code 7, retrying (attempt 3 of 3)
+      error: Recipe `broken` failed on line 3 with exit code 7
+      ",
+    )
+    .status(7)
+    .run();
+}
+
+#[test]
+fn backoff() {
+  let start = Instant::now();
+
+  Test::new()
+    .justfile(
+      "
+      [retry(2, '250ms')]
+      broken:
+        @exit 1
+      ",
+    )
+    .stderr(
+      "
+      ===> Recipe `broken` failed on line 3 with exit code 1, retrying in 250ms (attempt 2 of 3)
+      ===> Recipe `broken` failed on line 3 with exit code 1, retrying in 250ms (attempt 3 of 3)
+      error: Recipe `broken` failed on line 3 with exit code 1
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+
+  assert!(start.elapsed() >= Duration::from_millis(500));
+}
+
+#[test]
+fn whole_recipe_is_retried() {
+  Test::new()
+    .justfile(
+      "
+      [retry(1)]
+      flaky:
+        @echo first line
+        @test $JUST_ATTEMPT -ge 2
+      ",
+    )
+    .stdout(
+      "
+      first line
+      first line
+      ",
+    )
+    .stderr("===> Recipe `flaky` failed on line 4 with exit code 1, retrying (attempt 2 of 2)\n")
+    .run();
+}
+
+#[test]
+fn script() {
+  Test::new()
+    .justfile(
+      "
+      [retry(1)]
+      flaky:
+        #!/bin/sh
+        echo attempt $JUST_ATTEMPT
+        test $JUST_ATTEMPT -ge 2
+      ",
+    )
+    .stdout(
+      "
+      attempt 1
+      attempt 2
+      ",
+    )
+    .stderr("===> Recipe `flaky` failed with exit code 1, retrying (attempt 2 of 2)\n")
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn signals_are_not_retried() {
+  Test::new()
+    .justfile(
+      "
+      [retry(3)]
+      killed:
+        @kill -TERM $$
+      ",
+    )
+    .stderr("error: Recipe `killed` was terminated on line 3 by signal 15\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg(unix)]
+fn timeouts_are_not_retried() {
+  Test::new()
+    .justfile(
+      "
+      [retry(3)]
+      [timeout('250ms')]
+      slow:
+        @sleep 10
+      ",
+    )
+    .stderr_regex("error: Recipe `slow` timed out on line 4 after [^(]+ \\(limit 250ms\\)\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn quiet_suppresses_retry_messages() {
+  Test::new()
+    .justfile(
+      "
+      [retry(1)]
+      broken:
+        @exit 1
+      ",
+    )
+    .arg("--quiet")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn attempt_is_only_set_for_retried_recipes() {
+  Test::new()
+    .justfile(
+      "
+      foo:
+        @echo attempt ${JUST_ATTEMPT:-unset}
+      ",
+    )
+    .stdout("attempt unset\n")
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+      [retry(3)]
+      foo:
+
+      [retry(3, '2s')]
+      bar:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+      [retry(3)]
+      foo:
+
+      [retry(3, '2s')]
+      bar:
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn count_must_be_positive() {
+  Test::new()
+    .justfile(
+      "
+      [retry(0)]
+      foo:
+      ",
+    )
+    .stderr(
+      "
+      error: Retry count `0` is not a positive integer
+       ——▶ justfile:1:2
+        │
+      1 │ [retry(0)]
+        │  ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn count_must_be_integer() {
+  Test::new()
+    .justfile(
+      "
+      [retry('lots')]
+      foo:
+      ",
+    )
+    .stderr(
+      "
+      error: Retry count `lots` is not a positive integer
+       ——▶ justfile:1:2
+        │
+      1 │ [retry('lots')]
+        │  ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_backoff() {
+  Test::new()
+    .justfile(
+      "
+      [retry(3, 'later')]
+      foo:
+      ",
+    )
+    .stderr_regex("error: Attribute `retry` has invalid duration `later`: .*")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn too_many_arguments() {
+  Test::new()
+    .justfile(
+      "
+      [retry(3, '1s', '2s')]
+      foo:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `retry` got 3 arguments but takes at most 2 arguments
+       ——▶ justfile:1:2
+        │
+      1 │ [retry(3, '1s', '2s')]
+        │  ^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn integers_are_not_names() {
+  Test::new()
+    .justfile(
+      "
+      [retry(3foo)]
+      foo:
+      ",
+    )
+    .stderr(
+      "
+      error: Unknown start of token:
+       ——▶ justfile:1:8
+        │
+      1 │ [retry(3foo)]
+        │        ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
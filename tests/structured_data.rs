@@ -0,0 +1,343 @@
+use super::*;
+
+const PACKAGE_JSON: &str = r#"
+{
+  "name": "app",
+  "version": "1.2.3",
+  "private": true,
+  "workspaces": ["core", "cli"],
+  "engines": { "node": ">=18" }
+}
+"#;
+
+const CARGO_TOML: &str = r#"
+[package]
+name = "app"
+version = "0.4.0"
+
+[[bin]]
+name = "app-cli"
+
+[dependencies]
+serde = { version = "1.0", features = ["derive"] }
+"#;
+
+const COMPOSE_YAML: &str = "
+services:
+  web:
+    image: nginx:1.25
+    ports:
+      - 8080
+      - 8443
+";
+
+#[test]
+fn json_get_file() {
+  Test::new()
+    .justfile("version := json_get_file('package.json', 'version')")
+    .write("package.json", PACKAGE_JSON)
+    .args(["--evaluate", "version"])
+    .stdout("1.2.3")
+    .run();
+}
+
+#[test]
+fn json_get_file_nested() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo '{{json_get_file('package.json', 'engines.node')}}' {{json_get_file('package.json', 'workspaces[1]')}}
+      ",
+    )
+    .write("package.json", PACKAGE_JSON)
+    .stdout(">=18 cli\n")
+    .run();
+}
+
+#[test]
+fn json_get_file_non_string_values_are_json() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo '{{json_get_file('package.json', 'private')}} {{json_get_file('package.json', 'workspaces')}}'
+      ",
+    )
+    .write("package.json", PACKAGE_JSON)
+    .stdout("true [\"core\",\"cli\"]\n")
+    .run();
+}
+
+#[test]
+fn json_get_from_string() {
+  Test::new()
+    .justfile(r#"foo := json_get('{"a": [{"b": "c"}]}', 'a[0].b')"#)
+    .args(["--evaluate", "foo"])
+    .stdout("c")
+    .run();
+}
+
+#[test]
+fn json_get_file_is_relative_to_working_directory() {
+  Test::new()
+    .justfile("foo := json_get_file('web/package.json', 'name')")
+    .write("web/package.json", PACKAGE_JSON)
+    .current_dir("web")
+    .args(["--evaluate", "foo"])
+    .stdout("app")
+    .run();
+}
+
+#[test]
+fn json_get_missing_key() {
+  Test::new()
+    .justfile("foo := json_get_file('package.json', 'engines.npm')")
+    .write("package.json", PACKAGE_JSON)
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `json_get_file` failed: Key `npm` of key path `engines.npm` not found in `package.json`
+         ——▶ justfile:1:8
+          │
+        1 │ foo := json_get_file('package.json', 'engines.npm')
+          │        ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn json_get_index_out_of_range() {
+  Test::new()
+    .justfile("foo := json_get_file('package.json', 'workspaces[2]')")
+    .write("package.json", PACKAGE_JSON)
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `json_get_file` failed: Index `2` of key path `workspaces[2]` not found in `package.json`
+         ——▶ justfile:1:8
+          │
+        1 │ foo := json_get_file('package.json', 'workspaces[2]')
+          │        ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn json_get_wrong_type() {
+  Test::new()
+    .justfile("foo := json_get_file('package.json', 'version.major')")
+    .write("package.json", PACKAGE_JSON)
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `json_get_file` failed: Key `major` of key path `version.major` not found in `package.json`: `version` is a string
+         ——▶ justfile:1:8
+          │
+        1 │ foo := json_get_file('package.json', 'version.major')
+          │        ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn json_get_invalid_key_path() {
+  Test::new()
+    .justfile("foo := json_get_file('package.json', 'engines..node')")
+    .write("package.json", PACKAGE_JSON)
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `json_get_file` failed: Invalid key path `engines..node`
+         ——▶ justfile:1:8
+          │
+        1 │ foo := json_get_file('package.json', 'engines..node')
+          │        ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn json_get_file_missing() {
+  Test::new()
+    .justfile("foo := json_get_file('package.jsn', 'version')")
+    .write("package.json", r#"{"version": "1.0.0"}"#)
+    .args(["--evaluate", "foo"])
+    .stderr_regex(
+      "error: Call to function `json_get_file` failed: I/O error reading `package.jsn`: .*",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn json_get_invalid_json() {
+  Test::new()
+    .justfile("foo := json_get_file('package.json', 'name')")
+    .write("package.json", "{\n  name: 'app'\n}")
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `json_get_file` failed: Failed to parse JSON in `package.json`: key must be a string at line 2 column 3
+         ——▶ justfile:1:8
+          │
+        1 │ foo := json_get_file('package.json', 'name')
+          │        ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn toml_get_file() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{toml_get_file('Cargo.toml', 'package.version')}} {{toml_get_file('Cargo.toml', 'bin[0].name')}}
+      ",
+    )
+    .write("Cargo.toml", CARGO_TOML)
+    .stdout("0.4.0 app-cli\n")
+    .run();
+}
+
+#[test]
+fn toml_get_file_inline_table() {
+  Test::new()
+    .justfile("foo := toml_get_file('Cargo.toml', 'dependencies.serde.features[0]')")
+    .write("Cargo.toml", CARGO_TOML)
+    .args(["--evaluate", "foo"])
+    .stdout("derive")
+    .run();
+}
+
+#[test]
+fn toml_get_from_string() {
+  Test::new()
+    .justfile("foo := toml_get('a = { b = 1970-01-01 }', 'a.b')")
+    .args(["--evaluate", "foo"])
+    .stdout("1970-01-01")
+    .run();
+}
+
+#[test]
+fn toml_get_missing_key() {
+  Test::new()
+    .justfile("foo := toml_get_file('Cargo.toml', 'package.edition')")
+    .write("Cargo.toml", CARGO_TOML)
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `toml_get_file` failed: Key `edition` of key path `package.edition` not found in `Cargo.toml`
+         ——▶ justfile:1:8
+          │
+        1 │ foo := toml_get_file('Cargo.toml', 'package.edition')
+          │        ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn toml_get_invalid_toml() {
+  Test::new()
+    .justfile("foo := toml_get_file('Cargo.toml', 'package.name')")
+    .write("Cargo.toml", "[package]\nname = app\n")
+    .args(["--evaluate", "foo"])
+    .stderr_regex(
+      "error: Call to function `toml_get_file` failed: Failed to parse TOML in `Cargo.toml`: .* at line 2 column 8\n.*",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn yaml_get_file() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{yaml_get_file('compose.yaml', 'services.web.image')}} {{yaml_get_file('compose.yaml', 'services.web.ports[1]')}}
+      ",
+    )
+    .write("compose.yaml", COMPOSE_YAML)
+    .stdout("nginx:1.25 8443\n")
+    .run();
+}
+
+#[test]
+fn yaml_get_from_string() {
+  Test::new()
+    .justfile("foo := yaml_get('[a, {b: c}]', '[1].b')")
+    .args(["--evaluate", "foo"])
+    .stdout("c")
+    .run();
+}
+
+#[test]
+fn yaml_get_wrong_type() {
+  Test::new()
+    .justfile("foo := yaml_get_file('compose.yaml', 'services.web.ports.http')")
+    .write("compose.yaml", COMPOSE_YAML)
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `yaml_get_file` failed: Key `http` of key path `services.web.ports.http` not found in `compose.yaml`: `services.web.ports` is an array
+         ——▶ justfile:1:8
+          │
+        1 │ foo := yaml_get_file('compose.yaml', 'services.web.ports.http')
+          │        ^^^^^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn json_get_file_path_with_brackets() {
+  Test::new()
+    .justfile("foo := json_get_file('data[1].json', 'name')")
+    .write("data[1].json", PACKAGE_JSON)
+    .args(["--evaluate", "foo"])
+    .stdout("app")
+    .run();
+}
+
+#[test]
+fn yaml_get_scalar_document() {
+  Test::new()
+    .justfile("foo := yaml_get('a: b', 'a')")
+    .args(["--evaluate", "foo"])
+    .stdout("b")
+    .run();
+}
+
+#[test]
+fn json_get_does_not_read_files() {
+  Test::new()
+    .justfile("foo := json_get('package.json', 'name')")
+    .write("package.json", PACKAGE_JSON)
+    .args(["--evaluate", "foo"])
+    .stderr(
+      "
+        error: Call to function `json_get` failed: Failed to parse JSON: expected value at line 1 column 1
+         ——▶ justfile:1:8
+          │
+        1 │ foo := json_get('package.json', 'name')
+          │        ^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
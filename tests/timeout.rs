@@ -0,0 +1,301 @@
+use {
+  super::*,
+  std::{
+    thread,
+    time::{Duration, Instant},
+  },
+};
+
+#[test]
+fn attribute() {
+  Test::new()
+    .justfile(
+      "
+      [timeout('500ms')]
+      slow:
+        sleep 10
+      ",
+    )
+    .stderr_regex(
+      "sleep 10\nerror: Recipe `slow` timed out on line 3 after [^(]+ \\(limit 500ms\\)\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn flag() {
+  Test::new()
+    .justfile(
+      "
+      slow:
+        sleep 10
+      ",
+    )
+    .args(["--timeout", "500ms"])
+    .stderr_regex(
+      "sleep 10\nerror: Recipe `slow` timed out on line 2 after [^(]+ \\(limit 500ms\\)\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn environment_variable() {
+  Test::new()
+    .justfile(
+      "
+      slow:
+        sleep 10
+      ",
+    )
+    .env("JUST_TIMEOUT", "500ms")
+    .stderr_regex(
+      "sleep 10\nerror: Recipe `slow` timed out on line 2 after [^(]+ \\(limit 500ms\\)\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn attribute_overrides_flag() {
+  Test::new()
+    .justfile(
+      "
+      [timeout('1m')]
+      slow:
+        sleep 1
+      ",
+    )
+    .args(["--timeout", "500ms"])
+    .stderr("sleep 1\n")
+    .run();
+}
+
+#[test]
+fn recipes_that_finish_in_time_succeed() {
+  Test::new()
+    .justfile(
+      "
+      [timeout('1m')]
+      fast:
+        echo done
+      ",
+    )
+    .stdout("done\n")
+    .stderr("echo done\n")
+    .run();
+}
+
+#[test]
+fn timeout_applies_to_whole_recipe() {
+  Test::new()
+    .justfile(
+      "
+      [timeout('1s')]
+      slow:
+        sleep 0.7
+        sleep 0.7
+      ",
+    )
+    .stderr_regex(
+      "sleep 0.7\nsleep 0.7\nerror: Recipe `slow` timed out on line 4 after [^(]+ \\(limit 1s\\)\n",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn timeout_includes_retries() {
+  let start = Instant::now();
+
+  Test::new()
+    .justfile(
+      "
+      [retry(3, '10s')]
+      [timeout('1s')]
+      flaky:
+        @sleep 0.6; exit 1
+      ",
+    )
+    .stderr_regex(
+      "===> Recipe `flaky` failed on line 4 with exit code 1, retrying in [^(]+ \\(attempt 2 of 4\\)
+error: Recipe `flaky` timed out on line 4 after [^(]+ \\(limit 1s\\)
+",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+
+  assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn timeout_does_not_include_dependencies() {
+  Test::new()
+    .justfile(
+      "
+      [timeout('500ms')]
+      a: b
+        true
+
+      b:
+        sleep 1
+      ",
+    )
+    .stderr("sleep 1\ntrue\n")
+    .run();
+}
+
+#[test]
+fn script() {
+  Test::new()
+    .justfile(
+      "
+      [timeout('500ms')]
+      slow:
+        #!/bin/sh
+        sleep 10
+      ",
+    )
+    .stderr_regex("error: Recipe `slow` timed out after [^(]+ \\(limit 500ms\\)\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn processes_that_ignore_termination_are_killed() {
+  let start = Instant::now();
+
+  Test::new()
+    .justfile(
+      "
+      [timeout('500ms')]
+      stubborn:
+        #!/bin/sh
+        trap 'echo terminated' TERM
+        sleep 10 & wait
+        sleep 10 & wait
+      ",
+    )
+    .stdout("terminated\n")
+    .stderr_regex("error: Recipe `stubborn` timed out after [^(]+ \\(limit 500ms\\)\n")
+    .status(EXIT_FAILURE)
+    .run();
+
+  assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn descendants_are_terminated() {
+  let tempdir = Test::new()
+    .justfile(
+      "
+      [timeout('500ms')]
+      slow:
+        @(sleep 1 && touch late) & sleep 10
+      ",
+    )
+    .stderr_regex("error: Recipe `slow` timed out on line 3 after [^(]+ \\(limit 500ms\\)\n")
+    .status(EXIT_FAILURE)
+    .run()
+    .tempdir;
+
+  thread::sleep(Duration::from_secs(2));
+
+  assert!(!tempdir.path().join("late").exists());
+}
+
+#[test]
+fn interrupts_are_forwarded() {
+  let tempdir = tempdir();
+
+  fs::write(
+    tempdir.path().join("justfile"),
+    "[timeout('1m')]\nslow:\n  @sleep 10\n",
+  )
+  .unwrap();
+
+  let start = Instant::now();
+
+  let mut child = Command::new(executable_path("just"))
+    .current_dir(&tempdir)
+    .spawn()
+    .unwrap();
+
+  thread::sleep(Duration::from_millis(500));
+
+  unsafe {
+    libc::kill(child.id().try_into().unwrap(), libc::SIGINT);
+  }
+
+  let status = child.wait().unwrap();
+
+  assert!(start.elapsed() < Duration::from_secs(5));
+
+  assert_eq!(status.code(), Some(130));
+}
+
+#[test]
+fn only_recipes_with_timeouts_run_in_their_own_process_group() {
+  Test::new()
+    .justfile(
+      r#"
+      [timeout('1m')]
+      isolated:
+        @[ "$(ps -o pgid= -p $$)" != "$(ps -o pgid= -p $PPID)" ]
+
+      shared:
+        @[ "$(ps -o pgid= -p $$)" = "$(ps -o pgid= -p $PPID)" ]
+      "#,
+    )
+    .args(["isolated", "shared"])
+    .run();
+}
+
+#[test]
+fn invalid_attribute_duration() {
+  Test::new()
+    .justfile(
+      "
+      [timeout('soon')]
+      slow:
+      ",
+    )
+    .stderr_regex(
+      "error: Attribute `timeout` has invalid duration `soon`: .*\n ——▶ justfile:1:2\n.*",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_flag_duration() {
+  Test::new()
+    .justfile("foo:")
+    .args(["--timeout", "soon"])
+    .stderr_regex("error: invalid value 'soon' for '--timeout <DURATION>': .*")
+    .status(2)
+    .run();
+}
+
+#[test]
+fn attribute_requires_argument() {
+  Test::new()
+    .justfile(
+      "
+      [timeout]
+      slow:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `timeout` got 0 arguments but takes 1 argument
+       ——▶ justfile:1:2
+        │
+      1 │ [timeout]
+        │  ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,243 @@
+use {
+  super::*,
+  filetime::{set_file_mtime, FileTime},
+  std::time::{Duration, SystemTime},
+};
+
+const JUSTFILE: &str = "
+  [sources('src/*.c'), outputs('app')]
+  build:
+    @echo build
+";
+
+fn age(test: Test, path: &str, seconds: u64) -> Test {
+  set_file_mtime(
+    test.tempdir.path().join(path),
+    FileTime::from_system_time(SystemTime::now() - Duration::from_secs(seconds)),
+  )
+  .unwrap();
+  test
+}
+
+#[test]
+fn skipped_when_outputs_are_newer_than_sources() {
+  let test = Test::new()
+    .justfile(JUSTFILE)
+    .write("src/main.c", "")
+    .write("app", "");
+
+  age(age(test, "src/main.c", 60), "app", 30).run();
+}
+
+#[test]
+fn run_when_source_is_newer_than_output() {
+  let test = Test::new()
+    .justfile(JUSTFILE)
+    .write("src/main.c", "")
+    .write("app", "");
+
+  age(age(test, "src/main.c", 30), "app", 60)
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn run_when_output_is_missing() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .write("src/main.c", "")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn run_when_any_output_is_missing() {
+  Test::new()
+    .justfile(
+      "
+      [outputs('foo', 'bar')]
+      build:
+        @echo build
+      ",
+    )
+    .write("foo", "")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn outputs_without_sources_are_up_to_date_if_they_exist() {
+  Test::new()
+    .justfile(
+      "
+      [outputs('app')]
+      build:
+        @echo build
+      ",
+    )
+    .write("app", "")
+    .run();
+}
+
+#[test]
+fn sources_without_outputs_always_run() {
+  Test::new()
+    .justfile(
+      "
+      [sources('src/*.c')]
+      build:
+        @echo build
+      ",
+    )
+    .write("src/main.c", "")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn force_overrides_up_to_date_check() {
+  let test = Test::new()
+    .justfile(JUSTFILE)
+    .write("src/main.c", "")
+    .write("app", "");
+
+  age(age(test, "src/main.c", 60), "app", 30)
+    .arg("--force")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn up_to_date_recipes_are_reported_when_verbose() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .write("app", "")
+    .arg("--verbose")
+    .stderr("===> Recipe `build` is up to date\n")
+    .run();
+}
+
+#[test]
+fn up_to_date_recipes_are_reported_with_explain() {
+  Test::new()
+    .justfile(JUSTFILE)
+    .write("app", "")
+    .arg("--explain")
+    .stderr("===> Recipe `build` is up to date\n")
+    .run();
+}
+
+#[test]
+fn dependencies_of_up_to_date_recipes_still_run() {
+  Test::new()
+    .justfile(
+      "
+      [outputs('app')]
+      build: generate
+        @echo build
+
+      generate:
+        @echo generate
+      ",
+    )
+    .write("app", "")
+    .stdout("generate\n")
+    .run();
+}
+
+#[test]
+fn paths_are_relative_to_working_directory() {
+  Test::new()
+    .justfile(
+      "
+      [working-directory('bar')]
+      [outputs('app')]
+      build:
+        @echo build
+      ",
+    )
+    .write("bar/app", "")
+    .run();
+}
+
+#[test]
+fn attributes_are_repeatable() {
+  Test::new()
+    .justfile(
+      "
+      [outputs('foo')]
+      [outputs('bar')]
+      build:
+        @echo build
+      ",
+    )
+    .write("foo", "")
+    .stdout("build\n")
+    .run();
+}
+
+#[test]
+fn attributes_require_arguments() {
+  Test::new()
+    .justfile(
+      "
+      [sources]
+      build:
+      ",
+    )
+    .stderr(
+      "
+      error: Attribute `sources` got 0 arguments but takes at least 1 argument
+       ——▶ justfile:1:2
+        │
+      1 │ [sources]
+        │  ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn invalid_source_pattern_is_compile_error() {
+  Test::new()
+    .justfile(
+      "
+      foo:
+
+      [sources('src/[.c'), outputs('app')]
+      build:
+      ",
+    )
+    .stderr(
+      "
+        error: Attribute `sources` has invalid pattern `src/[.c`: Pattern syntax error near position 4: invalid range pattern
+         ——▶ justfile:3:2
+          │
+        3 │ [sources('src/[.c'), outputs('app')]
+          │  ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn attributes_are_dumped() {
+  Test::new()
+    .justfile(
+      "
+      [outputs('app'), sources('a', 'b')]
+      build:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+      [outputs('app')]
+      [sources('a', 'b')]
+      build:
+      ",
+    )
+    .run();
+}
@@ -0,0 +1,189 @@
+use super::*;
+
+#[test]
+fn no_parameters() {
+  Test::new()
+    .justfile(
+      "
+      foo:
+      ",
+    )
+    .args(["--usage", "foo"])
+    .stdout("Usage: just foo\n")
+    .run();
+}
+
+#[test]
+fn doc_comment() {
+  Test::new()
+    .justfile(
+      "
+      # build the project
+      build:
+      ",
+    )
+    .args(["--usage", "build"])
+    .stdout(
+      "
+      build the project
+
+      Usage: just build
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn positional_parameters() {
+  Test::new()
+    .justfile(
+      "
+      build target mode='debug' *flags='-v':
+      ",
+    )
+    .args(["--usage", "build"])
+    .stdout(
+      "
+      Usage: just build <target> [mode] [flags]...
+
+      Arguments:
+        <target>
+        [mode]      [default: 'debug']
+        [flags]...  [default: '-v']
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn required_variadic_parameter() {
+  Test::new()
+    .justfile(
+      "
+      test +files:
+      ",
+    )
+    .args(["--usage", "test"])
+    .stdout(
+      "
+      Usage: just test <files>...
+
+      Arguments:
+        <files>...
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn options_and_help() {
+  Test::new()
+    .justfile(
+      "
+      # build the project
+      [arg('target', long, short='t', help='Target triple')]
+      [arg('release', long, value='true', help='Build with optimizations')]
+      [arg('jobs', short='j')]
+      [arg('package', help='Package to build')]
+      build package target='x86' release='false' jobs='4':
+      ",
+    )
+    .args(["--usage", "build"])
+    .stdout(
+      "
+      build the project
+
+      Usage: just build [OPTIONS] <package>
+
+      Arguments:
+        <package>              Package to build
+
+      Options:
+        -t, --target <target>  Target triple [default: 'x86']
+            --release          Build with optimizations [default: 'false']
+        -j <jobs>              [default: '4']
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn recipe_in_submodule() {
+  Test::new()
+    .write("foo.just", "bar baz:")
+    .justfile(
+      "
+      mod foo
+      ",
+    )
+    .args(["--usage", "foo", "bar"])
+    .stdout(
+      "
+      Usage: just foo::bar <baz>
+
+      Arguments:
+        <baz>
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn alias() {
+  Test::new()
+    .justfile(
+      "
+      alias b := build
+
+      build target:
+      ",
+    )
+    .args(["--usage", "b"])
+    .stdout(
+      "
+      Usage: just build <target>
+
+      Arguments:
+        <target>
+      ",
+    )
+    .run();
+}
+
+#[test]
+fn unknown_recipe() {
+  Test::new()
+    .justfile(
+      "
+      build:
+      ",
+    )
+    .args(["--usage", "biuld"])
+    .stderr(
+      "
+      error: Justfile does not contain recipe `biuld`
+      Did you mean `build`?
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn help_is_dumped() {
+  Test::new()
+    .justfile(
+      "
+      [arg('target', help='Target triple')]
+      build target:
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+      [arg('target', help='Target triple')]
+      build target:
+      ",
+    )
+    .run();
+}
@@ -0,0 +1,493 @@
+use super::*;
+
+#[test]
+fn call_in_assignment() {
+  Test::new()
+    .justfile(
+      "
+        fn image_tag(name, version) := name + ':' + version
+
+        tag := image_tag('app', '1.2')
+
+        foo:
+          @echo {{tag}}
+      ",
+    )
+    .stdout("app:1.2\n")
+    .run();
+}
+
+#[test]
+fn call_in_interpolation() {
+  Test::new()
+    .justfile(
+      "
+        fn greet(name) := 'hello ' + name
+
+        foo:
+          @echo {{greet('world')}}
+      ",
+    )
+    .stdout("hello world\n")
+    .run();
+}
+
+#[test]
+fn call_in_default() {
+  Test::new()
+    .justfile(
+      "
+        fn double(s) := s + s
+
+        foo bar=double('x'):
+          @echo {{bar}}
+      ",
+    )
+    .stdout("xx\n")
+    .run();
+}
+
+#[test]
+fn call_in_dependency_argument() {
+  Test::new()
+    .justfile(
+      "
+        fn double(s) := s + s
+
+        foo: (bar double('y'))
+
+        bar x:
+          @echo {{x}}
+      ",
+    )
+    .stdout("yy\n")
+    .run();
+}
+
+#[test]
+fn arguments_may_reference_recipe_parameters() {
+  Test::new()
+    .justfile(
+      "
+        fn wrap(s) := '[' + s + ']'
+
+        foo bar:
+          @echo {{wrap(bar)}}
+      ",
+    )
+    .args(["foo", "baz"])
+    .stdout("[baz]\n")
+    .run();
+}
+
+#[test]
+fn nullary() {
+  Test::new()
+    .justfile(
+      "
+        fn registry() := 'ghcr.io'
+
+        foo:
+          @echo {{registry()}}
+      ",
+    )
+    .stdout("ghcr.io\n")
+    .run();
+}
+
+#[test]
+fn body_may_call_builtin_functions() {
+  Test::new()
+    .justfile(
+      "
+        fn slug(s) := replace(lowercase(trim(s)), ' ', '-')
+
+        foo:
+          @echo {{slug('  Hello World  ')}}
+      ",
+    )
+    .stdout("hello-world\n")
+    .run();
+}
+
+#[test]
+fn body_may_call_user_functions() {
+  Test::new()
+    .justfile(
+      "
+        fn registry(image) := 'ghcr.io/' + tag(image, 'latest')
+        fn tag(name, version) := name + ':' + version
+
+        foo:
+          @echo {{registry('web')}}
+      ",
+    )
+    .stdout("ghcr.io/web:latest\n")
+    .run();
+}
+
+#[test]
+fn body_may_use_constants() {
+  Test::new()
+    .justfile(
+      "
+        fn hex(s) := HEX + s
+
+        foo:
+          @echo {{hex('!')}}
+      ",
+    )
+    .stdout("0123456789abcdef!\n")
+    .run();
+}
+
+#[test]
+fn parameters_shadow_assignments() {
+  Test::new()
+    .justfile(
+      "
+        name := 'outer'
+
+        fn id(name) := name
+
+        foo:
+          @echo {{id('inner')}} {{name}}
+      ",
+    )
+    .stdout("inner outer\n")
+    .run();
+}
+
+#[test]
+fn body_may_not_reference_assignments() {
+  Test::new()
+    .justfile(
+      "
+        version := '1.0'
+
+        fn tag(name) := name + ':' + version
+      ",
+    )
+    .stderr(
+      "
+        error: Variable `version` not defined
+         ——▶ justfile:3:30
+          │
+        3 │ fn tag(name) := name + ':' + version
+          │                              ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_function() {
+  Test::new()
+    .justfile(
+      "
+        fn tag(name) := name
+
+        foo := tga('x')
+      ",
+    )
+    .stderr(
+      "
+        error: Call to unknown function `tga`
+         ——▶ justfile:3:8
+          │
+        3 │ foo := tga('x')
+          │        ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn unknown_function_in_body() {
+  Test::new()
+    .justfile(
+      "
+        fn tag(name) := wrap(name)
+      ",
+    )
+    .stderr(
+      "
+        error: Call to unknown function `wrap`
+         ——▶ justfile:1:17
+          │
+        1 │ fn tag(name) := wrap(name)
+          │                 ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn too_few_arguments() {
+  Test::new()
+    .justfile(
+      "
+        fn tag(name, version) := name + ':' + version
+
+        foo:
+          echo {{tag('app')}}
+      ",
+    )
+    .stderr(
+      "
+        error: Function `tag` called with 1 argument but takes 2
+         ——▶ justfile:4:10
+          │
+        4 │   echo {{tag('app')}}
+          │          ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn too_many_arguments() {
+  Test::new()
+    .justfile(
+      "
+        fn registry() := 'ghcr.io'
+
+        foo := registry('x')
+      ",
+    )
+    .stderr(
+      "
+        error: Function `registry` called with 1 argument but takes 0
+         ——▶ justfile:3:8
+          │
+        3 │ foo := registry('x')
+          │        ^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn recursion() {
+  Test::new()
+    .justfile(
+      "
+        fn loop(s) := loop(s)
+      ",
+    )
+    .stderr(
+      "
+        error: Function `loop` calls itself
+         ——▶ justfile:1:4
+          │
+        1 │ fn loop(s) := loop(s)
+          │    ^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn mutual_recursion() {
+  Test::new()
+    .justfile(
+      "
+        fn a(s) := b(s)
+        fn b(s) := c(s)
+        fn c(s) := a(s)
+      ",
+    )
+    .stderr(
+      "
+        error: Function `a` has circular call chain `a -> b -> c -> a`
+         ——▶ justfile:1:4
+          │
+        1 │ fn a(s) := b(s)
+          │    ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn duplicate_function() {
+  Test::new()
+    .justfile(
+      "
+        fn tag(s) := s
+        fn tag(s) := s + s
+      ",
+    )
+    .stderr(
+      "
+        error: Function `tag` has multiple definitions
+         ——▶ justfile:2:4
+          │
+        2 │ fn tag(s) := s + s
+          │    ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn duplicate_parameter() {
+  Test::new()
+    .justfile(
+      "
+        fn tag(s, s) := s
+      ",
+    )
+    .stderr(
+      "
+        error: Function `tag` has duplicate parameter `s`
+         ——▶ justfile:1:11
+          │
+        1 │ fn tag(s, s) := s
+          │           ^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn builtin_function_redefinition() {
+  Test::new()
+    .justfile(
+      "
+        fn uppercase(s) := s
+      ",
+    )
+    .stderr(
+      "
+        error: Function `uppercase` redefines a built-in function
+         ——▶ justfile:1:4
+          │
+        1 │ fn uppercase(s) := s
+          │    ^^^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn attributes_are_not_allowed() {
+  Test::new()
+    .justfile(
+      "
+        [private]
+        fn tag(s) := s
+      ",
+    )
+    .stderr(
+      "
+        error: Function `tag` has invalid attribute `private`
+         ——▶ justfile:2:4
+          │
+        2 │ fn tag(s) := s
+          │    ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn functions_from_imports() {
+  Test::new()
+    .write("import.just", "fn tag(name) := name + ':latest'\n")
+    .justfile(
+      "
+        import 'import.just'
+
+        foo:
+          @echo {{tag('app')}}
+      ",
+    )
+    .stdout("app:latest\n")
+    .run();
+}
+
+#[test]
+fn functions_are_module_local() {
+  Test::new()
+    .write("bar.just", "baz:\n  @echo {{tag('app')}}\n")
+    .justfile(
+      "
+        mod bar
+
+        fn tag(name) := name + ':latest'
+      ",
+    )
+    .arg("bar::baz")
+    .stderr(
+      "
+        error: Call to unknown function `tag`
+         ——▶ bar.just:2:11
+          │
+        2 │   @echo {{tag('app')}}
+          │           ^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+fn fn_may_still_be_used_as_a_name() {
+  Test::new()
+    .justfile(
+      "
+        fn := 'a'
+
+        fn:
+          @echo {{fn}}
+      ",
+    )
+    .stdout("a\n")
+    .run();
+}
+
+#[test]
+fn evaluate() {
+  Test::new()
+    .justfile(
+      "
+        fn tag(name, version) := name + ':' + version
+
+        image := tag('app', '1.2')
+      ",
+    )
+    .args(["--evaluate", "image"])
+    .stdout("app:1.2")
+    .run();
+}
+
+#[test]
+fn dump() {
+  Test::new()
+    .justfile(
+      "
+        fn tag(name, version) := name+':'+version
+        fn registry() := 'ghcr.io'
+      ",
+    )
+    .arg("--dump")
+    .stdout(
+      "
+        fn tag(name, version) := name + ':' + version
+        fn registry() := 'ghcr.io'
+      ",
+    )
+    .run();
+}
@@ -0,0 +1,268 @@
+use {
+  super::*,
+  std::{
+    process::Child,
+    thread,
+    time::{Duration, Instant},
+  },
+};
+
+struct Watch {
+  child: Child,
+  tempdir: TempDir,
+}
+
+impl Watch {
+  fn new(justfile: &str, arguments: &[&str]) -> Self {
+    Self::with_subdirectory(justfile, None, arguments)
+  }
+
+  /// Watch from `subdirectory`, containing its own justfile, if given
+  fn with_subdirectory(
+    justfile: &str,
+    subdirectory: Option<(&str, &str)>,
+    arguments: &[&str],
+  ) -> Self {
+    let tempdir = tempdir();
+
+    fs::write(tempdir.path().join("justfile"), unindent(justfile)).unwrap();
+    fs::write(tempdir.path().join("input.txt"), "a").unwrap();
+
+    let mut directory = tempdir.path().to_owned();
+
+    if let Some((subdirectory, justfile)) = subdirectory {
+      directory.push(subdirectory);
+      fs::create_dir(&directory).unwrap();
+      fs::write(directory.join("justfile"), unindent(justfile)).unwrap();
+    }
+
+    let child = Command::new(executable_path("just"))
+      .current_dir(directory)
+      .arg("--watch")
+      .args(arguments)
+      .stdout(Stdio::null())
+      .stderr(Stdio::null())
+      .spawn()
+      .unwrap();
+
+    Self { child, tempdir }
+  }
+
+  fn write(&self, path: &str, content: &str) {
+    fs::write(self.tempdir.path().join(path), unindent(content)).unwrap();
+  }
+
+  fn log(&self) -> String {
+    fs::read_to_string(self.tempdir.path().join("log")).unwrap_or_default()
+  }
+
+  fn wait_for(&self, expected: &str) {
+    let start = Instant::now();
+
+    while self.log() != expected {
+      assert!(
+        start.elapsed() < Duration::from_secs(10),
+        "timed out waiting for log `{expected}`, found `{}`",
+        self.log(),
+      );
+      thread::sleep(Duration::from_millis(50));
+    }
+  }
+}
+
+impl Drop for Watch {
+  fn drop(&mut self) {
+    self.child.kill().unwrap();
+    self.child.wait().unwrap();
+  }
+}
+
+#[test]
+fn reruns_when_glob_matches_change() {
+  let watch = Watch::new(
+    "
+    foo:
+      cat input.txt >> log
+    ",
+    &["--watch-glob", "*.txt"],
+  );
+
+  watch.wait_for("a");
+
+  watch.write("input.txt", "b");
+
+  watch.wait_for("ab");
+}
+
+#[test]
+fn ignores_unwatched_files() {
+  let watch = Watch::new(
+    "
+    foo:
+      cat input.txt >> log
+    ",
+    &[],
+  );
+
+  watch.wait_for("a");
+
+  watch.write("input.txt", "b");
+
+  thread::sleep(Duration::from_secs(1));
+
+  assert_eq!(watch.log(), "a");
+}
+
+#[test]
+fn reloads_justfile() {
+  let watch = Watch::new(
+    "
+    foo:
+      printf a >> log
+    ",
+    &[],
+  );
+
+  watch.wait_for("a");
+
+  watch.write(
+    "justfile",
+    "
+    foo:
+      printf b >> log
+    ",
+  );
+
+  watch.wait_for("ab");
+}
+
+#[test]
+fn waits_for_fix_after_compile_error() {
+  let watch = Watch::new(
+    "
+    foo:
+      printf a >> log
+    ",
+    &[],
+  );
+
+  watch.wait_for("a");
+
+  watch.write("justfile", "foo: bar");
+
+  thread::sleep(Duration::from_secs(1));
+
+  watch.write(
+    "justfile",
+    "
+    foo:
+      printf c >> log
+    ",
+  );
+
+  watch.wait_for("ac");
+}
+
+#[test]
+fn reruns_after_failure() {
+  let watch = Watch::new(
+    "
+    foo:
+      cat input.txt >> log
+      exit 1
+    ",
+    &["--watch-glob", "input.txt"],
+  );
+
+  watch.wait_for("a");
+
+  watch.write("input.txt", "b");
+
+  watch.wait_for("ab");
+}
+
+#[test]
+fn running_invocation_is_terminated() {
+  let watch = Watch::new(
+    "
+    foo:
+      cat input.txt >> log
+      sleep 5
+      printf done >> log
+    ",
+    &["--watch-glob", "input.txt"],
+  );
+
+  watch.wait_for("a");
+
+  watch.write("input.txt", "b");
+
+  watch.wait_for("ab");
+
+  watch.write("input.txt", "c");
+
+  watch.wait_for("abc");
+}
+
+#[test]
+#[cfg(unix)]
+fn processes_started_by_running_invocation_are_terminated() {
+  let watch = Watch::new(
+    r#"
+    foo:
+      cat input.txt >> log; if [ "$(cat input.txt)" = a ]; then sh -c 'sleep 2; printf orphan >> log'; true; fi
+    "#,
+    &["--watch-glob", "input.txt"],
+  );
+
+  watch.wait_for("a");
+
+  watch.write("input.txt", "b");
+
+  watch.wait_for("ab");
+
+  thread::sleep(Duration::from_secs(3));
+
+  assert_eq!(watch.log(), "ab");
+}
+
+#[test]
+fn falls_back_to_parent_justfile() {
+  let watch = Watch::with_subdirectory(
+    "
+    foo:
+      cat input.txt >> log
+    ",
+    Some((
+      "sub",
+      "
+      set fallback
+
+      bar:
+      ",
+    )),
+    &["foo"],
+  );
+
+  watch.wait_for("a");
+}
+
+#[test]
+fn watch_requires_run() {
+  Test::new()
+    .justfile("foo:")
+    .args(["--watch", "--list"])
+    .stderr_regex("(?s)error: the argument '--watch' cannot be used with:.*--list.*")
+    .status(2)
+    .run();
+}
+
+#[test]
+fn invalid_glob() {
+  Test::new()
+    .justfile("foo:")
+    .args(["--watch", "--watch-glob", "[", "foo"])
+    .stderr_regex("error: Invalid watch pattern `\\[`: .*\n")
+    .status(EXIT_FAILURE)
+    .run();
+}
@@ -0,0 +1,197 @@
+use super::*;
+
+const HELLO: &str = "#!/bin/sh\necho hello\n";
+
+#[cfg_attr(windows, allow(unused_variables))]
+fn make_executable(test: &Test, path: &str) {
+  #[cfg(not(windows))]
+  {
+    let permissions = std::os::unix::fs::PermissionsExt::from_mode(0o700);
+    fs::set_permissions(test.tempdir.path().join(path), permissions).unwrap();
+  }
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn which_finds_executable_in_path_setting() {
+  let test = Test::new()
+    .justfile(
+      "
+        set path := ['bin']
+
+        foo:
+          @echo {{which('hello')}}
+      ",
+    )
+    .write("bin/hello", HELLO);
+
+  make_executable(&test, "bin/hello");
+
+  test.stdout_regex(".*/bin/hello\n").run();
+}
+
+#[test]
+fn which_returns_empty_string_if_executable_is_not_found() {
+  Test::new()
+    .justfile(
+      "
+        foo:
+          @echo '[{{which('just-test-missing-executable')}}]'
+      ",
+    )
+    .stdout("[]\n")
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn which_skips_files_which_are_not_executable() {
+  let test = Test::new()
+    .justfile(
+      "
+        set path := ['a', 'b']
+
+        foo:
+          @echo {{which('hello')}}
+      ",
+    )
+    .write("a/hello", HELLO)
+    .write("b/hello", HELLO);
+
+  make_executable(&test, "b/hello");
+
+  test.stdout_regex(".*/b/hello\n").run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn which_with_path_separator_is_relative_to_working_directory() {
+  let test = Test::new()
+    .justfile(
+      "
+        foo:
+          @echo {{which('bin/hello')}}
+      ",
+    )
+    .write("bin/hello", HELLO);
+
+  make_executable(&test, "bin/hello");
+
+  test.stdout_regex(".*/bin/hello\n").run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn which_searches_exported_path() {
+  let test = Test::new()
+    .justfile(
+      "
+        export PATH := justfile_directory() / 'bin' + ':' + env('PATH')
+
+        foo:
+          @echo {{file_name(which('hello'))}}
+      ",
+    )
+    .write("bin/hello", HELLO);
+
+  make_executable(&test, "bin/hello");
+
+  test.stdout("hello\n").run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn require() {
+  let test = Test::new()
+    .justfile(
+      "
+        set path := ['bin']
+
+        hello := require('hello')
+
+        foo:
+          @{{hello}}
+      ",
+    )
+    .write("bin/hello", HELLO);
+
+  make_executable(&test, "bin/hello");
+
+  test.stdout("hello\n").run();
+}
+
+#[test]
+fn require_fails_before_recipes_run() {
+  Test::new()
+    .justfile(
+      "
+        docker := require('just-test-missing-executable')
+
+        build:
+          echo building
+      ",
+    )
+    .stderr(
+      "
+        error: Call to function `require` failed: Could not find executable `just-test-missing-executable` in PATH
+         ——▶ justfile:1:11
+          │
+        1 │ docker := require('just-test-missing-executable')
+          │           ^^^^^^^
+      ",
+    )
+    .status(EXIT_FAILURE)
+    .run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn path_setting_applies_to_recipes_and_backticks() {
+  let test = Test::new()
+    .justfile(
+      "
+        set path := ['bin']
+
+        greeting := `hello`
+
+        foo:
+          @echo {{greeting}}
+          @hello
+      ",
+    )
+    .write("bin/hello", HELLO);
+
+  make_executable(&test, "bin/hello");
+
+  test.stdout("hello\nhello\n").run();
+}
+
+#[test]
+#[cfg_attr(windows, ignore)]
+fn path_setting_is_relative_to_working_directory() {
+  let test = Test::new()
+    .justfile(
+      "
+        set path := ['bin']
+
+        foo:
+          @hello
+      ",
+    )
+    .write("bin/hello", HELLO)
+    .create_dir("sub")
+    .current_dir("sub");
+
+  make_executable(&test, "bin/hello");
+
+  test.stdout("hello\n").run();
+}
+
+#[test]
+fn path_setting_dump() {
+  Test::new()
+    .justfile("set path := [ 'bin','node_modules/.bin', ]")
+    .arg("--dump")
+    .stdout("set path := ['bin', 'node_modules/.bin']\n")
+    .run();
+}